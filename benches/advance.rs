@@ -0,0 +1,23 @@
+use std::time::Instant;
+
+use yagoll::{Backend, Board, BorderOpt};
+
+const SIZE: usize = 256;
+const CYCLES: usize = 20;
+
+fn main() {
+    let soup = Board::new_random(SIZE, SIZE, BorderOpt::Loop, 0.4, 1);
+
+    let mut scalar = soup.clone();
+    let start = Instant::now();
+    scalar.advance_n_cycles(CYCLES);
+    println!("Scalar:   {:?}", start.elapsed());
+
+    let mut bitboard = soup;
+    bitboard.set_backend(Backend::Bitboard);
+    let start = Instant::now();
+    bitboard.advance_n_cycles(CYCLES);
+    println!("Bitboard: {:?}", start.elapsed());
+
+    assert_eq!(scalar.render_fast(), bitboard.render_fast());
+}
@@ -0,0 +1,60 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use yagoll::{Board, BorderOpt};
+
+const SIZE: usize = 128;
+const CYCLES: usize = 200;
+
+// Passes everything through to the system allocator, counting calls
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn count_allocations(board: &mut Board) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    board.advance_n_cycles(CYCLES);
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn main() {
+    let soup = Board::new_random(SIZE, SIZE, BorderOpt::Loop, 0.4, 1);
+
+    let mut board = soup.clone();
+    println!(
+        "Default:  {:>6} allocations over {} cycles",
+        count_allocations(&mut board),
+        CYCLES
+    );
+
+    let mut reserved = soup;
+    reserved.reserve_updates(SIZE * SIZE);
+    println!(
+        "Reserved: {:>6} allocations over {} cycles",
+        count_allocations(&mut reserved),
+        CYCLES
+    );
+
+    assert_eq!(board, reserved);
+}
@@ -0,0 +1,28 @@
+use std::time::Instant;
+
+use yagoll::{Board, BorderOpt};
+
+const SIZE: usize = 200;
+const CYCLES: usize = 600;
+const REPORT_EVERY: usize = 100;
+
+fn main() {
+    let mut board = Board::new_random(SIZE, SIZE, BorderOpt::Empty, 0.35, 1);
+
+    (0..CYCLES / REPORT_EVERY).for_each(|i| {
+        let mut evaluated = 0;
+        let start = Instant::now();
+        (0..REPORT_EVERY).for_each(|_| {
+            evaluated += board.dirty_cell_count();
+            board.advance_cycle();
+        });
+        println!(
+            "cycles {:>4}-{:<4} {:>10} cell evaluations ({:>5.1}% of naive) in {:?}",
+            i * REPORT_EVERY,
+            (i + 1) * REPORT_EVERY,
+            evaluated,
+            100.0 * evaluated as f64 / (SIZE * SIZE * REPORT_EVERY) as f64,
+            start.elapsed()
+        );
+    });
+}
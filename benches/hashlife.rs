@@ -0,0 +1,32 @@
+use std::time::Instant;
+
+use yagoll::{Board, BorderOpt, Cell};
+
+const CYCLES: usize = 400;
+
+fn main() {
+    // Four glider guns spread over a large, mostly empty board
+    let gun = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+    let mut board = Board::new(320, 240, BorderOpt::Empty);
+    [(0, 0), (160, 0), (0, 120), (160, 120)]
+        .iter()
+        .for_each(|&(ox, oy)| {
+            (0..gun.height).for_each(|y| {
+                (0..gun.width)
+                    .filter(|&x| gun.get(x, y) == Cell::Alive)
+                    .for_each(|x| board.set(ox + x, oy + y, Cell::Alive))
+            })
+        });
+
+    let mut naive = board.clone();
+    let start = Instant::now();
+    naive.advance_n_cycles(CYCLES);
+    println!("advance_n_cycles: {:?}", start.elapsed());
+
+    let mut memoized = board;
+    let start = Instant::now();
+    memoized.advance_hashlife(CYCLES);
+    println!("advance_hashlife: {:?}", start.elapsed());
+
+    assert_eq!(naive, memoized);
+}
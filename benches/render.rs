@@ -0,0 +1,17 @@
+use std::time::Instant;
+
+use yagoll::{Board, BorderOpt};
+
+fn main() {
+    let board = Board::new_random(1000, 1000, BorderOpt::Empty, 0.5, 1);
+
+    let start = Instant::now();
+    let display = format!("{}", board);
+    println!("Display:     {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let fast = board.render_fast();
+    println!("render_fast: {:?}", start.elapsed());
+
+    assert_eq!(display, fast);
+}
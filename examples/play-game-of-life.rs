@@ -1,4 +1,4 @@
-use std::{env, thread, time};
+use std::{env, io};
 
 use yagoll::Board;
 
@@ -25,9 +25,8 @@ fn main() {
     let mut board = Board::new_from_file(&path);
     println!("Board from {}:\n{}", path, board);
 
-    (0..num_cycles + 1).for_each(|i| {
-        println!("Cycle: {}/{}\n{}", i, num_cycles, board);
-        board.advance_cycle();
-        thread::sleep(time::Duration::from_millis(delay));
+    let stdout = io::stdout();
+    board.play(1000.0 / delay.max(1) as f64, num_cycles, |board| {
+        board.write_frame(&mut stdout.lock()).unwrap();
     });
 }
@@ -0,0 +1,257 @@
+//! Bit-parallel generation stepping for [`Backend::Bitboard`]
+//!
+//! The board is copied into rows of `u64` words with a one cell ring of
+//! padding around it, filled in according to the border mode. Each word
+//! then has its eight neighbor words built with shifts, and the neighbor
+//! counts of all 64 cells are summed at once in three bit planes (ones,
+//! twos and fours) with half adders. A count of 8 overflows to 0, which is
+//! harmless since both mean death under Conway's rules.
+//!
+//! [`Backend::Bitboard`]: crate::Backend::Bitboard
+
+use crate::{board::Board, cell::Cell};
+
+const WORD_BITS: usize = 64;
+
+struct BitGrid {
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    // Pack `board` with padding, so board cell (x, y) is bit x + 1 of
+    // padded row y + 1
+    fn pack(board: &Board) -> Self {
+        let (width, height) = (board.width, board.height);
+        let words_per_row = (width + 2).div_ceil(WORD_BITS);
+        let mut grid = BitGrid {
+            words_per_row,
+            words: vec![0; words_per_row * (height + 2)],
+        };
+
+        board.cells.chunks(width).enumerate().for_each(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &c)| c == Cell::Alive)
+                .for_each(|(x, _)| grid.set(x + 1, y + 1))
+        });
+
+        let (w, h) = (width as i64, height as i64);
+        let ring = (-1..=w)
+            .flat_map(|x| vec![(x, -1), (x, h)])
+            .chain((0..h).flat_map(|y| vec![(-1, y), (w, y)]));
+        ring.filter(|&(x, y)| board.effective_neighbor(x, y) == Cell::Alive)
+            .for_each(|(x, y)| grid.set((x + 1) as usize, (y + 1) as usize));
+
+        grid
+    }
+
+    fn set(&mut self, px: usize, py: usize) {
+        self.words[py * self.words_per_row + px / WORD_BITS] |= 1 << (px % WORD_BITS);
+    }
+
+    fn is_set(&self, px: usize, py: usize) -> bool {
+        self.words[py * self.words_per_row + px / WORD_BITS] & (1 << (px % WORD_BITS)) != 0
+    }
+
+    fn row(&self, py: usize) -> &[u64] {
+        &self.words[py * self.words_per_row..(py + 1) * self.words_per_row]
+    }
+}
+
+// Bits of `row` moved one cell to the right, so each cell sees the value
+// of its west neighbor
+fn west(row: &[u64], k: usize) -> u64 {
+    let carry = if k > 0 {
+        row[k - 1] >> (WORD_BITS - 1)
+    } else {
+        0
+    };
+    (row[k] << 1) | carry
+}
+
+// Bits of `row` moved one cell to the left, so each cell sees the value of
+// its east neighbor
+fn east(row: &[u64], k: usize) -> u64 {
+    let carry = if k + 1 < row.len() {
+        row[k + 1] << (WORD_BITS - 1)
+    } else {
+        0
+    };
+    (row[k] >> 1) | carry
+}
+
+/// Compute the next generation of `board` under Conway's rules
+pub(crate) fn next_generation(board: &Board) -> Vec<Cell> {
+    let (width, height) = (board.width, board.height);
+    if width == 0 || height == 0 {
+        return board.cells.clone();
+    }
+    let grid = BitGrid::pack(board);
+    let mut next = BitGrid {
+        words_per_row: grid.words_per_row,
+        words: vec![0; grid.words.len()],
+    };
+
+    (1..=height).for_each(|py| {
+        let (above, middle, below) = (grid.row(py - 1), grid.row(py), grid.row(py + 1));
+
+        (0..grid.words_per_row).for_each(|k| {
+            let neighbors = [
+                west(above, k),
+                above[k],
+                east(above, k),
+                west(middle, k),
+                east(middle, k),
+                west(below, k),
+                below[k],
+                east(below, k),
+            ];
+            let (mut ones, mut twos, mut fours) = (0u64, 0u64, 0u64);
+
+            neighbors.iter().for_each(|&n| {
+                let carry_one = ones & n;
+                ones ^= n;
+                let carry_two = twos & carry_one;
+                twos ^= carry_one;
+                fours ^= carry_two;
+            });
+
+            let three = ones & twos & !fours;
+            let two = !ones & twos & !fours;
+            next.words[py * grid.words_per_row + k] = three | (two & middle[k]);
+        });
+    });
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            if next.is_set(x + 1, y + 1) {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        board::{Backend, Board, BorderOpt},
+        cell::Cell,
+    };
+
+    // Run `initial` through the scalar and bitboard backends, HashLife and
+    // the sparse board, checking they agree after every one of `steps`
+    // generations
+    //
+    // HashLife is advanced in ever longer runs, so it's checked with and
+    // without whole blocks of memoized generations. The sparse board is
+    // unbounded and only knows Conway's rules, so it's only compared on
+    // such `Empty` boards, and only until a cell leaves the board, after
+    // which the two legitimately part ways.
+    fn assert_backends_agree(initial: &Board, steps: usize) {
+        let mut scalar = initial.clone();
+        let mut bitboard = initial.clone();
+        let mut hashlife = initial.clone();
+        let (mut hashlife_at, mut run) = (0, 1);
+        let mut sparse = (initial.border == BorderOpt::Empty
+            && initial.neighbor_radius() == 1
+            && initial.rule().is_conway())
+        .then(|| initial.to_sparse());
+        let in_bounds = |&(x, y): &(i64, i64)| {
+            (0..initial.width as i64).contains(&x) && (0..initial.height as i64).contains(&y)
+        };
+
+        scalar.set_backend(Backend::Scalar);
+        bitboard.set_backend(Backend::Bitboard);
+
+        (1..=steps).for_each(|step| {
+            scalar.advance_cycle();
+            bitboard.advance_cycle();
+            assert_eq!(scalar.cells, bitboard.cells, "diverged at step {}", step);
+
+            if step == hashlife_at + run || step == steps {
+                hashlife.advance_hashlife(step - hashlife_at);
+                assert_eq!(
+                    hashlife.cells, scalar.cells,
+                    "hashlife differs at step {}",
+                    step
+                );
+                hashlife_at = step;
+                run += 1;
+            }
+
+            if let Some(board) = sparse.as_mut() {
+                board.advance_cycle();
+                let live = board.live_cells();
+                if live.iter().all(in_bounds) {
+                    assert_eq!(
+                        live,
+                        scalar.to_sparse().live_cells(),
+                        "sparse differs at step {}",
+                        step
+                    );
+                } else {
+                    sparse = None;
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn bitboard_matches_scalar_on_soups() {
+        [(5, 5), (63, 10), (64, 64), (65, 7), (130, 40)]
+            .iter()
+            .enumerate()
+            .for_each(|(seed, &(w, h))| {
+                [BorderOpt::Empty, BorderOpt::Solid, BorderOpt::Loop]
+                    .iter()
+                    .for_each(|border| {
+                        let soup = Board::new_random(w, h, border.clone(), 0.4, seed as u64);
+                        assert_backends_agree(&soup, 30);
+                    })
+            });
+    }
+
+    #[test]
+    fn bitboard_matches_scalar_on_glider() {
+        let mut board = Board::new(10, 10, BorderOpt::Loop);
+
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        assert_backends_agree(&board, 40);
+    }
+
+    #[test]
+    fn bitboard_falls_back_for_larger_radius() {
+        let mut board = Board::new_random(12, 12, BorderOpt::Loop, 0.5, 3);
+
+        board.set_neighbor_radius(2);
+        assert_backends_agree(&board, 5);
+    }
+
+    #[test]
+    fn backends_agree_on_glider() {
+        let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+
+        assert_backends_agree(&glider.pad(10, 10, 10, 10), 40);
+        let mut looped = glider;
+        looped.set_border(BorderOpt::Loop);
+        assert_backends_agree(&looped, 24);
+    }
+
+    #[test]
+    fn backends_agree_on_blinker() {
+        [BorderOpt::Empty, BorderOpt::Solid, BorderOpt::Loop]
+            .iter()
+            .for_each(|border| {
+                let mut blinker = Board::new(5, 5, border.clone());
+                blinker.fill_rect(1, 2, 3, 1, Cell::Alive);
+                assert_backends_agree(&blinker, 6);
+            });
+    }
+}
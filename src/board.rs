@@ -1,19 +1,40 @@
 use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
     fmt::{self, Debug, Display, Formatter},
-    fs::File,
-    io::{BufRead, BufReader},
+    fs,
+    hash::{Hash, Hasher},
+    io::{self, BufRead, Error, ErrorKind},
+    mem,
     path::Path,
 };
 
+use crate::bitboard;
 use crate::cell::Cell;
+use crate::error::{BoardError, DimensionsTooLarge, InvalidCellState, OutOfBounds, ParseError};
+use crate::fixed::Grid;
+use crate::history::History;
+use crate::rng::SplitMix64;
+use crate::rule::{Rule, RuleId, RuleMap};
+use crate::update_mode::ScanOrder;
 
 const FILE_LIVE_CHAR: u8 = b'#';
 const FILE_DEAD_CHAR: u8 = b'_';
+const FILE_COMMENT_CHAR: char = '!';
+// Generous room for the border line, which is a single short word
+const BORDER_LINE_LIMIT: usize = 64;
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
 
 /// Border options
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BorderOpt {
     /// Consider the border as "alive"
+    ///
+    /// Every off-board neighbor counts as a live cell, so cells along the
+    /// edge see three extra live neighbors and corner cells five. That's
+    /// enough to bring empty edge cells to life, and to overcrowd
+    /// patterns that are stable elsewhere: a 2×2 block in a corner dies
+    /// under `Solid` but survives under `Empty`.
     Solid,
     /// Consider the border as "dead"
     Empty,
@@ -21,8 +42,101 @@ pub enum BorderOpt {
     Loop,
 }
 
+/// A generation number, to keep generations apart from coordinates and
+/// other counts at call sites
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// let gen = Generation::from(3);
+/// assert_eq!(usize::from(gen), 3);
+/// assert!(Generation(2) < gen);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Generation(pub usize);
+
+impl From<usize> for Generation {
+    fn from(gen: usize) -> Self {
+        Generation(gen)
+    }
+}
+
+impl From<Generation> for usize {
+    fn from(gen: Generation) -> Self {
+        gen.0
+    }
+}
+
+impl Display for Generation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "generation {}", self.0)
+    }
+}
+
+/// Where and how long a board's cycle is, see [`Board::run_detect_cycle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleInfo {
+    /// The first generation that is part of the cycle
+    pub start: Generation,
+    /// The number of generations before the cycle repeats, or 0 if no
+    /// cycle was found
+    pub period: usize,
+}
+
+/// How a run of [`Board::simulate`] ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimOutcome {
+    /// Every cell was dead at this generation
+    Extinct(Generation),
+    /// The board stopped changing at this generation
+    StillLife(Generation),
+    /// The board started repeating itself every `period` generations at
+    /// generation `gen`
+    Oscillator {
+        /// The number of generations before the board repeats
+        period: usize,
+        /// The first generation that is part of the cycle
+        gen: Generation,
+    },
+    /// None of the above happened within the allotted generations
+    Ongoing,
+}
+
+/// How a board computes its next generation
+///
+/// Both backends produce identical results and the board's public API
+/// behaves the same with either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Evaluate one cell at a time
+    Scalar,
+    /// Pack each row into 64-bit words and evaluate 64 cells at once
+    ///
+    /// Much faster on large boards. Only applies to the standard Conway
+    /// rule with a neighbor radius of 1 and no rule map; anything else
+    /// falls back to [`Backend::Scalar`].
+    Bitboard,
+}
+
 /// A Game of Life Board
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Cells are addressed as `(x, y)`, i.e. (column, row), everywhere in the
+/// API: `x` counts columns from the left edge and `y` counts rows from
+/// the top edge, so `(0, 0)` is the top left corner and
+/// `(width - 1, 0)` the top right one. Cells are stored row by row.
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// let mut board = Board::new(3, 2, BorderOpt::Empty);
+/// board.set(2, 0, Cell::Alive);
+/// assert_eq!(board.to_ascii(), "..#\n...\n");
+/// ```
+#[derive(Debug, Clone)]
 pub struct Board {
     /// The width of the board
     pub width: usize,
@@ -30,8 +144,57 @@ pub struct Board {
     pub height: usize,
     /// The border behavior
     pub border: BorderOpt,
+    /// How far away a cell can be and still count as a neighbor
+    radius: usize,
+    /// Which neighbor counts make cells be born and survive
+    rule: Rule,
+    /// Per cell rules, overriding `rule` where present
+    pub(crate) rule_map: Option<RuleMap>,
+    /// How the next generation is computed
+    backend: Backend,
     /// Private array of Cells
-    cells: Vec<Cell>,
+    pub(crate) cells: Vec<Cell>,
+    /// Cells that may change in the next generation
+    frontier: Frontier,
+    /// Cells that keep their state no matter what their neighbors do
+    pub(crate) frozen: Vec<bool>,
+    /// Past generations, for stepping back
+    pub(crate) history: History,
+    /// How many generations the board has been advanced
+    generation: usize,
+    /// Scratch space for the changes of the next generation, kept around
+    /// so stepping doesn't allocate every cycle
+    updates: Vec<(usize, usize, Cell)>,
+    /// The cells changed by the most recent generation
+    pub(crate) changes: Vec<(usize, usize, Cell)>,
+}
+
+// Under a fixed rule a cell can only change if it or one of its neighbors
+// changed in the previous generation, so only those cells need to be
+// evaluated. Anything that could break that assumption (another rule,
+// a new radius or border) marks every cell dirty again.
+#[derive(Clone)]
+struct Frontier {
+    dirty: Vec<bool>,
+    // The border the frontier was built under, since `Board::border` can
+    // be changed directly
+    border: BorderOpt,
+}
+
+impl Frontier {
+    fn new(len: usize, border: BorderOpt) -> Self {
+        Frontier {
+            dirty: vec![true; len],
+            border,
+        }
+    }
+}
+
+impl Debug for Frontier {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let dirty = self.dirty.iter().filter(|&&d| d).count();
+        write!(f, "Frontier {{ dirty: {} cells }}", dirty)
+    }
 }
 
 impl Board {
@@ -46,13 +209,297 @@ impl Board {
     /// assert!(board.width == 5 && board.height == 5 && board.border == BorderOpt::Empty);
     /// assert_eq!(board.get(2,2), Cell::Dead);
     /// ```
+    ///
+    /// # Panics:
+    ///
+    /// If `width * height` overflows `usize`, see [`Board::try_new`]
     pub fn new(width: usize, height: usize, border: BorderOpt) -> Self {
-        Board {
+        Self::from_cells(
+            width,
+            height,
+            border,
+            vec![Cell::Dead; Self::cell_count(width, height)],
+        )
+    }
+
+    /// Initialize a new board, failing cleanly if it would have more
+    /// cells than a `usize` can count
+    ///
+    /// # Errors:
+    ///
+    /// If `width * height` overflows `usize`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// assert!(Board::try_new(5, 5, BorderOpt::Empty).is_ok());
+    /// assert!(Board::try_new(usize::MAX, 2, BorderOpt::Empty).is_err());
+    /// ```
+    pub fn try_new(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+    ) -> Result<Self, DimensionsTooLarge> {
+        let len = width
+            .checked_mul(height)
+            .ok_or(DimensionsTooLarge { width, height })?;
+
+        Ok(Self::from_cells(
+            width,
+            height,
+            border,
+            vec![Cell::Dead; len],
+        ))
+    }
+
+    /// Initialize a new board, rejecting dimensions no useful board has
+    /// before allocating anything
+    ///
+    /// Like [`Board::try_new`], but a zero width or height is an error
+    /// too, so sizes taken from user input can be checked in one place.
+    /// Use [`Board::new_checked_with_limit`] to also cap the cell count.
+    ///
+    /// # Errors:
+    ///
+    /// - If `width` or `height` is 0 ([`BoardError::ZeroSized`])
+    /// - If `width * height` overflows `usize` ([`BoardError::TooLarge`])
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// assert!(Board::new_checked(5, 5, BorderOpt::Empty).is_ok());
+    /// assert_eq!(
+    ///     Board::new_checked(0, 5, BorderOpt::Empty).unwrap_err().to_string(),
+    ///     "a 0x5 board has no cells"
+    /// );
+    /// ```
+    pub fn new_checked(width: usize, height: usize, border: BorderOpt) -> Result<Self, BoardError> {
+        Self::new_checked_with_limit(width, height, border, usize::MAX)
+    }
+
+    /// Like [`Board::new_checked`], but also reject boards with more than
+    /// `max_cells` cells, e.g. to bound the memory a request can use
+    ///
+    /// # Errors:
+    ///
+    /// Like [`Board::new_checked`], or [`BoardError::OverLimit`] if the
+    /// board would have more than `max_cells` cells
+    pub fn new_checked_with_limit(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+        max_cells: usize,
+    ) -> Result<Self, BoardError> {
+        if width == 0 || height == 0 {
+            return Err(BoardError::ZeroSized { width, height });
+        }
+        let len = width
+            .checked_mul(height)
+            .ok_or(DimensionsTooLarge { width, height })?;
+        if len > max_cells {
+            return Err(BoardError::OverLimit {
+                width,
+                height,
+                max_cells,
+            });
+        }
+
+        Ok(Self::from_cells(
             width,
             height,
             border,
-            cells: vec![Cell::Dead; width * height],
+            vec![Cell::Dead; len],
+        ))
+    }
+
+    /// Initialize a new board that advances using `backend`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::with_backend(64, 64, BorderOpt::Loop, Backend::Bitboard);
+    /// assert_eq!(board.backend(), Backend::Bitboard);
+    /// ```
+    pub fn with_backend(width: usize, height: usize, border: BorderOpt, backend: Backend) -> Self {
+        let mut board = Self::new(width, height, border);
+        board.backend = backend;
+        board
+    }
+
+    /// Initialize a new board with randomly placed live cells
+    ///
+    /// Each cell is alive with probability `density` (clamped to
+    /// `0.0..=1.0`). The same `seed` always produces the same board.
+    ///
+    /// # Panics:
+    ///
+    /// If `width * height` overflows `usize`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let a = Board::new_random(8, 8, BorderOpt::Empty, 0.5, 42);
+    /// let b = Board::new_random(8, 8, BorderOpt::Empty, 0.5, 42);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn new_random(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+        density: f64,
+        seed: u64,
+    ) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let cells = (0..Self::cell_count(width, height))
+            .map(|_| {
+                if rng.next_f64() < density {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        Self::from_cells(width, height, border, cells)
+    }
+
+    /// Initialize a new board with randomly placed live cells, seeded by
+    /// a memorable `phrase` instead of a number
+    ///
+    /// The phrase is hashed with 64-bit FNV-1a into a seed for
+    /// [`Board::new_random`]. The hash doesn't depend on the platform or
+    /// compiler version, so a phrase always gives the same board and can
+    /// be shared.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let a = Board::from_seed_phrase(16, 16, BorderOpt::Loop, "hello world", 0.4);
+    /// let b = Board::from_seed_phrase(16, 16, BorderOpt::Loop, "hello world", 0.4);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn from_seed_phrase(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+        phrase: &str,
+        density: f64,
+    ) -> Self {
+        let seed = phrase.bytes().fold(FNV_OFFSET_BASIS, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+        });
+
+        Self::new_random(width, height, border, density, seed)
+    }
+
+    /// Initialize a new board where the cell at `x`, `y` is alive with
+    /// probability `f(x, y)`
+    ///
+    /// Probabilities outside `0.0..=1.0` act like the nearest bound. The
+    /// same `seed` and `f` always produce the same board, and a constant
+    /// `f` gives the same board as [`Board::new_random`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // Denser towards the bottom
+    /// let board = Board::from_probability_field(8, 8, BorderOpt::Empty, 1, |_, y| {
+    ///     y as f64 / 7.0
+    /// });
+    /// assert_eq!(board.live_in_rect(0, 0, 8, 1), 0);
+    /// assert_eq!(board.live_in_rect(0, 7, 8, 1), 8);
+    /// ```
+    pub fn from_probability_field<F: Fn(usize, usize) -> f64>(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+        seed: u64,
+        f: F,
+    ) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let cells = (0..Self::cell_count(width, height))
+            .map(|i| {
+                if rng.next_f64() < f(i % width, i / width) {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        Self::from_cells(width, height, border, cells)
+    }
+
+    /// Initialize a new board from a hand-drawn density map, where each
+    /// cell is a digit from `0` to `9` and is alive with probability
+    /// `digit / 9`
+    ///
+    /// So `0` is always dead, `9` always alive, and the digits between
+    /// sketch out sparse and dense areas of a soup. The width is the
+    /// length of the first row. Whitespace around rows and blank lines
+    /// are ignored. The same `seed` always produces the same board.
+    ///
+    /// # Errors:
+    ///
+    /// - If a row is longer or shorter than the first
+    /// - If a row contains anything other than digits
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::from_density_ascii("0990\n0550\n", BorderOpt::Empty, 3).unwrap();
+    /// assert_eq!(board.live_in_rect(0, 0, 4, 1), 2);
+    /// assert_eq!(board.get(0, 1), Cell::Dead);
+    /// ```
+    pub fn from_density_ascii(art: &str, border: BorderOpt, seed: u64) -> Result<Self, ParseError> {
+        let mut densities: Vec<f64> = vec![];
+        let (mut width, mut height) = (0, 0);
+
+        for (row, line) in art
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .enumerate()
+        {
+            let len = line.chars().count();
+            width = if row == 0 { len } else { width };
+            if len != width {
+                return Err(ParseError {
+                    row,
+                    message: format!("{} columns, expected {}", len, width),
+                });
+            }
+            for (column, c) in line.chars().enumerate() {
+                let digit = c.to_digit(10).ok_or_else(|| ParseError {
+                    row,
+                    message: format!("invalid character {:?} in column {}", c, column),
+                })?;
+                densities.push(f64::from(digit) / 9.0);
+            }
+            height += 1;
         }
+
+        Ok(Self::from_probability_field(
+            width,
+            height,
+            border,
+            seed,
+            |x, y| densities[y * width + x],
+        ))
     }
 
     /// Initialize new board from the file at `path`.
@@ -64,14 +511,20 @@ impl Board {
     ///
     /// - `empty`
     /// - `solid`
+    /// - `loop`
     ///
     /// Followed by lines consisting of `#` (alive) and `_` (dead)
-    /// characters.
+    /// characters. Lines may end in `\n`, `\r\n` or `\r`.
+    ///
+    /// Lines starting with `!` are comments. The ones written by
+    /// [`Board::save_with_metadata`] restore the board's generation,
+    /// rule and border, any others are ignored.
     ///
     /// # Panics:
     ///
     /// - If the file is invalid or non-existent
     /// - If the length of a line exceeds the width of the first line
+    /// - If a generation or rule comment can't be parsed
     ///
     /// # Example:
     /// ```
@@ -83,19 +536,23 @@ impl Board {
     /// assert_eq!(board.get(2, 1), Cell::Alive);
     /// ```
     pub fn new_from_file(path: &str) -> Self {
-        let file = match File::open(Path::new(path)) {
+        let contents = match fs::read_to_string(Path::new(path)) {
             Err(why) => panic!("Error opening file{}: {}", path, why),
-            Ok(file) => file,
+            Ok(contents) => contents,
         };
         let mut cells: Vec<Cell> = vec![];
-        let mut line_iter = BufReader::new(file).lines();
+        let mut line_iter = Self::split_lines(&contents).into_iter();
         let (mut width, mut height) = (0, 0);
-        let border_str = line_iter.next().unwrap().unwrap();
-        let border = Self::parse_str_as_border_opt(&border_str).unwrap_or(BorderOpt::Empty);
+        let border_str = line_iter.next().unwrap();
+        let border = Self::parse_str_as_border_opt(border_str).unwrap_or(BorderOpt::Empty);
+        let mut comments: Vec<String> = vec![];
 
         line_iter.enumerate().for_each(|(_i, l)| {
-            let l = l.unwrap();
             let l = l.trim();
+            if l.starts_with(FILE_COMMENT_CHAR) {
+                comments.push(l.to_string());
+                return;
+            }
             width = if width == 0 { l.len() } else { width };
             if l.len() != width {
                 panic!("row {} is length {}, expected {}", _i, l.len(), width);
@@ -104,400 +561,2454 @@ impl Board {
             height += 1;
         });
 
-        Board {
-            width,
-            height,
-            cells,
-            border,
-        }
-    }
-
-    /// Advance board state by one cycle
-    pub fn advance_cycle(&mut self) {
-        let mut updates: Vec<(usize, usize, Cell)> = vec![];
-
-        (0..self.width).for_each(|x| {
-            (0..self.height).for_each(|y| match (self.cell_should_live(x, y), self.get(x, y)) {
-                (true, Cell::Dead) => updates.push((x, y, Cell::Alive)),
-                (false, Cell::Alive) => updates.push((x, y, Cell::Dead)),
-                _ => (),
-            })
+        let mut board = Self::from_cells(width, height, border, cells);
+        comments.iter().for_each(|c| {
+            if let Err(why) = board.apply_metadata(c) {
+                panic!("Error reading file {}: {}", path, why)
+            }
         });
 
-        updates.iter().for_each(|&(x, y, cell)| {
-            self.set(x, y, cell);
-        });
+        board
     }
 
-    /// Advance board state by n cycles
-    pub fn advance_n_cycles(&mut self, n: usize) {
-        (0..n).for_each(|_| self.advance_cycle())
+    /// The board in the format read by [`Board::new_from_file`], with
+    /// comments recording its generation, rule and border
+    ///
+    /// A rule with counts above 8 doesn't fit `B.../S...` notation (see
+    /// [`Rule::has_digit_notation`]), so it's left out and reads back as
+    /// Conway's.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::with_rule(3, 3, BorderOpt::Empty, Rule::parse("B36/S23").unwrap());
+    /// board.fill_rect(0, 1, 3, 1, Cell::Alive);
+    /// board.advance_n_cycles(2);
+    ///
+    /// assert_eq!(
+    ///     board.to_string_with_metadata(),
+    ///     "empty\n!generation: 2\n!rule: B36/S23\n!border: empty\n___\n###\n___\n"
+    /// );
+    /// ```
+    pub fn to_string_with_metadata(&self) -> String {
+        let border = Self::border_opt_as_str(&self.border);
+        let mut out = format!(
+            "{}\n{}generation: {}\n",
+            border, FILE_COMMENT_CHAR, self.generation
+        );
+        if self.rule.has_digit_notation() {
+            out.push_str(&format!("{}rule: {}\n", FILE_COMMENT_CHAR, self.rule));
+        }
+        out.push_str(&format!("{}border: {}\n", FILE_COMMENT_CHAR, border));
+        self.cells.chunks(self.width.max(1)).for_each(|row| {
+            row.iter().for_each(|&c| {
+                out.push(if c == Cell::Alive {
+                    FILE_LIVE_CHAR
+                } else {
+                    FILE_DEAD_CHAR
+                } as char)
+            });
+            out.push('\n');
+        });
+
+        out
     }
 
-    /// Set cell at `x` and `y` to state `c`
+    /// Save the board to `path` as [`Board::to_string_with_metadata`], so
+    /// loading it with [`Board::new_from_file`] picks up where it left
+    /// off
     ///
-    /// # Panics:
+    /// # Errors:
     ///
-    /// If `x` or `y` are out of range
-    pub fn set(&mut self, x: usize, y: usize, c: Cell) {
-        let idx = self.to_idx(x, y);
-        self.cells[idx] = c;
+    /// If writing the file fails
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// board.advance_n_cycles(3);
+    /// let path = std::env::temp_dir().join("yagoll-metadata-doc.txt");
+    ///
+    /// board.save_with_metadata(&path).unwrap();
+    /// let read = Board::new_from_file(path.to_str().unwrap());
+    /// assert_eq!(read.generation(), Generation(3));
+    /// assert_eq!(read, board);
+    /// ```
+    pub fn save_with_metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string_with_metadata())
     }
 
-    /// Get cell at `x` and `y`
+    /// Initialize new board from `reader`, refusing boards larger than
+    /// `max_width` by `max_height`
     ///
-    /// # Panics:
+    /// Reads the same format as [`Board::new_from_file`], but is meant for
+    /// untrusted input: no line is ever buffered past the width limit and
+    /// reading stops at the first row over the height limit, so a
+    /// malicious input can't make this allocate more than roughly
+    /// `max_width * max_height` cells.
     ///
-    /// If `x` or `y` are out of range
-    pub fn get(&self, x: usize, y: usize) -> Cell {
-        self.cells[self.to_idx(x, y)]
-    }
-}
+    /// # Errors:
+    ///
+    /// - If reading fails or the input isn't UTF-8
+    /// - If the border line is missing
+    /// - If a row is longer than `max_width` or there are more than
+    ///   `max_height` rows
+    /// - If a row's length differs from the first row's
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let input = "empty\n_#_\n_#_\n_#_\n";
+    /// let board = Board::from_reader_limited(input.as_bytes(), 3, 3).unwrap();
+    /// assert_eq!(board.get(1, 2), Cell::Alive);
+    ///
+    /// assert!(Board::from_reader_limited(input.as_bytes(), 2, 3).is_err());
+    /// ```
+    pub fn from_reader_limited<R: BufRead>(
+        mut reader: R,
+        max_width: usize,
+        max_height: usize,
+    ) -> io::Result<Self> {
+        let border_str = Self::read_limited_line(&mut reader, BORDER_LINE_LIMIT)?
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "missing border line"))?;
+        let border = Self::parse_str_as_border_opt(&border_str).unwrap_or(BorderOpt::Empty);
+        let mut cells: Vec<Cell> = vec![];
+        let mut comments: Vec<String> = vec![];
+        let (mut width, mut height) = (0, 0);
+        let line_limit = max_width.max(BORDER_LINE_LIMIT);
 
-impl Board {
-    fn to_idx(&self, x: usize, y: usize) -> usize {
-        if x >= self.width {
-            panic!("out of bounds: width is {} but x is {}", self.width, x);
-        } else if y >= self.height {
-            panic!("out of bounds: height is {} but y is {}", self.height, y);
+        while let Some(l) = Self::read_limited_line(&mut reader, line_limit)? {
+            if l.starts_with(FILE_COMMENT_CHAR) {
+                comments.push(l);
+                continue;
+            }
+            if l.len() > max_width {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("line is longer than {} characters", max_width),
+                ));
+            }
+            if height == max_height {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("more than {} rows", max_height),
+                ));
+            }
+            width = if width == 0 { l.len() } else { width };
+            if l.len() != width {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("row {} is length {}, expected {}", height, l.len(), width),
+                ));
+            }
+            cells.append(&mut Self::parse_str_as_cells(&l));
+            height += 1;
         }
-        ((y % self.height) * self.width) + x
-    }
 
-    // ___
-    // _X#
-    // _##
-    fn get_lower_right_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &[self.get(x + 1, y)][..],
-            &[self.get(x, y + 1), self.get(x + 1, y + 1)][..],
-        ]
-        .concat()
-        .to_vec()
-    }
+        let mut board = Self::from_cells(width, height, border, cells);
+        comments.iter().try_for_each(|c| {
+            board
+                .apply_metadata(c)
+                .map_err(|why| Error::new(ErrorKind::InvalidData, why))
+        })?;
 
-    // ###
-    // #X#
-    // ___
-    fn get_upper_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &self.cells[self.to_idx(x - 1, y - 1)..=self.to_idx(x + 1, y - 1)],
-            &[self.get(x - 1, y), self.get(x + 1, y)][..],
-        ]
-        .concat()
-        .to_vec()
+        Ok(board)
     }
 
-    // ___
-    // #X#
-    // ###
-    fn get_lower_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &[self.get(x - 1, y), self.get(x + 1, y)][..],
-            &self.cells[self.to_idx(x - 1, y + 1)..=self.to_idx(x + 1, y + 1)],
-        ]
-        .concat()
-        .to_vec()
+    /// Advance board state by one cycle
+    ///
+    /// Every cell's next state is decided from the current generation
+    /// before any cell changes, so the result doesn't depend on the order
+    /// cells are visited in. For the record, that's
+    /// [`ScanOrder::ColumnMajor`], which is also the order
+    /// [`Board::last_diff`] lists changes in on the default backend.
+    pub fn advance_cycle(&mut self) {
+        self.advance_cycle_summary();
     }
 
-    // _##
-    // _X#
-    // _##
-    fn get_right_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &self.cells[self.to_idx(x, y - 1)..=self.to_idx(x + 1, y - 1)],
-            &[self.get(x + 1, y)][..],
-            &self.cells[self.to_idx(x, y + 1)..=self.to_idx(x + 1, y + 1)],
-        ]
-        .concat()
-        .to_vec()
+    /// Like [`Board::advance_cycle`], but visit cells in `order`
+    ///
+    /// The board ends up the same either way; only the order
+    /// [`Board::last_diff`] lists changes in follows `order`. The bitboard
+    /// backend works on whole rows at once and always lists them row by
+    /// row.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(1, 0, 1, 3, Cell::Alive);
+    /// let expected = board.clone().advanced();
+    ///
+    /// board.advance_cycle_ordered(ScanOrder::RowMajor);
+    /// assert_eq!(board, expected);
+    /// assert_eq!(board.last_diff()[0], (1, 0, Cell::Dead));
+    /// ```
+    pub fn advance_cycle_ordered(&mut self, order: ScanOrder) {
+        self.advance_summary_in(order);
     }
 
-    // _##
-    // _X#
-    // ___
-    fn get_upper_right_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &[self.get(x, y - 1), self.get(x + 1, y - 1)][..],
-            &[self.get(x + 1, y)][..],
-        ]
-        .concat()
-        .to_vec()
+    /// Advance board state by one cycle, returning the generation the
+    /// board is now at
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// assert_eq!(board.step(), Generation(1));
+    /// ```
+    pub fn step(&mut self) -> Generation {
+        self.advance_cycle();
+        self.generation()
     }
 
-    // ___
-    // #X_
-    // ##_
-    fn get_lower_left_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &[self.get(x - 1, y)][..],
-            &[self.get(x - 1, y + 1), self.get(x, y + 1)][..],
-        ]
-        .concat()
-        .to_vec()
-    }
+    /// How many generations the board has been advanced since it was
+    /// created
+    ///
+    /// Every way of advancing the board counts, and stepping back
+    /// through the history counts down again.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.advance_n_cycles(2);
+    /// assert_eq!(board.generation(), Generation(2));
+    /// ```
+    pub fn generation(&self) -> Generation {
+        Generation(self.generation)
+    }
+
+    /// Advance board state by one cycle, returning how many cells were
+    /// `(born, killed)`
+    ///
+    /// The counts are tallied as cells are updated, so this costs no
+    /// more than [`Board::advance_cycle`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(1, 0, 1, 3, Cell::Alive);
+    /// assert_eq!(board.advance_cycle_summary(), (2, 2));
+    /// ```
+    pub fn advance_cycle_summary(&mut self) -> (usize, usize) {
+        self.advance_summary_in(ScanOrder::ColumnMajor)
+    }
+
+    // Advance one cycle, deciding cells in `order` on the scalar backend,
+    // and return how many cells were `(born, killed)`
+    fn advance_summary_in(&mut self, order: ScanOrder) -> (usize, usize) {
+        self.begin_generation();
+        let mut updates = mem::take(&mut self.updates);
+        updates.clear();
+
+        if self.backend == Backend::Bitboard
+            && self.radius == 1
+            && self.rule.is_conway()
+            && self.rule_map.is_none()
+        {
+            let next = bitboard::next_generation(self);
+            updates.extend(
+                next.into_iter()
+                    .enumerate()
+                    .filter(|&(i, c)| self.cells[i] != c)
+                    .map(|(i, c)| (i % self.width, i / self.width, c)),
+            );
+            let summary = self.apply_updates(&updates);
+            self.updates = updates;
+            self.invalidate_frontier();
+            return summary;
+        }
+        if self.frontier.border != self.border || self.frontier.dirty.len() != self.cells.len() {
+            self.invalidate_frontier();
+        }
+
+        order
+            .positions(self.width, self.height)
+            .filter(|&(x, y)| self.frontier.dirty[y * self.width + x])
+            .for_each(
+                |(x, y)| match (self.cell_should_live(x, y), self.get(x, y)) {
+                    (true, Cell::Dead) => updates.push((x, y, Cell::Alive)),
+                    (false, Cell::Alive) => updates.push((x, y, Cell::Dead)),
+                    _ => (),
+                },
+            );
+
+        self.frontier.dirty.iter_mut().for_each(|d| *d = false);
+        let summary = self.apply_updates(&updates);
+        self.updates = updates;
+
+        summary
+    }
+
+    /// Make room for at least `cap` cell changes per cycle up front
+    ///
+    /// The buffer [`Board::advance_cycle`] collects changes in is reused
+    /// from cycle to cycle and only grows when a generation changes more
+    /// cells than any before it. Reserving the expected number of changes
+    /// avoids even those reallocations in tight loops. Results are the
+    /// same either way.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_random(64, 64, BorderOpt::Loop, 0.4, 1);
+    /// board.reserve_updates(64 * 64);
+    /// board.advance_n_cycles(10);
+    /// ```
+    pub fn reserve_updates(&mut self, cap: usize) {
+        self.updates.reserve(cap);
+    }
+
+    /// The number of cells the next [`Board::advance_cycle`] will
+    /// evaluate
+    ///
+    /// The scalar backend only re-evaluates cells that could change, i.e.
+    /// cells that changed or had a neighbor change in the last
+    /// generation. Once a board settles this drops far below the total
+    /// number of cells.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 10, BorderOpt::Empty);
+    /// assert_eq!(board.dirty_cell_count(), 100);
+    ///
+    /// board.advance_cycle();
+    /// assert_eq!(board.dirty_cell_count(), 0);
+    /// ```
+    pub fn dirty_cell_count(&self) -> usize {
+        if self.frontier.border != self.border || self.frontier.dirty.len() != self.cells.len() {
+            return self.cells.len();
+        }
+        self.frontier.dirty.iter().filter(|&&d| d).count()
+    }
+
+    /// Advance board state by one cycle using a custom `rule`
+    ///
+    /// The rule is given each cell's current state and its number of
+    /// live neighbors and returns the cell's next state. All cells are
+    /// updated at once, just like [`Board::advance_cycle`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    ///
+    /// // Every cell with a live neighbor is born, nothing survives
+    /// board.advance_cycle_with(|_, n| if n > 0 { Cell::Alive } else { Cell::Dead });
+    /// assert_eq!(board.get(0, 0), Cell::Dead);
+    /// assert_eq!(board.get(1, 1), Cell::Alive);
+    /// ```
+    pub fn advance_cycle_with<F: Fn(Cell, usize) -> Cell>(&mut self, rule: F) {
+        self.begin_generation();
+        let mut updates: Vec<(usize, usize, Cell)> = vec![];
+
+        (0..self.width).for_each(|x| {
+            (0..self.height).for_each(|y| {
+                let cell = self.get(x, y);
+                let next = rule(cell, self.get_live_neighbor_count(x, y));
+                if next != cell {
+                    updates.push((x, y, next));
+                }
+            })
+        });
+
+        self.apply_updates(&updates);
+        // Cells the custom rule left alone may still change under Conway
+        self.invalidate_frontier();
+    }
+
+    /// Advance board state by one cycle using a custom `rule` that
+    /// produces raw cell states
+    ///
+    /// Like [`Board::advance_cycle_with`], except the rule returns `0`
+    /// for dead and `1` for alive. Every produced value is checked before
+    /// any cell is updated.
+    ///
+    /// # Errors:
+    ///
+    /// If the rule produces any other value, the first offending cell is
+    /// reported and the board is left unchanged.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// let err = board.try_advance_with(|_, n| n as u8 + 2).unwrap_err();
+    /// assert_eq!(err.value, 2);
+    /// ```
+    pub fn try_advance_with<F: Fn(Cell, usize) -> u8>(
+        &mut self,
+        rule: F,
+    ) -> Result<(), InvalidCellState> {
+        let mut updates: Vec<(usize, usize, Cell)> = vec![];
+
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let next = match rule(self.get(x, y), self.get_live_neighbor_count(x, y)) {
+                    0 => Cell::Dead,
+                    1 => Cell::Alive,
+                    value => return Err(InvalidCellState { x, y, value }),
+                };
+                if next != self.get(x, y) {
+                    updates.push((x, y, next));
+                }
+            }
+        }
+
+        self.begin_generation();
+        self.apply_updates(&updates);
+        self.invalidate_frontier();
+
+        Ok(())
+    }
+
+    /// Advance board state by n cycles
+    pub fn advance_n_cycles(&mut self, n: usize) {
+        (0..n).for_each(|_| self.advance_cycle())
+    }
+
+    /// Advance board state by one cycle, then flip each cell with
+    /// probability `flip_probability`, drawing from `rng`
+    ///
+    /// Models random defects ("cosmic rays") for testing how robust a
+    /// pattern is. Frozen cells are never flipped. A probability of 0.0
+    /// is the same as [`Board::advance_cycle`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(8, 8, BorderOpt::Empty);
+    /// let mut rng = SplitMix64::new(1);
+    ///
+    /// // Every cell flips, so the empty board comes back full
+    /// board.advance_with_noise(1.0, &mut rng);
+    /// assert_eq!(board.live_in_rect(0, 0, 8, 8), 64);
+    /// ```
+    pub fn advance_with_noise(&mut self, flip_probability: f64, rng: &mut SplitMix64) {
+        self.advance_cycle();
+
+        let updates: Vec<(usize, usize, Cell)> = (0..self.cells.len())
+            .filter(|_| rng.next_f64() < flip_probability)
+            .map(|i| {
+                let flipped = match self.cells[i] {
+                    Cell::Alive => Cell::Dead,
+                    Cell::Dead => Cell::Alive,
+                };
+                (i % self.width, i / self.width, flipped)
+            })
+            .collect();
+        self.apply_updates(&updates);
+        self.squash_changes();
+    }
+
+    /// Advance board state by one cycle, then set every cell on the edge
+    /// of the board to `source(x, y)`
+    ///
+    /// Models an open system with cells flowing in from outside, e.g. a
+    /// source that keeps feeding a pattern from one side. Frozen edge
+    /// cells are left alone.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.advance_with_edge_source(|x, _| if x == 0 { Cell::Alive } else { Cell::Dead });
+    /// assert_eq!(board.to_ascii(), "#..\n#..\n#..\n");
+    /// ```
+    pub fn advance_with_edge_source<F: Fn(usize, usize) -> Cell>(&mut self, source: F) {
+        self.advance_cycle();
+
+        let (w, h) = (self.width, self.height);
+        let updates: Vec<(usize, usize, Cell)> = (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .filter(|&(x, y)| x == 0 || y == 0 || x + 1 == w || y + 1 == h)
+            .map(|(x, y)| (x, y, source(x, y)))
+            .collect();
+        self.apply_updates(&updates);
+        self.squash_changes();
+    }
+
+    /// Advance board state by one cycle, suppressing births that would
+    /// take the population over `max_pop`
+    ///
+    /// Models growth limited by resources. Deaths and survivals happen as
+    /// usual; births are allowed in row-major scan order until the
+    /// population reaches the cap, and the rest are dropped. A board that
+    /// starts at or under the cap stays there. Frozen cells are left
+    /// alone, as always. See [`Board::advance_capped_ordered`] to pick
+    /// the order.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(2, 1, 1, 3, Cell::Alive);
+    ///
+    /// // The blinker would turn, but only one of its two births fits
+    /// board.advance_capped(2);
+    /// assert_eq!(board.to_ascii(), ".....\n.....\n.##..\n.....\n.....\n");
+    /// ```
+    pub fn advance_capped(&mut self, max_pop: usize) {
+        self.advance_capped_ordered(max_pop, ScanOrder::RowMajor);
+    }
+
+    /// Like [`Board::advance_capped`], but births are allowed in `order`
+    /// instead of row by row
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(1, 2, 3, 1, Cell::Alive);
+    ///
+    /// // Column by column, the birth above the blinker's center comes
+    /// // before the one below
+    /// board.advance_capped_ordered(2, ScanOrder::ColumnMajor);
+    /// assert_eq!(board.to_ascii(), ".....\n..#..\n..#..\n.....\n.....\n");
+    /// ```
+    pub fn advance_capped_ordered(&mut self, max_pop: usize, order: ScanOrder) {
+        let (mut births, deaths): (Vec<_>, Vec<_>) = order
+            .positions(self.width, self.height)
+            .filter(|&(x, y)| !self.is_frozen(x, y))
+            .filter_map(
+                |(x, y)| match (self.get(x, y), self.cell_should_live(x, y)) {
+                    (Cell::Dead, true) => Some((x, y, Cell::Alive)),
+                    (Cell::Alive, false) => Some((x, y, Cell::Dead)),
+                    _ => None,
+                },
+            )
+            .partition(|&(_, _, c)| c == Cell::Alive);
+        let survivors = self.population() - deaths.len();
+        births.truncate(max_pop.saturating_sub(survivors));
+
+        self.begin_generation();
+        self.apply_updates(&deaths);
+        self.apply_updates(&births);
+        self.invalidate_frontier();
+    }
+
+    /// The cells changed by the most recent generation, as
+    /// `(x, y, new_state)`
+    ///
+    /// Saves keeping the previous generation around just to see what
+    /// changed, e.g. for drawing only the changed cells. Setting cells
+    /// directly doesn't count as a generation, and stepping back clears
+    /// the list. The order of the entries is unspecified.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 2, 2, Cell::Alive);
+    /// board.set(0, 0, Cell::Dead);
+    ///
+    /// board.advance_cycle();
+    /// assert_eq!(board.last_diff(), &[(0, 0, Cell::Alive)]);
+    /// ```
+    pub fn last_diff(&self) -> &[(usize, usize, Cell)] {
+        &self.changes
+    }
+
+    /// An endless iterator that advances the board by one cycle on each
+    /// call to `next` and yields that generation's [`Board::last_diff`]
+    ///
+    /// Nothing is computed until asked for, so long runs can be processed
+    /// change by change and cut short with `take`, `take_while` and
+    /// friends. The board stays borrowed while the iterator is alive.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_random(16, 16, BorderOpt::Loop, 0.4, 7);
+    ///
+    /// let busiest = board.change_stream().take(10).map(|diff| diff.len()).max();
+    /// assert!(busiest.unwrap() > 0);
+    /// assert_eq!(board.generation(), Generation(10));
+    /// ```
+    pub fn change_stream(&mut self) -> impl Iterator<Item = Vec<(usize, usize, Cell)>> + '_ {
+        std::iter::from_fn(move || {
+            self.advance_cycle();
+            Some(self.changes.clone())
+        })
+    }
+
+    /// Consume the board and return it advanced by one cycle
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new(3, 3, BorderOpt::Empty);
+    /// let next = board.advanced();
+    /// assert_eq!(next.get(1, 1), Cell::Dead);
+    /// ```
+    pub fn advanced(mut self) -> Self {
+        self.advance_cycle();
+        self
+    }
+
+    /// Consume the board and return it advanced by n cycles
+    pub fn advanced_n(mut self, n: usize) -> Self {
+        self.advance_n_cycles(n);
+        self
+    }
+
+    /// Find when the board settles into a repeating state
+    ///
+    /// Simulates a copy of the board for up to `max_cycles` cycles and
+    /// returns `Some((start, period))` once a state repeats, where
+    /// `start` is the generation the cycle begins at, counted like
+    /// [`Board::generation`], and `period` its length (1 for still lifes
+    /// and extinct boards). A board already on its cycle has `start`
+    /// equal to its own generation. Returns `None` if no repeat is seen
+    /// in time. `self` is left untouched.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.set(1, 2, Cell::Alive);
+    ///
+    /// assert_eq!(board.detect_stability(10), Some((Generation(0), 2)));
+    /// ```
+    pub fn detect_stability(&self, max_cycles: usize) -> Option<(Generation, usize)> {
+        let mut board = self.clone();
+        let mut seen: HashMap<Vec<Cell>, usize> = HashMap::new();
+
+        for generation in 0..=max_cycles {
+            if let Some(&start) = seen.get(&board.cells) {
+                return Some((Generation(self.generation + start), generation - start));
+            }
+            seen.insert(board.cells.clone(), generation);
+            board.advance_cycle();
+        }
+
+        None
+    }
+
+    /// Run the board for up to `max` generations, stopping as soon as it
+    /// dies out or starts repeating itself
+    ///
+    /// Extinction takes precedence, so an empty board is
+    /// [`SimOutcome::Extinct`] rather than a still life. Every generation
+    /// is remembered, like [`Board::detect_stability`]. Generations are
+    /// counted like [`Board::generation`], so they keep counting from
+    /// wherever the board already was. The board is left at the
+    /// generation where the outcome was recognized, which for still lifes
+    /// and oscillators is one full period past `gen`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.set(1, 2, Cell::Alive);
+    ///
+    /// assert_eq!(
+    ///     board.simulate(10),
+    ///     SimOutcome::Oscillator { period: 2, gen: Generation(0) }
+    /// );
+    /// ```
+    pub fn simulate(&mut self, max: usize) -> SimOutcome {
+        let mut seen: HashMap<Vec<Cell>, usize> = HashMap::new();
+
+        for step in 0..=max {
+            if self.cells.iter().all(|&c| c == Cell::Dead) {
+                return SimOutcome::Extinct(self.generation());
+            }
+            if let Some(&start) = seen.get(&self.cells) {
+                return match self.generation - start {
+                    1 => SimOutcome::StillLife(Generation(start)),
+                    period => SimOutcome::Oscillator {
+                        period,
+                        gen: Generation(start),
+                    },
+                };
+            }
+            if step == max {
+                break;
+            }
+            seen.insert(self.cells.clone(), self.generation);
+            self.advance_cycle();
+        }
+
+        SimOutcome::Ongoing
+    }
+
+    /// The period of the board, if the whole board repeats itself within
+    /// `max` generations
+    ///
+    /// Unlike asking whether the board has settled, this also recognizes
+    /// oscillating backgrounds ("agar") where no single cell is static,
+    /// as long as the board is already on its cycle. A still board has a
+    /// period of 1.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.set(1, 2, Cell::Alive);
+    ///
+    /// assert_eq!(board.detect_background_period(4), Some(2));
+    /// ```
+    pub fn detect_background_period(&self, max: usize) -> Option<usize> {
+        match self.detect_stability(max) {
+            Some((start, period)) if start == self.generation() => Some(period),
+            _ => None,
+        }
+    }
+
+    /// Which phase of the oscillator `reference` this board is in, if
+    /// any
+    ///
+    /// Phase `i` is `reference` advanced `i` cycles, for `i` in
+    /// `0..period`. Only the cells are compared, so the boards must be the
+    /// same size but may differ in border or other settings; `reference`
+    /// is advanced under its own settings. Returns `None` if no phase
+    /// matches.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut blinker = Board::new(3, 3, BorderOpt::Empty);
+    /// blinker.fill_rect(1, 0, 1, 3, Cell::Alive);
+    ///
+    /// let board = blinker.clone().advanced();
+    /// assert_eq!(board.matches_any_phase(&blinker, 2), Some(1));
+    /// ```
+    pub fn matches_any_phase(&self, reference: &Board, period: usize) -> Option<usize> {
+        if self.width != reference.width || self.height != reference.height {
+            return None;
+        }
+        let mut phase = reference.clone();
+
+        (0..period).find(|&i| {
+            if i > 0 {
+                phase.advance_cycle();
+            }
+            phase.cells == self.cells
+        })
+    }
+
+    /// Find the board's cycle using Brent's cycle-detection algorithm
+    ///
+    /// Unlike [`Board::detect_stability`], which remembers every
+    /// generation, this never keeps more than two copies of the board
+    /// besides itself around, so it is suited to long runs on large
+    /// boards. Generations are compared by [`Board::grid_hash`] first and
+    /// confirmed with a full comparison, so hash collisions can't produce
+    /// false cycles.
+    ///
+    /// At most `max` generations are simulated while searching. On
+    /// success the board is left at the first generation of the cycle,
+    /// `start`, counted like [`Board::generation`]. If no cycle is found
+    /// the period is 0 and the board is left `max` generations on, at
+    /// `start`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.set(1, 2, Cell::Alive);
+    ///
+    /// let cycle = board.run_detect_cycle(10);
+    /// assert_eq!(cycle, CycleInfo { start: Generation(0), period: 2 });
+    /// ```
+    pub fn run_detect_cycle(&mut self, max: usize) -> CycleInfo {
+        let mut tortoise = self.clone();
+        let mut tortoise_hash = tortoise.grid_hash();
+        let mut hare = self.clone().advanced();
+        let (mut power, mut period) = (1, 1);
+
+        let mut generation = 1;
+        while !(hare.grid_hash() == tortoise_hash && hare.cells == tortoise.cells) {
+            if generation >= max {
+                *self = hare;
+                return CycleInfo {
+                    start: self.generation(),
+                    period: 0,
+                };
+            }
+            if power == period {
+                tortoise = hare.clone();
+                tortoise_hash = tortoise.grid_hash();
+                power *= 2;
+                period = 0;
+            }
+            hare.advance_cycle();
+            generation += 1;
+            period += 1;
+        }
+        drop(tortoise);
+
+        // The board itself walks up to the start of the cycle, a period
+        // behind the hare
+        let mut hare = self.clone().advanced_n(period);
+        while !(hare.grid_hash() == self.grid_hash() && hare.cells == self.cells) {
+            self.advance_cycle();
+            hare.advance_cycle();
+        }
+
+        CycleInfo {
+            start: self.generation(),
+            period,
+        }
+    }
+
+    /// A hash of the board's dimensions and cells
+    ///
+    /// Equal boards always hash equally, so this is a cheap way to tell
+    /// generations apart. The value is only stable within one build of
+    /// the program and shouldn't be persisted.
+    pub fn grid_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.width.hash(&mut hasher);
+        self.height.hash(&mut hasher);
+        self.cells.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Set how far away a cell can be and still count as a neighbor
+    ///
+    /// The default radius of 1 is the classic 3×3 Moore neighborhood. A
+    /// radius of `r` counts every cell in the `(2r+1)`×`(2r+1)` block
+    /// centered on a cell, except the cell itself. On `Loop` boards the
+    /// block wraps around every edge.
+    ///
+    /// # Panics:
+    ///
+    /// - If `radius` is 0
+    /// - If the border is `Loop` and the block is wider or taller than
+    ///   the board, since it would then wrap onto itself
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 10, BorderOpt::Loop);
+    /// board.set_neighbor_radius(2);
+    /// assert_eq!(board.neighbor_radius(), 2);
+    /// ```
+    pub fn set_neighbor_radius(&mut self, radius: usize) {
+        if radius == 0 {
+            panic!("neighbor radius must be at least 1");
+        }
+        let span = 2 * radius + 1;
+        if self.border == BorderOpt::Loop && (span > self.width || span > self.height) {
+            panic!(
+                "radius {} spans {}x{} cells, larger than the {}x{} Loop board",
+                radius, span, span, self.width, self.height
+            );
+        }
+        self.radius = radius;
+        self.invalidate_frontier();
+    }
+
+    /// Change the border behavior, taking effect from the next cycle
+    ///
+    /// # Panics:
+    ///
+    /// If `border` is `Loop` and the neighbor block is wider or taller
+    /// than the board, see [`Board::set_neighbor_radius`]
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set_border(BorderOpt::Solid);
+    ///
+    /// // The solid border alone is enough to bring edge cells to life
+    /// board.advance_cycle();
+    /// assert_eq!(board.get(1, 0), Cell::Alive);
+    /// ```
+    pub fn set_border(&mut self, border: BorderOpt) {
+        let span = 2 * self.radius + 1;
+        if border == BorderOpt::Loop && (span > self.width || span > self.height) {
+            panic!(
+                "radius {} spans {}x{} cells, larger than the {}x{} Loop board",
+                self.radius, span, span, self.width, self.height
+            );
+        }
+        self.border = border;
+        self.invalidate_frontier();
+    }
+
+    /// Switch to the next border behavior, going from `Empty` to `Solid`
+    /// to `Loop` and back to `Empty`
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::set_border`], if switching to `Loop` on a board too
+    /// small for the neighbor radius
+    pub fn cycle_border(&mut self) {
+        let next = match self.border {
+            BorderOpt::Empty => BorderOpt::Solid,
+            BorderOpt::Solid => BorderOpt::Loop,
+            BorderOpt::Loop => BorderOpt::Empty,
+        };
+        self.set_border(next);
+    }
+
+    /// Get the neighbor radius
+    pub fn neighbor_radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Switch the backend used to compute the next generation
+    pub fn set_backend(&mut self, backend: Backend) {
+        self.backend = backend;
+        self.invalidate_frontier();
+    }
+
+    /// Get the backend used to compute the next generation
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Set the rule [`Board::advance_cycle`] and everything built on it
+    /// follow from now on
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    ///
+    /// // Every cell survives, even alone
+    /// board.set_rule(Rule::new(&[3], &[0, 1, 2, 3, 4, 5, 6, 7, 8]));
+    /// board.advance_cycle();
+    /// assert_eq!(board.get(1, 1), Cell::Alive);
+    /// ```
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+        self.invalidate_frontier();
+    }
+
+    /// Get the rule the board advances under
+    ///
+    /// Cells covered by a rule map follow their own rule instead, see
+    /// [`Board::rule_at`].
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Give every cell its own rule, so different regions of the board
+    /// advance under different rules
+    ///
+    /// `ids` holds one entry per cell in row-major order, picking the
+    /// cell's rule from `palette`. The map takes precedence over
+    /// [`Board::set_rule`] until it's removed with
+    /// [`Board::clear_rule_map`].
+    ///
+    /// # Panics:
+    ///
+    /// If `ids` doesn't have exactly one entry per cell, or an id is
+    /// outside `palette`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // Conway on the left, HighLife on the right
+    /// let mut board = Board::new(4, 2, BorderOpt::Empty);
+    /// let ids = (0..8).map(|i| RuleId(i % 4 / 2)).collect();
+    /// board.set_rule_map(vec![Rule::conway(), Rule::parse("B36/S23").unwrap()], ids);
+    ///
+    /// assert!(board.rule_at(1, 0).is_conway());
+    /// assert_eq!(board.rule_at(2, 1).to_string(), "B36/S23");
+    /// ```
+    pub fn set_rule_map(&mut self, palette: Vec<Rule>, ids: Vec<RuleId>) {
+        if ids.len() != self.cells.len() {
+            panic!(
+                "rule map has {} entries, expected {} for a {}x{} board",
+                ids.len(),
+                self.cells.len(),
+                self.width,
+                self.height
+            );
+        }
+        if let Some(id) = ids.iter().find(|id| id.0 >= palette.len()) {
+            panic!(
+                "rule id {} is outside the palette of {} rules",
+                id.0,
+                palette.len()
+            );
+        }
+
+        self.rule_map = Some(RuleMap { palette, ids });
+        self.invalidate_frontier();
+    }
+
+    /// Remove the rule map, so every cell follows [`Board::rule`] again
+    pub fn clear_rule_map(&mut self) {
+        self.rule_map = None;
+        self.invalidate_frontier();
+    }
+
+    /// Get the rule the cell at `x` and `y` advances under
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn rule_at(&self, x: usize, y: usize) -> &Rule {
+        match &self.rule_map {
+            Some(map) => map.rule(self.to_idx(x, y)),
+            None => &self.rule,
+        }
+    }
+
+    /// Set cell at `x` and `y` to state `c`
+    ///
+    /// `x` is the column and `y` the row, counted from the top left
+    /// corner.
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn set(&mut self, x: usize, y: usize, c: Cell) {
+        let idx = self.to_idx(x, y);
+        if self.cells[idx] != c {
+            self.cells[idx] = c;
+            self.mark_dirty_around(x, y);
+        }
+    }
+
+    /// Set many cells at once, each given as `(x, y, state)`
+    ///
+    /// Every coordinate is checked before anything changes, so on error
+    /// the board is left untouched. Later entries win over earlier ones
+    /// for the same cell.
+    ///
+    /// # Errors:
+    ///
+    /// [`OutOfBounds`] with the first coordinate outside the board
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set_many(&[(0, 0, Cell::Alive), (2, 2, Cell::Alive)]).unwrap();
+    /// assert_eq!(board.to_ascii(), "#..\n...\n..#\n");
+    ///
+    /// let err = board.set_many(&[(1, 1, Cell::Alive), (3, 0, Cell::Alive)]);
+    /// assert_eq!(err, Err(OutOfBounds { x: 3, y: 0 }));
+    /// assert_eq!(board.get(1, 1), Cell::Dead);
+    /// ```
+    pub fn set_many(&mut self, cells: &[(usize, usize, Cell)]) -> Result<(), OutOfBounds> {
+        if let Some(&(x, y, _)) = cells
+            .iter()
+            .find(|&&(x, y, _)| x >= self.width || y >= self.height)
+        {
+            let to_i64 = |v: usize| v.min(i64::MAX as usize) as i64;
+            return Err(OutOfBounds {
+                x: to_i64(x),
+                y: to_i64(y),
+            });
+        }
+
+        cells.iter().for_each(|&(x, y, c)| self.set(x, y, c));
+
+        Ok(())
+    }
+
+    /// Set every cell to `f(x, y)`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // Vertical stripes
+    /// let mut board = Board::new(4, 2, BorderOpt::Empty);
+    /// board.seed_with(|x, _| if x % 2 == 0 { Cell::Alive } else { Cell::Dead });
+    /// assert_eq!(board.to_ascii(), "#.#.\n#.#.\n");
+    /// ```
+    pub fn seed_with<F: Fn(usize, usize) -> Cell>(&mut self, f: F) {
+        (0..self.height).for_each(|y| (0..self.width).for_each(|x| self.set(x, y, f(x, y))));
+    }
+
+    /// Check the board's internal bookkeeping for consistency
+    ///
+    /// Every per-cell buffer must hold exactly `width * height` entries.
+    /// This can't fail through the public API; it's meant for catching
+    /// bugs in debug builds and tests.
+    ///
+    /// # Errors:
+    ///
+    /// A description of the first inconsistency found
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// assert_eq!(Board::new(7, 3, BorderOpt::Loop).validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let expected = self
+            .width
+            .checked_mul(self.height)
+            .ok_or_else(|| format!("{}x{} overflows", self.width, self.height))?;
+
+        let rule_map = self
+            .rule_map
+            .as_ref()
+            .map(|map| ("rule map", map.ids.len()));
+        [
+            Some(("cells", self.cells.len())),
+            Some(("frozen mask", self.frozen.len())),
+            rule_map,
+        ]
+        .iter()
+        .flatten()
+        .try_for_each(|&(name, len)| {
+            if len == expected {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} has {} entries, expected {} for a {}x{} board",
+                    name, len, expected, self.width, self.height
+                ))
+            }
+        })
+    }
+
+    /// Get cell at `x` and `y`
+    ///
+    /// `x` is the column and `y` the row, counted from the top left
+    /// corner.
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[self.to_idx(x, y)]
+    }
+
+    /// Get the cell a neighbor at `x` and `y` would see, even if that's
+    /// off the board
+    ///
+    /// On the board this is just [`Board::get`]. Off it, the border mode
+    /// decides: `Solid` is alive, `Empty` is dead and `Loop` wraps around
+    /// to the opposite side. Custom rules can build their own
+    /// neighborhoods on top of this, and it's a way to check what a
+    /// border change will do before advancing.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Loop);
+    /// board.set(2, 2, Cell::Alive);
+    /// assert_eq!(board.effective_neighbor(-1, -1), Cell::Alive);
+    ///
+    /// board.set_border(BorderOpt::Empty);
+    /// assert_eq!(board.effective_neighbor(-1, -1), Cell::Dead);
+    /// ```
+    #[doc(alias = "neighbor_value_at")]
+    pub fn effective_neighbor(&self, x: i64, y: i64) -> Cell {
+        Grid::neighbor(self, x, y)
+    }
+
+    /// The number of live neighbors of the cell at `x` and `y`, on or off
+    /// the board, counting everything off the board as dead
+    ///
+    /// Unlike the board's own stepping, this ignores the border mode and
+    /// always treats the board as a window onto an otherwise empty,
+    /// unbounded field. Simulators that grow the board themselves can ask
+    /// whether a cell just outside it is about to be born. The neighbor
+    /// radius is honored.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Loop);
+    /// board.fill_rect(0, 0, 3, 1, Cell::Alive);
+    ///
+    /// // The cell above the middle of the top row would be born
+    /// assert_eq!(board.live_neighbor_count_signed(1, -1), 3);
+    /// assert_eq!(board.live_neighbor_count_signed(1, -3), 0);
+    /// ```
+    pub fn live_neighbor_count_signed(&self, x: i64, y: i64) -> usize {
+        let r = self.radius as i64;
+        let (w, h) = (self.width as i64, self.height as i64);
+
+        (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (x + dx, y + dy)))
+            .filter(|&n| n != (x, y))
+            .filter(|&(nx, ny)| (0..w).contains(&nx) && (0..h).contains(&ny))
+            .filter(|&(nx, ny)| self.get(nx as usize, ny as usize) == Cell::Alive)
+            .count()
+    }
+}
+
+impl Board {
+    // Build a board around an already laid out row-major `cells` vector,
+    // with every other setting at its default
+    pub(crate) fn from_cells(
+        width: usize,
+        height: usize,
+        border: BorderOpt,
+        cells: Vec<Cell>,
+    ) -> Self {
+        Board {
+            frontier: Frontier::new(cells.len(), border.clone()),
+            width,
+            height,
+            border,
+            frozen: vec![false; cells.len()],
+            history: History::default(),
+            generation: 0,
+            updates: vec![],
+            changes: vec![],
+            radius: 1,
+            rule: Rule::default(),
+            rule_map: None,
+            backend: Backend::Scalar,
+            cells,
+        }
+    }
+
+    // Every way of advancing the board calls this first, to remember the
+    // generation being left behind and count the new one
+    pub(crate) fn begin_generation(&mut self) {
+        self.history.record(&self.cells);
+        self.generation += 1;
+        self.changes.clear();
+    }
+
+    // Merge changes made to the same cell by several passes over one
+    // generation, so each changed cell is listed once with its final
+    // state. Cells only have two states, so a cell changed an even number
+    // of times is back where it started.
+    fn squash_changes(&mut self) {
+        let mut flips: HashMap<(usize, usize), (usize, Cell)> = HashMap::new();
+        self.changes.iter().for_each(|&(x, y, cell)| {
+            let entry = flips.entry((x, y)).or_insert((0, cell));
+            *entry = (entry.0 + 1, cell);
+        });
+        self.changes = flips
+            .into_iter()
+            .filter(|&(_, (count, _))| count % 2 == 1)
+            .map(|((x, y), (_, cell))| (x, y, cell))
+            .collect();
+        self.changes.sort_by_key(|&(x, y, _)| (y, x));
+    }
+
+    // Undo the count of `n` generations stepped back through
+    pub(crate) fn rewind_generation(&mut self, n: usize) {
+        self.generation = self.generation.saturating_sub(n);
+        self.changes.clear();
+    }
+
+    // Count `n` generations advanced without going through
+    // `begin_generation`
+    pub(crate) fn skip_generations(&mut self, n: usize) {
+        self.generation += n;
+    }
+
+    // The number of cells on a `width`×`height` board, which every index
+    // into `cells` is below, so indexing arithmetic can't overflow either
+    pub(crate) fn cell_count(width: usize, height: usize) -> usize {
+        width.checked_mul(height).unwrap_or_else(|| {
+            panic!("{}", DimensionsTooLarge { width, height });
+        })
+    }
+
+    // Set each cell in `updates`, skipping frozen ones, and return how
+    // many cells were born and killed
+    pub(crate) fn apply_updates(&mut self, updates: &[(usize, usize, Cell)]) -> (usize, usize) {
+        let (mut born, mut killed) = (0, 0);
+
+        updates.iter().for_each(|&(x, y, cell)| {
+            if !self.frozen[self.to_idx(x, y)] && self.get(x, y) != cell {
+                self.set(x, y, cell);
+                self.changes.push((x, y, cell));
+                match cell {
+                    Cell::Alive => born += 1,
+                    Cell::Dead => killed += 1,
+                }
+            }
+        });
+
+        (born, killed)
+    }
+
+    // Forget which cells are settled, so the next scalar step evaluates
+    // every cell
+    pub(crate) fn invalidate_frontier(&mut self) {
+        self.frontier = Frontier::new(self.cells.len(), self.border.clone());
+    }
+
+    // Mark the cell at `x`, `y` and every cell that counts it as a
+    // neighbor as possibly changing next generation
+    fn mark_dirty_around(&mut self, x: usize, y: usize) {
+        let r = self.radius as i64;
+        let (w, h) = (self.width as i64, self.height as i64);
+
+        for dy in -r..=r {
+            for dx in -r..=r {
+                let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+                let (nx, ny) = match self.border {
+                    BorderOpt::Loop => (nx.rem_euclid(w), ny.rem_euclid(h)),
+                    _ if (0..w).contains(&nx) && (0..h).contains(&ny) => (nx, ny),
+                    _ => continue,
+                };
+                self.frontier.dirty[(ny * w + nx) as usize] = true;
+            }
+        }
+    }
+
+    fn to_idx(&self, x: usize, y: usize) -> usize {
+        if x >= self.width {
+            panic!("out of bounds: width is {} but x is {}", self.width, x);
+        } else if y >= self.height {
+            panic!("out of bounds: height is {} but y is {}", self.height, y);
+        }
+        // Both are in range, so this is below `width * height`, which
+        // `cell_count` checked when the board was made
+        y * self.width + x
+    }
+
+    // Every cell of the (2r+1)² block around `x`, `y` except the center
+    pub(crate) fn get_live_neighbor_count(&self, x: usize, y: usize) -> usize {
+        self.live_neighbors(x, y, self.radius)
+    }
+
+    pub(crate) fn cell_should_live(&self, x: usize, y: usize) -> bool {
+        self.next_cell(x, y, self.rule_at(x, y), self.radius) == Cell::Alive
+    }
+
+    // Split `contents` into lines ending in any of `\n`, `\r\n` or a
+    // lone `\r`, without a trailing empty line
+    fn split_lines(contents: &str) -> Vec<&str> {
+        let mut lines: Vec<&str> = contents
+            .split("\r\n")
+            .flat_map(|l| l.split(&['\n', '\r'][..]))
+            .collect();
+        if lines.last() == Some(&"") {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    // Read one trimmed line ending in any of `\n`, `\r\n` or a lone `\r`,
+    // like `split_lines`, failing rather than buffering more than
+    // `max_len` characters
+    fn read_limited_line<R: BufRead>(reader: &mut R, max_len: usize) -> io::Result<Option<String>> {
+        let too_long = || {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("line is longer than {} characters", max_len),
+            )
+        };
+        // Leave a little room for whitespace that's trimmed off
+        let limit = max_len.saturating_add(2);
+        let mut buf = vec![];
+
+        loop {
+            let available = reader.fill_buf()?;
+            if available.is_empty() {
+                if buf.is_empty() {
+                    return Ok(None);
+                }
+                break;
+            }
+            let end = available.iter().position(|&b| b == b'\n' || b == b'\r');
+            let len = end.unwrap_or(available.len());
+            if len > limit - buf.len() {
+                return Err(too_long());
+            }
+            buf.extend_from_slice(&available[..len]);
+            match end {
+                Some(i) => {
+                    let cr = available[i] == b'\r';
+                    reader.consume(i + 1);
+                    if cr && reader.fill_buf()?.first() == Some(&b'\n') {
+                        reader.consume(1);
+                    }
+                    break;
+                }
+                None => reader.consume(len),
+            }
+        }
+        let line = String::from_utf8(buf).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let line = line.trim();
+        if line.len() > max_len {
+            return Err(too_long());
+        }
+
+        Ok(Some(line.to_string()))
+    }
+
+    fn parse_str_as_cells(string: &str) -> Vec<Cell> {
+        let mut cell_row: Vec<Cell> = vec![];
+
+        string.bytes().for_each(|c| {
+            cell_row.push(match c {
+                FILE_LIVE_CHAR => Cell::Alive,
+                FILE_DEAD_CHAR => Cell::Dead,
+                _ => Cell::Dead,
+            })
+        });
+
+        cell_row
+    }
+
+    fn parse_str_as_border_opt(string: &str) -> Option<BorderOpt> {
+        match string {
+            "solid" => Some(BorderOpt::Solid),
+            "empty" => Some(BorderOpt::Empty),
+            "loop" => Some(BorderOpt::Loop),
+            _ => None,
+        }
+    }
+
+    fn border_opt_as_str(border: &BorderOpt) -> &'static str {
+        match border {
+            BorderOpt::Solid => "solid",
+            BorderOpt::Empty => "empty",
+            BorderOpt::Loop => "loop",
+        }
+    }
+
+    // Restore what a `!key: value` comment line records, ignoring
+    // comments that aren't metadata
+    fn apply_metadata(&mut self, comment: &str) -> Result<(), String> {
+        let (key, value) = match comment[1..].split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => return Ok(()),
+        };
+
+        match key {
+            "generation" => {
+                self.generation = value
+                    .parse()
+                    .map_err(|_| format!("invalid generation: {}", value))?
+            }
+            "rule" => self.set_rule(Rule::parse(value).map_err(|e| e.to_string())?),
+            // Usually just repeats the border line at the top
+            "border" => match Self::parse_str_as_border_opt(value) {
+                Some(border) if border != self.border => self.set_border(border),
+                _ => (),
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.border == other.border
+            && self.radius == other.radius
+            && self.rule == other.rule
+            && self.rule_map == other.rule_map
+            && self.backend == other.backend
+            && self.cells == other.cells
+            && self.frozen == other.frozen
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new(10, 10, BorderOpt::Empty)
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.cells.iter().enumerate().for_each(|(i, c)| {
+            if (i + 1) % self.width == 0 {
+                writeln!(f, "{}", c).unwrap()
+            } else {
+                write!(f, "{}", c).unwrap()
+            }
+        });
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::{BufReader, Read};
+
+    use super::*;
+
+    // ▓▓░░░░░░
+    // ░░▓▓░░░░
+    // ░░░░▓▓░░
+    // ░░░░░░▓▓
+    fn get_4x4_board() -> Board {
+        let mut board = Board::new(4, 4, BorderOpt::Empty);
+
+        board.set(0, 0, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+        board.set(2, 2, Cell::Alive);
+        board.set(3, 3, Cell::Alive);
+
+        board
+    }
 
-    // ##_
-    // #X_
-    // ##_
-    fn get_left_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &self.cells[self.to_idx(x - 1, y - 1)..=self.to_idx(x, y - 1)],
-            &[self.get(x - 1, y)][..],
-            &self.cells[self.to_idx(x - 1, y + 1)..=self.to_idx(x, y + 1)],
-        ]
-        .concat()
-        .to_vec()
+    // ░░▓▓░░
+    // ░░▓▓░░
+    // ░░▓▓░░
+    fn get_blinker_board() -> Board {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+
+        board.set(1, 0, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+        board.set(1, 2, Cell::Alive);
+
+        board
     }
 
-    // ##_
-    // #X_
-    // ___
-    fn get_upper_left_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &[self.get(x - 1, y - 1), self.get(x, y - 1)][..],
-            &[self.get(x - 1, y)][..],
-        ]
-        .concat()
-        .to_vec()
+    // ░░▓▓░░░░░░
+    // ░░░░▓▓░░░░
+    // ▓▓▓▓▓▓░░░░
+    // ░░░░░░░░░░
+    // ░░░░░░░░░░
+    fn get_glider_board() -> Board {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+
+        board.set(0, 1, Cell::Alive);
+        board.set(1, 2, Cell::Alive);
+        board.set(2, 0, Cell::Alive);
+        board.set(2, 1, Cell::Alive);
+        board.set(2, 2, Cell::Alive);
+
+        board
     }
 
-    // ###
-    // #X#
-    // ###
-    fn get_all_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        [
-            &self.cells[self.to_idx(x - 1, y - 1)..=self.to_idx(x + 1, y - 1)],
-            &[self.get(x - 1, y), self.get(x + 1, y)][..],
-            &self.cells[self.to_idx(x - 1, y + 1)..=self.to_idx(x + 1, y + 1)],
-        ]
-        .concat()
-        .to_vec()
+    fn get_rectangular_board() -> Board {
+        let mut board = Board::new(5, 3, BorderOpt::Empty);
+
+        board.set(1, 1, Cell::Alive);
+        board.set(2, 1, Cell::Alive);
+        board.set(3, 1, Cell::Alive);
+
+        board
+    }
+
+    fn get_file_board() -> Board {
+        Board::new_from_file("./tests/test-boards/glider.txt")
+    }
+
+    fn get_bad_file_board() -> Board {
+        Board::new_from_file("./tests/test-boards/bad-test.txt")
+    }
+
+    #[test]
+    fn init_default_board() {
+        let board = Board::default();
+        assert_eq!(
+            board,
+            Board {
+                width: 10,
+                height: 10,
+                border: BorderOpt::Empty,
+                radius: 1,
+                rule: Rule::default(),
+                rule_map: None,
+                backend: Backend::Scalar,
+                cells: vec![Cell::Dead; 10 * 10],
+                frontier: Frontier::new(10 * 10, BorderOpt::Empty),
+                frozen: vec![false; 10 * 10],
+                history: History::default(),
+                generation: 0,
+                updates: vec![],
+                changes: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn display_board() {
+        let board = get_4x4_board();
+
+        assert_eq!(
+            format!("{}", board),
+            "▓▓░░░░░░\n\
+             ░░▓▓░░░░\n\
+             ░░░░▓▓░░\n\
+             ░░░░░░▓▓\n"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn correct_neighbor_count() {
+        let board_4x4 = get_4x4_board();
+        let board_blinker = get_blinker_board();
+
+        assert_eq!(board_4x4.get_live_neighbor_count(0, 0), 1);
+        assert_eq!(board_4x4.get_live_neighbor_count(1, 1), 2);
+        assert_eq!(board_4x4.get_live_neighbor_count(3, 3), 1);
+
+        assert_eq!(board_blinker.get_live_neighbor_count(0, 0), 2);
+        assert_eq!(board_blinker.get_live_neighbor_count(1, 1), 2);
+        assert_eq!(board_blinker.get_live_neighbor_count(2, 1), 3);
+    }
+
+    #[test]
+    fn should_cell_live() {
+        let board = get_4x4_board();
+
+        assert!(!board.cell_should_live(0, 0));
+        assert!(board.cell_should_live(1, 1));
+        assert!(!board.cell_should_live(3, 3));
+    }
+
+    #[test]
+    fn blinker_should_blink() {
+        let mut board = get_blinker_board();
+
+        println!("{}", board);
+        board.advance_cycle();
+        println!("{}", board);
+
+        assert_eq!(
+            format!("{}", board),
+            "░░░░░░\n\
+             ▓▓▓▓▓▓\n\
+             ░░░░░░\n"
+                .to_string()
+        );
+
+        board.advance_cycle();
+        println!("{}", board);
+
+        assert_eq!(
+            format!("{}", board),
+            "░░▓▓░░\n\
+             ░░▓▓░░\n\
+             ░░▓▓░░\n"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn advanced_should_chain() {
+        let mut board = get_blinker_board();
+        let chained = get_blinker_board().advanced().advanced();
+
+        board.advance_cycle();
+        board.advance_cycle();
+
+        assert_eq!(chained, board);
+        assert_eq!(get_blinker_board().advanced_n(2), board);
+    }
+
+    #[test]
+    fn loop_radius_wraps_all_edges() {
+        let mut board = Board::new(6, 6, BorderOpt::Loop);
+
+        board.set_neighbor_radius(2);
+        board.set(0, 0, Cell::Alive);
+
+        assert_eq!(board.get_live_neighbor_count(0, 0), 0);
+        assert_eq!(board.get_live_neighbor_count(2, 2), 1);
+        assert_eq!(board.get_live_neighbor_count(4, 4), 1);
+        assert_eq!(board.get_live_neighbor_count(5, 0), 1);
+        assert_eq!(board.get_live_neighbor_count(0, 4), 1);
+        assert_eq!(board.get_live_neighbor_count(3, 0), 0);
+        assert_eq!(board.get_live_neighbor_count(3, 3), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn loop_radius_too_large() {
+        let mut board = Board::new(4, 4, BorderOpt::Loop);
+
+        board.set_neighbor_radius(2);
+    }
+
+    #[test]
+    fn blinker_agar_period() {
+        // A 3x3 grid of blinkers, each in its own 5x5 block
+        let mut board = Board::new(15, 15, BorderOpt::Empty);
+
+        (0..3).for_each(|bx| {
+            (0..3).for_each(|by| {
+                (1..4).for_each(|dy| board.set(bx * 5 + 2, by * 5 + dy, Cell::Alive));
+            })
+        });
+
+        assert_eq!(board.detect_background_period(10), Some(2));
+        assert_eq!(get_4x4_board().detect_background_period(10), None);
+    }
+
+    // These pin down the (column, row) convention, so it can't silently
+    // flip to (row, column)
+    #[test]
+    fn coordinates_are_column_then_row() {
+        // An L-tromino on a non-square board: two cells down the first
+        // column, then one to the right along the bottom
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+        board.set(0, 0, Cell::Alive);
+        board.set(0, 1, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+
+        assert_eq!(
+            format!("{}", board),
+            "▓▓░░░░░░\n\
+             ▓▓▓▓░░░░\n\
+             ░░░░░░░░\n"
+        );
+        assert_eq!(board.to_ascii(), "#...\n##..\n....\n");
+        assert_eq!(board.get(1, 0), Cell::Dead);
+        assert_eq!(board.get(1, 1), Cell::Alive);
+    }
+
+    #[test]
+    fn far_corner_coordinates() {
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+        board.set(3, 0, Cell::Alive);
+        board.set(0, 2, Cell::Alive);
+
+        assert_eq!(board.to_ascii(), "...#\n....\n#...\n");
+        assert_eq!(board.cells[3], Cell::Alive);
+        assert_eq!(board.cells[2 * 4], Cell::Alive);
+        assert_eq!(board.bounding_box(), Some((0, 0, 4, 3)));
+    }
+
+    #[test]
+    fn set_many_glider() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        let glider: Vec<(usize, usize, Cell)> = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]
+            .iter()
+            .map(|&(x, y)| (x, y, Cell::Alive))
+            .collect();
+
+        board.set_many(&glider).unwrap();
+        assert_eq!(board, get_glider_board());
+
+        let err = board.set_many(&[(0, 0, Cell::Alive), (0, 5, Cell::Alive), (9, 9, Cell::Dead)]);
+        assert_eq!(err, Err(OutOfBounds { x: 0, y: 5 }));
+        assert_eq!(board, get_glider_board());
+    }
+
+    #[test]
+    fn reused_updates_buffer_gives_same_results() {
+        let soup = Board::new_random(32, 24, BorderOpt::Loop, 0.4, 9);
+        let mut reserved = soup.clone();
+        reserved.reserve_updates(32 * 24);
+
+        (0..30).for_each(|_| {
+            let fresh = {
+                let mut fresh = Board::new(32, 24, BorderOpt::Loop);
+                fresh.cells = reserved.cells.clone();
+                fresh.advanced()
+            };
+            reserved.advance_cycle();
+            assert_eq!(reserved, fresh);
+        });
+        assert_eq!(reserved, soup.advanced_n(30));
+    }
+
+    #[test]
+    fn blinker_last_diff() {
+        let mut board = get_blinker_board();
+        assert!(board.last_diff().is_empty());
+
+        board.advance_cycle();
+        let mut diff = board.last_diff().to_vec();
+        diff.sort_by_key(|&(x, y, _)| (y, x));
+        assert_eq!(
+            diff,
+            vec![
+                (1, 0, Cell::Dead),
+                (0, 1, Cell::Alive),
+                (2, 1, Cell::Alive),
+                (1, 2, Cell::Dead),
+            ]
+        );
+
+        // Every backend and stepping method reports the same changes
+        let mut bitboard = get_blinker_board();
+        bitboard.set_backend(Backend::Bitboard);
+        bitboard.advance_cycle();
+        let mut with_rule = get_blinker_board();
+        with_rule.advance_cycle_with(|cell, n| match (cell, n) {
+            (_, 3) | (Cell::Alive, 2) => Cell::Alive,
+            _ => Cell::Dead,
+        });
+        let mut hashlife = get_blinker_board();
+        hashlife.advance_hashlife(9);
+        [bitboard, with_rule, hashlife].iter().for_each(|b| {
+            let mut other = b.last_diff().to_vec();
+            other.sort_by_key(|&(x, y, _)| (y, x));
+            assert_eq!(other, diff);
+        });
+
+        board.set(0, 0, Cell::Alive);
+        assert_eq!(board.last_diff().len(), 4);
+    }
+
+    #[test]
+    fn noise_flipping_back_is_no_change() {
+        let mut board = get_blinker_board();
+        let mut rng = SplitMix64::new(1);
+
+        board.advance_with_noise(1.0, &mut rng);
+        // Every cell flipped after the step, so the ones the step changed
+        // are back where they started
+        assert_eq!(board.last_diff().len(), board.width * board.height - 4);
+        assert!(board
+            .last_diff()
+            .iter()
+            .all(|&(x, y, cell)| board.get(x, y) == cell));
+    }
+
+    #[test]
+    fn edge_source_keeps_top_row_lit() {
+        let mut board = Board::new(6, 5, BorderOpt::Empty);
+        let top_row = |_, y| if y == 0 { Cell::Alive } else { Cell::Dead };
+
+        (0..10).for_each(|_| {
+            board.advance_with_edge_source(top_row);
+            (0..board.width).for_each(|x| assert_eq!(board.get(x, 0), Cell::Alive));
+            (0..board.height).for_each(|y| {
+                assert_eq!(board.get(0, y) == Cell::Alive, y == 0);
+                assert_eq!(board.get(board.width - 1, y) == Cell::Alive, y == 0);
+            });
+            (0..board.width).for_each(|x| assert_eq!(board.get(x, board.height - 1), Cell::Dead));
+        });
+
+        assert_eq!(board.generation(), Generation(10));
+    }
+
+    #[test]
+    fn generation_counts_advances() {
+        let mut board = get_glider_board();
+        assert_eq!(board.generation(), Generation(0));
+
+        board.advance_n_cycles(3);
+        board.advance_cycle_with(|cell, _| cell);
+        assert_eq!(board.step(), Generation(5));
+        assert_eq!(board.generation(), Generation(5));
+        assert_eq!(usize::from(board.generation()), 5);
+
+        board.set_history_capacity(4);
+        board.advance_n_cycles(2);
+        assert_eq!(board.step_back_n(3), 2);
+        assert_eq!(board.generation(), Generation(5));
+
+        board.advance_hashlife(9);
+        assert_eq!(board.generation(), Generation(14));
+    }
+
+    #[test]
+    fn blinker_phases() {
+        let blinker = get_blinker_board();
+        let flipped = blinker.clone().advanced();
+
+        assert_eq!(blinker.matches_any_phase(&blinker, 2), Some(0));
+        assert_eq!(flipped.matches_any_phase(&blinker, 2), Some(1));
+        assert_eq!(blinker.matches_any_phase(&flipped, 2), Some(1));
+        assert_eq!(flipped.matches_any_phase(&blinker, 1), None);
+        assert_eq!(get_4x4_board().matches_any_phase(&blinker, 2), None);
+        assert_eq!(
+            Board::new(3, 3, BorderOpt::Empty).matches_any_phase(&blinker, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn detect_blinker_cycle() {
+        let mut board = get_blinker_board();
+
+        assert_eq!(
+            board.run_detect_cycle(10),
+            CycleInfo {
+                start: Generation(0),
+                period: 2
+            }
+        );
+        assert_eq!(board, get_blinker_board());
+    }
+
+    #[test]
+    fn detect_delayed_cycle() {
+        // An L-tromino becomes a block after one generation
+        let mut board = Board::new(4, 4, BorderOpt::Empty);
+
+        board.set(1, 1, Cell::Alive);
+        board.set(2, 1, Cell::Alive);
+        board.set(1, 2, Cell::Alive);
+
+        let expected = board.clone().advanced();
+
+        assert_eq!(
+            board.run_detect_cycle(10),
+            CycleInfo {
+                start: Generation(1),
+                period: 1
+            }
+        );
+        assert_eq!(board, expected);
+        // Generations keep counting from where the board is
+        assert_eq!(board.run_detect_cycle(10).start, Generation(1));
+    }
+
+    #[test]
+    fn detect_cycle_gives_up() {
+        let mut board = get_glider_board();
+
+        assert_eq!(
+            board.run_detect_cycle(3),
+            CycleInfo {
+                start: Generation(3),
+                period: 0
+            }
+        );
+    }
+
+    #[test]
+    fn solid_border_counts_as_alive() {
+        let mut board = Board::new(3, 3, BorderOpt::Solid);
+
+        assert_eq!(board.get_live_neighbor_count(0, 0), 5);
+        assert_eq!(board.get_live_neighbor_count(1, 0), 3);
+        assert_eq!(board.get_live_neighbor_count(1, 1), 0);
+
+        board.advance_cycle();
+        assert_eq!(
+            format!("{}", board),
+            "░░▓▓░░\n\
+             ▓▓░░▓▓\n\
+             ░░▓▓░░\n"
+        );
     }
 
-    fn get_neighbors(&self, x: usize, y: usize) -> Vec<Cell> {
-        let w: usize = self.width - 1;
-        let h: usize = self.height - 1;
+    // How a block tucked into the top-left corner fares under Solid and
+    // Empty borders:
+    //
+    // - Under Solid, (0, 0) sees five live off-board neighbors plus the
+    //   rest of the block (8) and (1, 0) and (0, 1) see three plus the
+    //   block (6), so all three die of overcrowding; only (1, 1), which
+    //   doesn't touch the border, keeps its 3 neighbors. Meanwhile every
+    //   non-corner edge cell away from the block sees exactly the three
+    //   off-board neighbors and is born.
+    // - Under Empty the border adds nothing and the block is stable.
+    #[test]
+    fn corner_block_solid_vs_empty() {
+        let corner_block = |border| {
+            let mut board = Board::new(4, 4, border);
+            board.fill_rect(0, 0, 2, 2, Cell::Alive);
+            board
+        };
+
+        let mut solid = corner_block(BorderOpt::Solid);
+        solid.advance_cycle();
+        assert_eq!(
+            solid.to_ascii(),
+            "....\n\
+             .#.#\n\
+             ...#\n\
+             .##.\n"
+        );
 
-        match (x, y) {
-            (x, y) if (x > 0 && x < w) && y == h => self.get_upper_neighbors(x, y),
-            (0, y) if y == h => self.get_upper_right_neighbors(x, y),
-            (0, y) if y > 0 && y < h => self.get_right_neighbors(x, y),
-            (0, 0) => self.get_lower_right_neighbors(x, y),
-            (x, 0) if x > 0 && x < w => self.get_lower_neighbors(x, y),
-            (x, 0) if x == w => self.get_lower_left_neighbors(x, y),
-            (x, y) if x == w && (y > 0 && y < h) => self.get_left_neighbors(x, y),
-            (x, y) if x == w && y == h => self.get_upper_left_neighbors(x, y),
-            _ => self.get_all_neighbors(x, y),
-        }
+        let mut empty = corner_block(BorderOpt::Empty);
+        empty.advance_n_cycles(5);
+        assert_eq!(empty, corner_block(BorderOpt::Empty));
     }
 
-    fn get_live_neighbor_count(&self, x: usize, y: usize) -> usize {
-        self.get_neighbors(x, y)
-            .iter()
-            .filter(|n| **n == Cell::Alive)
-            .count()
+    #[test]
+    fn border_change_mid_run() {
+        let mut empty = get_glider_board();
+        empty.advance_n_cycles(4);
+        let mut looped = empty.clone();
+
+        looped.set_border(BorderOpt::Loop);
+        empty.advance_n_cycles(8);
+        looped.advance_n_cycles(8);
+
+        assert_ne!(empty.cells, looped.cells);
+        // The glider survives on the torus, but crashes into the edge
+        assert_eq!(looped.population(), 5);
+        assert_ne!(empty.population(), 5);
     }
 
-    fn cell_should_live(&self, x: usize, y: usize) -> bool {
-        let cell = self.get(x, y);
+    #[test]
+    fn cycle_border_rotates() {
+        let mut board = Board::new(4, 4, BorderOpt::Empty);
 
-        match self.get_live_neighbor_count(x, y) {
-            3 => true,
-            2 => cell == Cell::Alive,
-            _ => false,
-        }
+        board.cycle_border();
+        assert_eq!(board.border, BorderOpt::Solid);
+        board.cycle_border();
+        assert_eq!(board.border, BorderOpt::Loop);
+        board.cycle_border();
+        assert_eq!(board.border, BorderOpt::Empty);
     }
 
-    fn parse_str_as_cells(string: &str) -> Vec<Cell> {
-        let mut cell_row: Vec<Cell> = vec![];
+    #[test]
+    fn seed_phrases() {
+        let board = |phrase| Board::from_seed_phrase(12, 12, BorderOpt::Empty, phrase, 0.5);
 
-        string.bytes().for_each(|c| {
-            cell_row.push(match c {
-                FILE_LIVE_CHAR => Cell::Alive,
-                FILE_DEAD_CHAR => Cell::Dead,
-                _ => Cell::Dead,
-            })
+        assert_eq!(board("hello world"), board("hello world"));
+        assert_ne!(board("hello world"), board("hello world!"));
+        // FNV-1a of the empty string is the offset basis
+        assert_eq!(
+            board(""),
+            Board::new_random(12, 12, BorderOpt::Empty, 0.5, FNV_OFFSET_BASIS)
+        );
+    }
+
+    #[test]
+    fn probability_field_halves() {
+        let board = Board::from_probability_field(10, 6, BorderOpt::Empty, 9, |x, _| {
+            if x < 5 {
+                1.0
+            } else {
+                0.0
+            }
         });
 
-        cell_row
+        assert_eq!(board.live_in_rect(0, 0, 5, 6), 30);
+        assert_eq!(board.live_in_rect(5, 0, 5, 6), 0);
     }
 
-    fn parse_str_as_border_opt(string: &str) -> Option<BorderOpt> {
-        match string {
-            "solid" => Some(BorderOpt::Solid),
-            "empty" => Some(BorderOpt::Empty),
-            _ => None,
-        }
-    }
-}
+    #[test]
+    fn uniform_field_matches_random() {
+        let field = Board::from_probability_field(9, 7, BorderOpt::Loop, 4, |_, _| 0.3);
 
-impl Default for Board {
-    fn default() -> Self {
-        Board::new(10, 10, BorderOpt::Empty)
+        assert_eq!(field, Board::new_random(9, 7, BorderOpt::Loop, 0.3, 4));
     }
-}
 
-impl Display for Board {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.cells.iter().enumerate().for_each(|(i, c)| {
-            if (i + 1) % self.width == 0 {
-                writeln!(f, "{}", c).unwrap()
-            } else {
-                write!(f, "{}", c).unwrap()
-            }
+    #[test]
+    fn blinker_change_stream() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+        let mut expected = board.clone();
+
+        let diffs: Vec<_> = board.change_stream().take(3).collect();
+        assert_eq!(diffs.len(), 3);
+        diffs.iter().for_each(|diff| {
+            expected.advance_cycle();
+            assert_eq!(diff.len(), 4);
+            assert_eq!(
+                diff.iter().filter(|&&(_, _, c)| c == Cell::Alive).count(),
+                2
+            );
+            assert!(diff.iter().all(|&(x, y, c)| expected.get(x, y) == c));
         });
-        write!(f, "")
+        assert_eq!(board.generation(), Generation(3));
     }
-}
 
-#[cfg(test)]
-mod tests {
+    #[test]
+    fn signed_counts_outside_the_board() {
+        let mut board = Board::new(4, 4, BorderOpt::Solid);
+        board.fill_rect(3, 0, 1, 4, Cell::Alive);
+
+        // Just right of the live edge column, ignoring the solid border
+        assert_eq!(board.live_neighbor_count_signed(4, 1), 3);
+        assert_eq!(board.live_neighbor_count_signed(4, 0), 2);
+        assert_eq!(board.live_neighbor_count_signed(4, -1), 1);
+        assert_eq!(board.live_neighbor_count_signed(5, 1), 0);
+        assert_eq!(board.live_neighbor_count_signed(-1, 1), 0);
+        // On the board it agrees with stepping, away from the border
+        assert_eq!(
+            board.live_neighbor_count_signed(2, 1),
+            board.get_live_neighbor_count(2, 1)
+        );
 
-    use super::*;
+        board.set_neighbor_radius(2);
+        assert_eq!(board.live_neighbor_count_signed(5, 1), 4);
+    }
 
-    // ▓▓░░░░░░
-    // ░░▓▓░░░░
-    // ░░░░▓▓░░
-    // ░░░░░░▓▓
-    fn get_4x4_board() -> Board {
-        let mut board = Board::new(4, 4, BorderOpt::Empty);
+    #[test]
+    fn capped_growth_stays_under_cap() {
+        let mut board = Board::new(20, 20, BorderOpt::Empty);
+        board.set_rule(Rule::parse("B1/S012345678").unwrap());
+        board.set(10, 10, Cell::Alive);
+        let mut uncapped = board.clone();
+
+        (0..10).for_each(|_| {
+            board.advance_capped(50);
+            uncapped.advance_cycle();
+            assert!(board.population() <= 50);
+        });
+        assert_eq!(board.population(), 50);
+        assert!(uncapped.population() > 50);
+        assert_eq!(board.generation(), Generation(10));
+
+        // A cap that's never reached changes nothing
+        let mut glider = Board::new_from_file("./tests/test-boards/glider.txt");
+        let expected = glider.clone().advanced();
+        glider.advance_capped(100);
+        assert_eq!(glider, expected);
+    }
 
-        board.set(0, 0, Cell::Alive);
-        board.set(1, 1, Cell::Alive);
-        board.set(2, 2, Cell::Alive);
-        board.set(3, 3, Cell::Alive);
+    #[test]
+    fn density_ascii_extremes_for_any_seed() {
+        let art = "0909\n9090\n5555\n";
+        (0..50).for_each(|seed| {
+            let board = Board::from_density_ascii(art, BorderOpt::Empty, seed).unwrap();
+            assert_eq!(
+                board.to_ascii().lines().take(2).collect::<Vec<_>>(),
+                [".#.#", "#.#."]
+            );
+        });
 
-        board
+        assert!(Board::from_density_ascii("09\n9\n", BorderOpt::Empty, 0).is_err());
+        assert!(Board::from_density_ascii("0#\n", BorderOpt::Empty, 0).is_err());
     }
 
-    // ░░▓▓░░
-    // ░░▓▓░░
-    // ░░▓▓░░
-    fn get_blinker_board() -> Board {
+    // A board claiming to be larger than its cells
+    fn get_corrupted_board() -> Board {
         let mut board = Board::new(3, 3, BorderOpt::Empty);
-
-        board.set(1, 0, Cell::Alive);
-        board.set(1, 1, Cell::Alive);
-        board.set(1, 2, Cell::Alive);
-
+        board.height = 4;
         board
     }
 
-    // ░░▓▓░░░░░░
-    // ░░░░▓▓░░░░
-    // ▓▓▓▓▓▓░░░░
-    // ░░░░░░░░░░
-    // ░░░░░░░░░░
-    fn get_glider_board() -> Board {
-        let mut board = Board::new(5, 5, BorderOpt::Empty);
-
-        board.set(0, 1, Cell::Alive);
-        board.set(1, 2, Cell::Alive);
-        board.set(2, 0, Cell::Alive);
-        board.set(2, 1, Cell::Alive);
-        board.set(2, 2, Cell::Alive);
+    #[test]
+    fn validate_boards() {
+        assert_eq!(get_4x4_board().validate(), Ok(()));
+        assert_eq!(
+            Board::new_random(9, 2, BorderOpt::Loop, 0.5, 1).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            get_corrupted_board().validate(),
+            Err("cells has 9 entries, expected 12 for a 3x4 board".to_string())
+        );
 
-        board
+        let mut board = get_4x4_board();
+        board.frozen.pop();
+        assert!(board.validate().unwrap_err().starts_with("frozen mask"));
     }
 
-    fn get_rectangular_board() -> Board {
-        let mut board = Board::new(5, 3, BorderOpt::Empty);
-
-        board.set(1, 1, Cell::Alive);
-        board.set(2, 1, Cell::Alive);
-        board.set(3, 1, Cell::Alive);
-
-        board
+    #[test]
+    fn off_board_corner_per_border() {
+        // (3, 3) is alive, so it's what wraps around to (-1, -1)
+        let mut board = get_4x4_board();
+
+        board.set_border(BorderOpt::Solid);
+        assert_eq!(board.effective_neighbor(-1, -1), Cell::Alive);
+        board.set_border(BorderOpt::Empty);
+        assert_eq!(board.effective_neighbor(-1, -1), Cell::Dead);
+        board.set_border(BorderOpt::Loop);
+        assert_eq!(board.effective_neighbor(-1, -1), Cell::Alive);
+        assert_eq!(board.effective_neighbor(4, 4), board.get(0, 0));
     }
 
-    fn get_file_board() -> Board {
-        Board::new_from_file("./tests/test-boards/glider.txt")
+    #[test]
+    fn switching_to_loop_wraps_every_edge() {
+        let mut board = Board::new_random(7, 5, BorderOpt::Empty, 0.5, 9);
+        let (w, h) = (board.width as i64, board.height as i64);
+        assert!((0..h).all(|y| board.effective_neighbor(-1, y) == Cell::Dead));
+
+        board.set_border(BorderOpt::Loop);
+        (0..h).for_each(|y| {
+            assert_eq!(board.effective_neighbor(-1, y), board.get(6, y as usize));
+            assert_eq!(board.effective_neighbor(w, y), board.get(0, y as usize));
+        });
+        (0..w).for_each(|x| {
+            assert_eq!(board.effective_neighbor(x, -1), board.get(x as usize, 4));
+            assert_eq!(board.effective_neighbor(x, h), board.get(x as usize, 0));
+        });
     }
 
-    fn get_bad_file_board() -> Board {
-        Board::new_from_file("./tests/test-boards/bad-test.txt")
+    #[test]
+    fn blinker_summary() {
+        [Backend::Scalar, Backend::Bitboard]
+            .iter()
+            .for_each(|&backend| {
+                let mut board = get_blinker_board();
+                board.set_backend(backend);
+
+                assert_eq!(board.advance_cycle_summary(), (2, 2));
+                assert_eq!(board.advance_cycle_summary(), (2, 2));
+                assert_eq!(board.cells, get_blinker_board().cells);
+            });
+
+        let mut block = Board::new(4, 4, BorderOpt::Empty);
+        block.fill_rect(1, 1, 2, 2, Cell::Alive);
+        assert_eq!(block.advance_cycle_summary(), (0, 0));
     }
 
     #[test]
-    fn init_default_board() {
-        let board = Board::default();
+    fn overflowing_dimensions() {
+        let half = 1 << (usize::BITS / 2);
+
         assert_eq!(
-            board,
-            Board {
-                width: 10,
-                height: 10,
-                border: BorderOpt::Empty,
-                cells: vec![Cell::Dead; 10 * 10],
+            Board::try_new(half, half, BorderOpt::Empty).unwrap_err(),
+            DimensionsTooLarge {
+                width: half,
+                height: half
             }
         );
+        assert!(Board::try_new(0, usize::MAX, BorderOpt::Empty).is_ok());
     }
 
     #[test]
-    fn display_board() {
-        let board = get_4x4_board();
+    fn checked_rejects_degenerate_sizes() {
+        let half = 1 << (usize::BITS / 2);
 
         assert_eq!(
-            format!("{}", board),
-            "▓▓░░░░░░\n\
-             ░░▓▓░░░░\n\
-             ░░░░▓▓░░\n\
-             ░░░░░░▓▓\n"
-                .to_string()
+            Board::new_checked(0, 5, BorderOpt::Empty).unwrap_err(),
+            BoardError::ZeroSized {
+                width: 0,
+                height: 5
+            }
+        );
+        assert_eq!(
+            Board::new_checked(5, 0, BorderOpt::Loop).unwrap_err(),
+            BoardError::ZeroSized {
+                width: 5,
+                height: 0
+            }
+        );
+        assert_eq!(
+            Board::new_checked(half, half, BorderOpt::Empty).unwrap_err(),
+            BoardError::TooLarge(DimensionsTooLarge {
+                width: half,
+                height: half
+            })
+        );
+        assert_eq!(
+            Board::new_checked_with_limit(100, 100, BorderOpt::Empty, 9999).unwrap_err(),
+            BoardError::OverLimit {
+                width: 100,
+                height: 100,
+                max_cells: 9999
+            }
+        );
+        assert_eq!(
+            Board::new_checked_with_limit(100, 100, BorderOpt::Empty, 10_000).unwrap(),
+            Board::new(100, 100, BorderOpt::Empty)
         );
     }
 
     #[test]
-    fn correct_neighbor_count() {
-        let board_4x4 = get_4x4_board();
-        let board_blinker = get_blinker_board();
+    #[should_panic(expected = "more cells than fit in memory")]
+    fn new_panics_on_overflow() {
+        Board::new(usize::MAX, 3, BorderOpt::Empty);
+    }
 
-        assert_eq!(board_4x4.get_live_neighbor_count(0, 0), 1);
-        assert_eq!(board_4x4.get_live_neighbor_count(1, 1), 2);
-        assert_eq!(board_4x4.get_live_neighbor_count(3, 3), 1);
+    #[test]
+    fn noiseless_advance_matches_plain() {
+        let mut noisy = Board::new_random(16, 12, BorderOpt::Loop, 0.4, 3);
+        let mut plain = noisy.clone();
+        let mut rng = SplitMix64::new(5);
+
+        (0..10).for_each(|_| {
+            noisy.advance_with_noise(0.0, &mut rng);
+            plain.advance_cycle();
+        });
 
-        assert_eq!(board_blinker.get_live_neighbor_count(0, 0), 2);
-        assert_eq!(board_blinker.get_live_neighbor_count(1, 1), 2);
-        assert_eq!(board_blinker.get_live_neighbor_count(2, 1), 3);
+        assert_eq!(noisy, plain);
     }
 
     #[test]
-    fn should_cell_live() {
-        let board = get_4x4_board();
+    fn noise_is_reproducible() {
+        let run = |seed| {
+            let mut board = Board::new_random(16, 12, BorderOpt::Empty, 0.4, 3);
+            let mut rng = SplitMix64::new(seed);
+            (0..5).for_each(|_| board.advance_with_noise(0.05, &mut rng));
+            board
+        };
 
-        assert!(!board.cell_should_live(0, 0));
-        assert!(board.cell_should_live(1, 1));
-        assert!(!board.cell_should_live(3, 3));
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
     }
 
     #[test]
-    fn blinker_should_blink() {
-        let mut board = get_blinker_board();
+    fn simulate_outcomes() {
+        assert_eq!(
+            Board::new(4, 4, BorderOpt::Empty).simulate(5),
+            SimOutcome::Extinct(Generation(0))
+        );
+        assert_eq!(
+            get_blinker_board().simulate(5),
+            SimOutcome::Oscillator {
+                period: 2,
+                gen: Generation(0)
+            }
+        );
 
-        println!("{}", board);
-        board.advance_cycle();
-        println!("{}", board);
+        // A 2×2 block, plus a lone cell that dies in the first generation
+        let mut block = Board::new(5, 5, BorderOpt::Empty);
+        [(1, 1), (2, 1), (1, 2), (2, 2), (4, 4)]
+            .iter()
+            .for_each(|&(x, y)| block.set(x, y, Cell::Alive));
+        assert_eq!(block.simulate(5), SimOutcome::StillLife(Generation(1)));
+
+        // A lone pair dies after one generation
+        let mut pair = Board::new(4, 4, BorderOpt::Empty);
+        pair.set(1, 1, Cell::Alive);
+        pair.set(2, 1, Cell::Alive);
+        assert_eq!(pair.simulate(5), SimOutcome::Extinct(Generation(1)));
+
+        let mut gun = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+        assert_eq!(gun.simulate(60), SimOutcome::Ongoing);
 
+        // Generations keep counting from where the board already was
+        let mut later = get_blinker_board();
+        later.skip_generations(10);
         assert_eq!(
-            format!("{}", board),
-            "░░░░░░\n\
-             ▓▓▓▓▓▓\n\
-             ░░░░░░\n"
-                .to_string()
+            later.simulate(5),
+            SimOutcome::Oscillator {
+                period: 2,
+                gen: Generation(10)
+            }
         );
+        let blinker = get_blinker_board().advanced_n(3);
+        assert_eq!(blinker.detect_stability(5), Some((Generation(3), 2)));
+        assert_eq!(blinker.detect_background_period(5), Some(2));
+    }
 
-        board.advance_cycle();
-        println!("{}", board);
+    #[test]
+    fn custom_rule_matches_conway() {
+        let mut board = get_glider_board();
+        let mut expected = get_glider_board();
+
+        board.advance_cycle_with(|cell, n| match n {
+            3 => Cell::Alive,
+            2 => cell,
+            _ => Cell::Dead,
+        });
+        expected.advance_cycle();
+
+        assert_eq!(board, expected);
+    }
+
+    #[test]
+    fn broken_rule_is_reported() {
+        let mut board = get_blinker_board();
+
+        // Meant to emit 0/1 but leaks the neighbor count for busy cells
+        let result = board.try_advance_with(|_, n| if n < 3 { 0 } else { n as u8 });
 
         assert_eq!(
-            format!("{}", board),
-            "░░▓▓░░\n\
-             ░░▓▓░░\n\
-             ░░▓▓░░\n"
-                .to_string()
+            result,
+            Err(InvalidCellState {
+                x: 0,
+                y: 1,
+                value: 3
+            })
+        );
+        assert_eq!(board, get_blinker_board());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "rule produced invalid cell state 3 at (0, 1)"
         );
     }
 
+    #[test]
+    fn frontier_matches_full_evaluation() {
+        let mut board = Board::new(20, 20, BorderOpt::Loop);
+        let mut expected = Board::new(20, 20, BorderOpt::Loop);
+
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| {
+                board.set(x, y, Cell::Alive);
+                expected.set(x, y, Cell::Alive);
+            });
+
+        (0..100).for_each(|step| {
+            board.advance_cycle();
+            // A custom rule always evaluates every cell
+            expected.advance_cycle_with(|cell, n| match n {
+                3 => Cell::Alive,
+                2 => cell,
+                _ => Cell::Dead,
+            });
+            assert_eq!(board.cells, expected.cells, "diverged at step {}", step + 1);
+        });
+        assert!(board.dirty_cell_count() < 5 * 5);
+    }
+
+    #[test]
+    fn frontier_tracks_border_changes() {
+        let mut board = get_blinker_board();
+
+        board.advance_n_cycles(2);
+        board.border = BorderOpt::Loop;
+        assert_eq!(board.dirty_cell_count(), 9);
+
+        // On a 3x3 torus every cell neighbors the whole blinker, so the
+        // dead cells are born and the live ones survive
+        board.advance_cycle();
+        assert_eq!(board.cells, vec![Cell::Alive; 9]);
+    }
+
     #[test]
     fn gilder_should_glide() {
         let mut board = get_glider_board();
@@ -547,6 +3058,135 @@ mod tests {
         assert_eq!(format!("{}", board), expected.to_string());
     }
 
+    #[test]
+    fn any_line_endings() {
+        let glider = get_file_board();
+        let unix = fs::read_to_string("./tests/test-boards/glider.txt").unwrap();
+
+        ["\r", "\r\n"].iter().enumerate().for_each(|(i, ending)| {
+            let path = std::env::temp_dir().join(format!("yagoll-line-endings-{}.txt", i));
+            fs::write(&path, unix.replace('\n', ending)).unwrap();
+            let read = Board::new_from_file(path.to_str().unwrap());
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(read, glider, "with {:?} line endings", ending);
+
+            // The limited reader splits lines the same way, even when a
+            // `\r\n` straddles the end of its buffer
+            let contents = unix.replace('\n', ending);
+            let reader = BufReader::with_capacity(1, contents.as_bytes());
+            let limited = Board::from_reader_limited(reader, 5, 5).unwrap();
+            assert_eq!(limited, glider, "with {:?} line endings", ending);
+        });
+
+        let looped = "loop\r_#_\r_#_\r_#_\r";
+        let board = Board::from_reader_limited(looped.as_bytes(), 3, 3).unwrap();
+        assert_eq!((board.width, board.height), (3, 3));
+        assert_eq!(board.border, BorderOpt::Loop);
+        assert_eq!(board.get(1, 2), Cell::Alive);
+    }
+
+    #[test]
+    fn reader_within_limits() {
+        let input = "empty\n_____\n__#__\n___#_\n_###_\n_____\n";
+        let board = Board::from_reader_limited(input.as_bytes(), 5, 5).unwrap();
+
+        assert_eq!(board, get_file_board());
+    }
+
+    #[test]
+    fn reader_exceeding_limits() {
+        let too_tall = "empty\n#\n#\n#\n";
+        let err = Board::from_reader_limited(too_tall.as_bytes(), 5, 2).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "more than 2 rows");
+
+        // An endless first row must be rejected without reading it all
+        let endless = "empty\n".as_bytes().chain(io::repeat(b'#'));
+        let err = Board::from_reader_limited(BufReader::new(endless), 100, 100).unwrap_err();
+
+        assert_eq!(err.to_string(), "line is longer than 100 characters");
+    }
+
+    #[test]
+    fn seed_checkerboard() {
+        let checkerboard = |x: usize, y: usize| {
+            if (x + y).is_multiple_of(2) {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            }
+        };
+        [(4, 4), (5, 5), (7, 2)].iter().for_each(|&(w, h)| {
+            let mut board = Board::new_random(w, h, BorderOpt::Empty, 0.5, 6);
+            board.seed_with(checkerboard);
+
+            assert_eq!(board.live_in_rect(0, 0, w, h), (w * h).div_ceil(2));
+            assert_eq!(board.get(0, 0), Cell::Alive);
+            assert_eq!(board.get(1, 0), Cell::Dead);
+        });
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let mut board = Board::new_random(9, 6, BorderOpt::Loop, 0.4, 3);
+        board.set_rule(Rule::parse("B36/S23").unwrap());
+        board.advance_n_cycles(7);
+        let path = std::env::temp_dir().join("yagoll-metadata-round-trip.txt");
+
+        board.save_with_metadata(&path).unwrap();
+        let read = Board::new_from_file(path.to_str().unwrap());
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.generation(), Generation(7));
+        assert_eq!(read.rule().to_string(), "B36/S23");
+        assert_eq!(read.border, BorderOpt::Loop);
+        assert_eq!(read, board);
+
+        // The limited reader restores the same, even on a board narrower
+        // than the comments
+        let limited = Board::from_reader_limited(contents.as_bytes(), 9, 6).unwrap();
+        assert_eq!(limited.generation(), Generation(7));
+        assert_eq!(limited, board);
+    }
+
+    #[test]
+    fn multi_digit_rule_is_left_out_of_metadata() {
+        let board = Board::with_rule(3, 3, BorderOpt::Empty, Rule::new(&[3, 10], &[2, 3]));
+
+        let text = board.to_string_with_metadata();
+        assert_eq!(
+            text,
+            "empty\n!generation: 0\n!border: empty\n___\n___\n___\n"
+        );
+        let read = Board::from_reader_limited(text.as_bytes(), 3, 3).unwrap();
+        assert!(read.rule().is_conway());
+        assert_ne!(read.rule(), &Rule::new(&[0, 1, 3], &[2, 3]));
+    }
+
+    #[test]
+    fn unknown_comments_are_ignored() {
+        let input = "empty\n!name: blinker\n!a note\n___\n###\n!generation: 4\n___\n";
+        let board = Board::from_reader_limited(input.as_bytes(), 3, 3).unwrap();
+
+        assert_eq!((board.width, board.height), (3, 3));
+        assert_eq!(board.generation(), Generation(4));
+        assert_eq!(board.rule(), &Rule::conway());
+        assert_eq!(board.get(1, 1), Cell::Alive);
+
+        let looped = "empty\n!border: loop\n___\n###\n___\n";
+        let board = Board::from_reader_limited(looped.as_bytes(), 3, 3).unwrap();
+        assert_eq!(board.border, BorderOpt::Loop);
+        // The corner only comes to life if the row wraps around
+        assert_eq!(board.clone().advanced().get(0, 0), Cell::Alive);
+
+        let bad_rule = "empty\n!rule: B9/S23\n###\n";
+        let err = Board::from_reader_limited(bad_rule.as_bytes(), 3, 3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid rule: B9/S23");
+    }
+
     #[test]
     #[should_panic]
     fn bad_file() {
@@ -1,7 +1,10 @@
 use std::fmt::{Display, Formatter, Result};
 
+pub(crate) const ALIVE_GLYPH: &str = "▓▓";
+pub(crate) const DEAD_GLYPH: &str = "░░";
+
 /// A simple cell
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Cell {
     /// The cell is alive (true)
     Alive,
@@ -9,12 +12,18 @@ pub enum Cell {
     Dead,
 }
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self == Cell::Alive {
-            write!(f, "▓▓")
+impl Cell {
+    pub(crate) fn glyph(self) -> &'static str {
+        if self == Cell::Alive {
+            ALIVE_GLYPH
         } else {
-            write!(f, "░░")
+            DEAD_GLYPH
         }
     }
 }
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.glyph())
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::{DimensionsTooLarge, ShapeMismatch},
+};
+
+impl Board {
+    /// Whether both boards have exactly the same cells, regardless of
+    /// their border or other settings
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let a = Board::new(3, 3, BorderOpt::Empty);
+    /// let b = Board::new(3, 3, BorderOpt::Loop);
+    /// assert_eq!(a.cells_eq(&b), Ok(true));
+    /// assert!(a.cells_eq(&Board::new(2, 3, BorderOpt::Empty)).is_err());
+    /// ```
+    pub fn cells_eq(&self, other: &Board) -> Result<bool, ShapeMismatch> {
+        self.ensure_same_dims(other)?;
+
+        Ok(self.cells == other.cells)
+    }
+
+    /// Every cell that differs between the boards, as `(x, y, cell)`
+    /// where `cell` is the state in `other`, in row-major order
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    pub fn diff(&self, other: &Board) -> Result<Vec<(usize, usize, Cell)>, ShapeMismatch> {
+        self.ensure_same_dims(other)?;
+
+        Ok(self
+            .cells
+            .iter()
+            .zip(other.cells.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, (_, &b))| (i % self.width, i / self.width, b))
+            .collect())
+    }
+
+    /// The number of cells that differ between the boards
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let a = Board::new(3, 3, BorderOpt::Empty);
+    /// let mut b = a.clone();
+    /// b.fill_rect(0, 0, 2, 1, Cell::Alive);
+    /// assert_eq!(a.hamming_distance(&b), Ok(2));
+    /// ```
+    pub fn hamming_distance(&self, other: &Board) -> Result<usize, ShapeMismatch> {
+        self.ensure_same_dims(other)?;
+
+        Ok(self
+            .cells
+            .iter()
+            .zip(other.cells.iter())
+            .filter(|(a, b)| a != b)
+            .count())
+    }
+
+    /// Whether at most `max_diff` cells differ between the boards, for
+    /// comparing runs that may diverge slightly
+    ///
+    /// Like [`Board::cells_eq`], the boards' borders and other settings
+    /// are ignored.
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    pub fn approx_eq(&self, other: &Board, max_diff: usize) -> Result<bool, ShapeMismatch> {
+        Ok(self.hamming_distance(other)? <= max_diff)
+    }
+
+    /// A copy of this board with every cell that's alive in `other` set
+    /// alive as well
+    ///
+    /// The result keeps this board's border and other settings; `other`'s
+    /// are ignored. Use [`Board::overlay_with_border`] to pick the border
+    /// explicitly.
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    pub fn overlay(&self, other: &Board) -> Result<Board, ShapeMismatch> {
+        self.ensure_same_dims(other)?;
+
+        let mut board = self.clone();
+        other
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .for_each(|(i, _)| board.set(i % self.width, i / self.width, Cell::Alive));
+
+        Ok(board)
+    }
+
+    /// A copy of this board with every cell that's alive in `other` set
+    /// dead, the reverse of [`Board::overlay`]
+    ///
+    /// The result keeps this board's border and other settings.
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut dot = Board::new(5, 5, BorderOpt::Empty);
+    /// dot.set(2, 1, Cell::Alive);
+    ///
+    /// let rest = glider.subtract(&dot).unwrap();
+    /// assert_eq!(rest.bounding_box(), Some((1, 2, 3, 2)));
+    /// ```
+    pub fn subtract(&self, other: &Board) -> Result<Board, ShapeMismatch> {
+        self.ensure_same_dims(other)?;
+
+        let mut board = self.clone();
+        other
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .for_each(|(i, _)| board.set(i % self.width, i / self.width, Cell::Dead));
+
+        Ok(board)
+    }
+
+    /// The live cells packed into 64-bit words, for set operations a
+    /// word at a time
+    ///
+    /// Cells are numbered in row-major order, like everywhere else, and
+    /// cell `i` is bit `i % 64` (counting from the least significant) of
+    /// word `i / 64`. Bits past the last cell are 0. So boards of the
+    /// same dimensions line up word for word, and e.g. ANDing their words
+    /// gives the cells alive in both. The board stores one `Cell` per
+    /// cell, so the words are packed on every call.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 10, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(4, 6, Cell::Alive);
+    ///
+    /// assert_eq!(board.to_bitset(), vec![0b10, 1 << 0]);
+    /// ```
+    pub fn to_bitset(&self) -> Vec<u64> {
+        let mut words = vec![0; self.cells.len().div_ceil(64)];
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .for_each(|(i, _)| words[i / 64] |= 1 << (i % 64));
+
+        words
+    }
+
+    /// Like [`Board::overlay`], but the result has `border`
+    ///
+    /// # Errors:
+    ///
+    /// If the boards' dimensions differ.
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::set_border`], if `border` is `Loop` and the board is
+    /// too small for its neighbor radius
+    pub fn overlay_with_border(
+        &self,
+        other: &Board,
+        border: BorderOpt,
+    ) -> Result<Board, ShapeMismatch> {
+        let mut board = self.overlay(other)?;
+        board.set_border(border);
+
+        Ok(board)
+    }
+
+    /// Copy the live cells of `pattern` onto this board, with the
+    /// pattern's top-left corner at `x` and `y`
+    ///
+    /// Only live cells are copied, so existing live cells are kept, and
+    /// anything that falls off the board is clipped. The board keeps its
+    /// own border; `pattern`'s is ignored, so a pattern taken from a
+    /// `Loop` board evolves under this board's rules once stamped. Use
+    /// [`Board::stamp_with_border`] to change the border at the same
+    /// time.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut board = Board::new(20, 20, BorderOpt::Empty);
+    ///
+    /// board.stamp(&glider, 10, 10);
+    /// assert_eq!(board.bounding_box(), Some((11, 11, 3, 3)));
+    /// ```
+    pub fn stamp(&mut self, pattern: &Board, x: usize, y: usize) {
+        let (width, height) = (self.width, self.height);
+
+        pattern
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .filter_map(|(i, _)| {
+                Some((
+                    x.checked_add(i % pattern.width)?,
+                    y.checked_add(i / pattern.width)?,
+                ))
+            })
+            .filter(|&(px, py)| px < width && py < height)
+            .for_each(|(px, py)| self.set(px, py, Cell::Alive));
+    }
+
+    /// Like [`Board::stamp`], but also switch this board to `border`
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::set_border`], if `border` is `Loop` and the board is
+    /// too small for its neighbor radius
+    pub fn stamp_with_border(&mut self, pattern: &Board, x: usize, y: usize, border: BorderOpt) {
+        self.stamp(pattern, x, y);
+        self.set_border(border);
+    }
+
+    /// A board with `a` on the left and `b` on the right, `gap` dead
+    /// columns apart
+    ///
+    /// The shorter board is padded with dead rows at the bottom. The
+    /// result is `a` grown to the right size with [`Board::pad`], so it
+    /// keeps `a`'s border, rule, neighbor radius, frozen cells and rule
+    /// map, and `b`'s live cells are stamped on next to it. Handy for
+    /// looking at two boards together, e.g. the same soup advanced under
+    /// two rules.
+    ///
+    /// # Errors:
+    ///
+    /// If the board would have more cells than a `usize` can count
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let later = glider.clone().advanced_n(4);
+    ///
+    /// let both = Board::side_by_side(&glider, &later, 1).unwrap();
+    /// assert_eq!((both.width, both.height), (11, 5));
+    /// ```
+    pub fn side_by_side(a: &Board, b: &Board, gap: usize) -> Result<Board, DimensionsTooLarge> {
+        let height = a.height.max(b.height);
+        let too_large = DimensionsTooLarge {
+            width: usize::MAX,
+            height,
+        };
+        let right = gap.checked_add(b.width).ok_or(too_large)?;
+        let width = a.width.checked_add(right).ok_or(too_large)?;
+        width
+            .checked_mul(height)
+            .ok_or(DimensionsTooLarge { width, height })?;
+
+        let mut board = a.pad(0, right, height - a.height, 0);
+        board.stamp(b, a.width + gap, 0);
+
+        Ok(board)
+    }
+
+    // Check that `other` can be combined with this board cell for cell
+    pub(crate) fn ensure_same_dims(&self, other: &Board) -> Result<(), ShapeMismatch> {
+        if self.width != other.width || self.height != other.height {
+            return Err(ShapeMismatch {
+                expected: (self.width, self.height),
+                found: (other.width, other.height),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        board::BorderOpt,
+        rule::{Rule, RuleId},
+    };
+
+    #[test]
+    fn mismatch_message() {
+        let a = Board::new(5, 3, BorderOpt::Empty);
+        let b = Board::new(4, 4, BorderOpt::Empty);
+        let expected = "dimension mismatch: self is 5x3, other is 4x4";
+
+        assert_eq!(a.cells_eq(&b).unwrap_err().to_string(), expected);
+        assert_eq!(a.diff(&b).unwrap_err().to_string(), expected);
+        assert_eq!(a.overlay(&b).unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn hamming_distance_tolerance() {
+        let a = Board::new_random(8, 8, BorderOpt::Empty, 0.5, 4);
+        let mut b = a.clone();
+        [(0, 0), (3, 5), (7, 7)].iter().for_each(|&(x, y)| {
+            let flipped = match b.get(x, y) {
+                Cell::Alive => Cell::Dead,
+                Cell::Dead => Cell::Alive,
+            };
+            b.set(x, y, flipped);
+        });
+
+        assert_eq!(a.hamming_distance(&b), Ok(3));
+        assert_eq!(b.hamming_distance(&a), Ok(3));
+        assert_eq!(a.approx_eq(&b, 5), Ok(true));
+        assert_eq!(a.approx_eq(&b, 3), Ok(true));
+        assert_eq!(a.approx_eq(&b, 2), Ok(false));
+        assert_eq!(a.hamming_distance(&a), Ok(0));
+        assert!(a
+            .approx_eq(&Board::new(8, 7, BorderOpt::Empty), 64)
+            .is_err());
+    }
+
+    #[test]
+    fn diff_and_overlay() {
+        let mut a = Board::new(3, 2, BorderOpt::Empty);
+        let mut b = Board::new(3, 2, BorderOpt::Empty);
+
+        a.set(0, 0, Cell::Alive);
+        b.set(2, 1, Cell::Alive);
+
+        assert_eq!(
+            a.diff(&b),
+            Ok(vec![(0, 0, Cell::Dead), (2, 1, Cell::Alive)])
+        );
+
+        let both = a.overlay(&b).unwrap();
+        assert_eq!(both.get(0, 0), Cell::Alive);
+        assert_eq!(both.get(2, 1), Cell::Alive);
+        assert_eq!(both.cells_eq(&b.overlay(&a).unwrap()), Ok(true));
+    }
+
+    #[test]
+    fn side_by_side_with_gap() {
+        let mut a = Board::new(3, 3, BorderOpt::Loop);
+        a.fill_rect(0, 1, 3, 1, Cell::Alive);
+        let mut b = Board::new(3, 3, BorderOpt::Empty);
+        b.fill_rect(1, 0, 1, 3, Cell::Alive);
+
+        let both = Board::side_by_side(&a, &b, 1).unwrap();
+
+        assert_eq!((both.width, both.height), (7, 3));
+        assert_eq!(both.border, BorderOpt::Loop);
+        assert_eq!(both.to_ascii(), ".....#.\n###..#.\n.....#.\n");
+    }
+
+    #[test]
+    fn side_by_side_pads_shorter_board() {
+        let mut tall = Board::new(1, 4, BorderOpt::Empty);
+        tall.fill_rect(0, 0, 1, 4, Cell::Alive);
+        let mut short = Board::new(2, 2, BorderOpt::Empty);
+        short.fill_rect(0, 0, 2, 2, Cell::Alive);
+
+        let both = Board::side_by_side(&short, &tall, 0).unwrap();
+
+        assert_eq!(both.to_ascii(), "###\n###\n..#\n..#\n");
+    }
+
+    #[test]
+    fn side_by_side_keeps_left_settings() {
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let mut a = Board::new(2, 2, BorderOpt::Empty);
+        a.set_rule(highlife.clone());
+        a.set_neighbor_radius(2);
+        a.freeze(1, 1);
+        a.set_rule_map(
+            vec![Rule::conway(), highlife.clone()],
+            vec![RuleId(0), RuleId(1), RuleId(0), RuleId(1)],
+        );
+        let mut b = Board::new(2, 3, BorderOpt::Loop);
+        b.set(1, 2, Cell::Alive);
+
+        let both = Board::side_by_side(&a, &b, 1).unwrap();
+        assert_eq!((both.width, both.height), (5, 3));
+        assert_eq!(both.border, BorderOpt::Empty);
+        assert_eq!(both.rule(), &highlife);
+        assert_eq!(both.neighbor_radius(), 2);
+        assert!(both.is_frozen(1, 1));
+        assert_eq!(both.rule_at(0, 0), &Rule::conway());
+        assert_eq!(both.rule_at(1, 0), &highlife);
+        assert_eq!(both.get(4, 2), Cell::Alive);
+    }
+
+    #[test]
+    fn side_by_side_too_wide() {
+        let a = Board::new(1, 1, BorderOpt::Empty);
+        let tall = Board::new(1, 2, BorderOpt::Empty);
+
+        assert!(Board::side_by_side(&a, &a, usize::MAX).is_err());
+        // The width fits, but not the cell count
+        assert!(Board::side_by_side(&a, &tall, usize::MAX / 2).is_err());
+    }
+
+    #[test]
+    fn subtract_erases_stamped_pattern() {
+        let mut blinker = Board::new(3, 3, BorderOpt::Empty);
+        blinker.fill_rect(0, 1, 3, 1, Cell::Alive);
+        let mut board = Board::new(8, 8, BorderOpt::Loop);
+        board.stamp(&blinker, 2, 4);
+        let mut stamped = Board::new(8, 8, BorderOpt::Empty);
+        stamped.stamp(&blinker, 2, 4);
+
+        let erased = board.subtract(&stamped).unwrap();
+        assert_eq!(erased.live_in_rect(0, 0, 8, 8), 0);
+        assert_eq!(erased.border, BorderOpt::Loop);
+
+        // Cells dead in `other` are left as they are
+        board.set(0, 0, Cell::Alive);
+        assert_eq!(
+            board.subtract(&stamped).unwrap().to_sparse().live_cells(),
+            vec![(0, 0)]
+        );
+        assert!(board.subtract(&blinker).is_err());
+    }
+
+    #[test]
+    fn bitset_popcount_and_algebra() {
+        let a = Board::new_random(13, 11, BorderOpt::Empty, 0.4, 1);
+        let b = Board::new_random(13, 11, BorderOpt::Empty, 0.4, 2);
+        let popcount = |words: &[u64]| words.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+
+        assert_eq!(a.to_bitset().len(), 3);
+        assert_eq!(popcount(&a.to_bitset()), a.population());
+
+        let or: Vec<u64> = a
+            .to_bitset()
+            .iter()
+            .zip(b.to_bitset())
+            .map(|(x, y)| x | y)
+            .collect();
+        assert_eq!(or, a.overlay(&b).unwrap().to_bitset());
+        let and_not: Vec<u64> = a
+            .to_bitset()
+            .iter()
+            .zip(b.to_bitset())
+            .map(|(x, y)| x & !y)
+            .collect();
+        assert_eq!(and_not, a.subtract(&b).unwrap().to_bitset());
+
+        assert!(Board::new(0, 0, BorderOpt::Empty).to_bitset().is_empty());
+    }
+
+    #[test]
+    fn stamp_keeps_destination_border() {
+        let mut pattern = Board::new(3, 3, BorderOpt::Loop);
+        pattern.fill_rect(0, 1, 3, 1, Cell::Alive);
+        let mut board = Board::new(8, 8, BorderOpt::Empty);
+
+        board.stamp(&pattern, 2, 3);
+
+        assert_eq!(board.border, BorderOpt::Empty);
+        assert_eq!(board.bounding_box(), Some((2, 4, 3, 1)));
+
+        board.stamp_with_border(&pattern, 6, 0, BorderOpt::Loop);
+        assert_eq!(board.border, BorderOpt::Loop);
+        // Clipped at the right edge
+        assert_eq!(board.live_in_rect(6, 0, 2, 3), 2);
+
+        // Even where the coordinates themselves would overflow
+        let before = board.clone();
+        board.stamp(&pattern, usize::MAX - 1, usize::MAX);
+        board.stamp(&pattern, 0, usize::MAX - 1);
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn overlay_border_policy() {
+        let empty = Board::new(4, 4, BorderOpt::Empty);
+        let looped = Board::new_random(4, 4, BorderOpt::Loop, 0.5, 1);
+
+        assert_eq!(empty.overlay(&looped).unwrap().border, BorderOpt::Empty);
+        assert_eq!(looped.overlay(&empty).unwrap().border, BorderOpt::Loop);
+        assert_eq!(
+            empty
+                .overlay_with_border(&looped, BorderOpt::Solid)
+                .unwrap()
+                .border,
+            BorderOpt::Solid
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    board::{Board, BorderOpt, Generation},
+    cell::Cell,
+};
+
+/// Longest oscillator period `Board::classify_components` looks for
+const COMPONENT_MAX_PERIOD: usize = 64;
+
+/// How many of a board's components are of each kind, see
+/// [`Board::classify_components`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComponentStats {
+    /// Components that never change
+    pub still_lifes: usize,
+    /// Components that return to their current state after more than
+    /// one generation
+    pub oscillators: usize,
+    /// Everything else, like spaceships and components still evolving
+    pub other: usize,
+}
+
+impl Board {
+    /// Sort every connected group of live cells into still lifes,
+    /// oscillators and everything else
+    ///
+    /// Live cells touching in any of the eight directions (wrapping
+    /// around on `Loop` boards) form one component. Each component is
+    /// simulated on its own, on a board of the same size and border, and
+    /// counts as a still life or oscillator if it's already on a cycle
+    /// of period 1 or at most 64. Meant for boards that have settled;
+    /// objects close enough to interact, like a pond and a nearby
+    /// blinker, may be misjudged.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 6, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    /// board.fill_rect(6, 2, 3, 1, Cell::Alive);
+    ///
+    /// let stats = board.classify_components();
+    /// assert_eq!((stats.still_lifes, stats.oscillators), (1, 1));
+    /// ```
+    pub fn classify_components(&self) -> ComponentStats {
+        let mut stats = ComponentStats::default();
+
+        self.components().iter().for_each(|component| {
+            match self
+                .isolate(component)
+                .detect_stability(COMPONENT_MAX_PERIOD)
+            {
+                Some((Generation(0), 1)) => stats.still_lifes += 1,
+                Some((Generation(0), _)) => stats.oscillators += 1,
+                _ => stats.other += 1,
+            }
+        });
+
+        stats
+    }
+
+    /// Every component that oscillates with exactly period `p`, each on
+    /// its own otherwise empty copy of the board
+    ///
+    /// Components are found and simulated in isolation just like in
+    /// [`Board::classify_components`], checking up to `max_check`
+    /// generations for a repeat. A period of 1 finds still lifes. The
+    /// returned boards keep each component where it was; use
+    /// [`Board::extract_pattern`] or [`Board::canonical_form`] to trim
+    /// them.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 6, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    /// board.fill_rect(6, 2, 3, 1, Cell::Alive);
+    ///
+    /// let blinkers = board.components_with_period(2, 10);
+    /// assert_eq!(blinkers.len(), 1);
+    /// assert_eq!(blinkers[0].bounding_box(), Some((6, 2, 3, 1)));
+    /// ```
+    pub fn components_with_period(&self, p: usize, max_check: usize) -> Vec<Board> {
+        self.components()
+            .iter()
+            .map(|component| self.isolate(component))
+            .filter(|isolated| isolated.detect_stability(max_check) == Some((Generation(0), p)))
+            .collect()
+    }
+
+    // A board like this one with only `component`'s cells alive
+    fn isolate(&self, component: &[(usize, usize)]) -> Board {
+        let mut isolated = Board::new(self.width, self.height, self.border.clone());
+        isolated.set_neighbor_radius(self.neighbor_radius());
+        isolated.set_rule(self.rule().clone());
+        isolated.rule_map = self.rule_map.clone();
+        component
+            .iter()
+            .for_each(|&(x, y)| isolated.set(x, y, Cell::Alive));
+
+        isolated
+    }
+
+    // The coordinates of each group of 8-connected live cells
+    pub(crate) fn components(&self) -> Vec<Vec<(usize, usize)>> {
+        let (w, h) = (self.width as i64, self.height as i64);
+        let mut seen = vec![false; self.cells.len()];
+        let mut components = vec![];
+
+        for start in 0..self.cells.len() {
+            if seen[start] || self.cells[start] == Cell::Dead {
+                continue;
+            }
+            seen[start] = true;
+            let mut stack = vec![start];
+            let mut component = vec![];
+
+            while let Some(i) = stack.pop() {
+                let (x, y) = ((i % self.width) as i64, (i / self.width) as i64);
+                component.push((x as usize, y as usize));
+
+                (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                    .filter_map(|(nx, ny)| match self.border {
+                        BorderOpt::Loop => Some((nx.rem_euclid(w), ny.rem_euclid(h))),
+                        _ if (0..w).contains(&nx) && (0..h).contains(&ny) => Some((nx, ny)),
+                        _ => None,
+                    })
+                    .map(|(nx, ny)| (ny * w + nx) as usize)
+                    .for_each(|n| {
+                        if !seen[n] && self.cells[n] == Cell::Alive {
+                            seen[n] = true;
+                            stack.push(n);
+                        }
+                    });
+            }
+            components.push(component);
+        }
+
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn settles_into_block_and_blinker() {
+        let mut board = Board::new(12, 8, BorderOpt::Empty);
+        // A block, a blinker and a lone cell that dies right away
+        board.fill_rect(1, 1, 2, 2, Cell::Alive);
+        board.fill_rect(7, 3, 3, 1, Cell::Alive);
+        board.set(2, 6, Cell::Alive);
+        assert_eq!(board.components().len(), 3);
+
+        board.advance_cycle();
+
+        assert_eq!(
+            board.classify_components(),
+            ComponentStats {
+                still_lifes: 1,
+                oscillators: 1,
+                other: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn find_blinker_among_mix() {
+        let mut board = Board::new(20, 12, BorderOpt::Empty);
+        // Block, blinker, glider and beehive
+        board.fill_rect(1, 1, 2, 2, Cell::Alive);
+        board.fill_rect(6, 2, 3, 1, Cell::Alive);
+        board
+            .place_coords(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)], 12, 1, true)
+            .unwrap();
+        board
+            .place_coords(
+                &[(1, 0), (2, 0), (0, 1), (3, 1), (1, 2), (2, 2)],
+                2,
+                7,
+                true,
+            )
+            .unwrap();
+
+        let blinkers = board.components_with_period(2, 20);
+        assert_eq!(blinkers.len(), 1);
+        assert_eq!(blinkers[0].bounding_box(), Some((6, 2, 3, 1)));
+
+        assert_eq!(board.components_with_period(1, 20).len(), 2);
+        assert!(board.components_with_period(3, 20).is_empty());
+    }
+
+    #[test]
+    fn glider_is_other() {
+        let board = Board::new_from_file("./tests/test-boards/glider.txt");
+
+        assert_eq!(board.classify_components().other, 1);
+    }
+
+    #[test]
+    fn components_wrap_on_loop() {
+        let mut board = Board::new(6, 6, BorderOpt::Loop);
+        board.set(0, 2, Cell::Alive);
+        board.set(5, 2, Cell::Alive);
+
+        assert_eq!(board.components().len(), 1);
+        board.set_border(BorderOpt::Empty);
+        assert_eq!(board.components().len(), 2);
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::Path,
+};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::ParseError,
+};
+
+impl Board {
+    /// Initialize new board from rows of `1` (alive) and `0` (dead)
+    /// separated by `delimiter`, as exported by spreadsheet applications
+    ///
+    /// The width is the number of columns in the first row. Whitespace
+    /// around values is ignored, as are blank lines.
+    ///
+    /// # Errors:
+    ///
+    /// - If a row has a different number of columns than the first
+    /// - If a value is anything other than `0` or `1`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::from_delimited("0\t1\n1\t0\n", '\t', BorderOpt::Empty).unwrap();
+    /// assert_eq!(board.get(1, 0), Cell::Alive);
+    /// ```
+    pub fn from_delimited(
+        input: &str,
+        delimiter: char,
+        border: BorderOpt,
+    ) -> Result<Self, ParseError> {
+        let mut cells: Vec<Cell> = vec![];
+        let (mut width, mut height) = (0, 0);
+
+        for (row, line) in input.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+            let values: Vec<&str> = line.split(delimiter).map(str::trim).collect();
+            width = if row == 0 { values.len() } else { width };
+            if values.len() != width {
+                return Err(ParseError {
+                    row,
+                    message: format!("{} columns, expected {}", values.len(), width),
+                });
+            }
+            for (column, value) in values.iter().enumerate() {
+                cells.push(match *value {
+                    "1" => Cell::Alive,
+                    "0" => Cell::Dead,
+                    _ => {
+                        return Err(ParseError {
+                            row,
+                            message: format!("invalid value {:?} in column {}", value, column),
+                        })
+                    }
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Board::from_cells(width, height, border, cells))
+    }
+
+    /// Initialize new board from a matrix of integers, one `Vec` per
+    /// row, where nonzero is alive and zero is dead
+    ///
+    /// Matches what numeric tools like NumPy or MATLAB export. The width
+    /// is the length of the first row.
+    ///
+    /// # Errors:
+    ///
+    /// If a row has a different length than the first
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let rows = vec![vec![0, 2], vec![-1, 0]];
+    /// let board = Board::from_i32_matrix(&rows, BorderOpt::Empty).unwrap();
+    /// assert_eq!(board.to_ascii(), ".#\n#.\n");
+    /// ```
+    pub fn from_i32_matrix(rows: &[Vec<i32>], border: BorderOpt) -> Result<Self, ParseError> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((row, values)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(ParseError {
+                row,
+                message: format!("{} columns, expected {}", values.len(), width),
+            });
+        }
+        let cells = rows
+            .iter()
+            .flatten()
+            .map(|&v| if v != 0 { Cell::Alive } else { Cell::Dead })
+            .collect();
+
+        Ok(Board::from_cells(width, rows.len(), border, cells))
+    }
+
+    /// Initialize new board from the comma-separated file at `path`, see
+    /// [`Board::from_delimited`]
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be read or isn't a valid board
+    pub fn from_csv_file<P: AsRef<Path>>(path: P, border: BorderOpt) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+
+        Self::from_delimited(&contents, ',', border)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Write the board to the file at `path` as rows of comma-separated
+    /// `1` (alive) and `0` (dead), ready to edit in a spreadsheet
+    ///
+    /// The border option isn't stored and has to be supplied again when
+    /// loading.
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be written
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let path = std::env::temp_dir().join("yagoll-csv-doc.csv");
+    ///
+    /// board.to_csv_file(&path).unwrap();
+    /// assert_eq!(Board::from_csv_file(&path, BorderOpt::Empty).unwrap(), board);
+    /// ```
+    pub fn to_csv_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let csv: String = (0..self.height)
+            .map(|y| {
+                let row: Vec<&str> = (0..self.width)
+                    .map(|x| match self.get(x, y) {
+                        Cell::Alive => "1",
+                        Cell::Dead => "0",
+                    })
+                    .collect();
+                row.join(",") + "\n"
+            })
+            .collect();
+
+        fs::write(path, csv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn i32_matrix_diagonal() {
+        let rows = vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]];
+        let board = Board::from_i32_matrix(&rows, BorderOpt::Loop).unwrap();
+
+        assert_eq!(board.to_ascii(), "#..\n.#.\n..#\n");
+        assert_eq!(board.border, BorderOpt::Loop);
+
+        let ragged = vec![vec![1, 0, 0], vec![0, 1]];
+        assert_eq!(
+            Board::from_i32_matrix(&ragged, BorderOpt::Empty)
+                .unwrap_err()
+                .to_string(),
+            "row 1: 2 columns, expected 3"
+        );
+    }
+
+    #[test]
+    fn csv_file_round_trip() {
+        let path = std::env::temp_dir().join("yagoll-csv-round-trip.csv");
+        let mut board = Board::new(4, 2, BorderOpt::Loop);
+        board.set(0, 0, Cell::Alive);
+        board.set(3, 1, Cell::Alive);
+
+        board.to_csv_file(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "1,0,0,0\n0,0,0,1\n"
+        );
+        assert_eq!(Board::from_csv_file(&path, BorderOpt::Loop).unwrap(), board);
+
+        std::fs::write(&path, "1,0\n0\n").unwrap();
+        let err = Board::from_csv_file(&path, BorderOpt::Empty).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn csv_blinker() {
+        let csv = "0,1,0\n0,1,0\n0,1,0\n";
+        let board = Board::from_delimited(csv, ',', BorderOpt::Empty).unwrap();
+
+        assert_eq!(
+            format!("{}", board),
+            "░░▓▓░░\n\
+             ░░▓▓░░\n\
+             ░░▓▓░░\n"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings_and_spaces() {
+        let csv = "0, 1\r\n1, 0\r\n";
+        let board = Board::from_delimited(csv, ',', BorderOpt::Loop).unwrap();
+
+        assert!(board.width == 2 && board.height == 2);
+        assert_eq!(board.get(0, 1), Cell::Alive);
+        assert_eq!(board.border, BorderOpt::Loop);
+    }
+
+    #[test]
+    fn ragged_and_invalid_rows() {
+        let err = Board::from_delimited("0,1,0\n0,1\n", ',', BorderOpt::Empty).unwrap_err();
+        assert_eq!(err.to_string(), "row 1: 2 columns, expected 3");
+
+        let err = Board::from_delimited("0,x\n", ',', BorderOpt::Empty).unwrap_err();
+        assert_eq!(err.to_string(), "row 0: invalid value \"x\" in column 1");
+    }
+}
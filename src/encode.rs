@@ -0,0 +1,475 @@
+use std::{
+    convert::TryInto,
+    io::{self, Error, ErrorKind, Read, Write},
+};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::DecodeError,
+};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+// The standard alphabet, for data URIs and other places that expect it
+#[cfg(feature = "image")]
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const SHARE_PARAM: &str = "b=";
+/// The first bytes of every file written by `Board::to_binary`
+const BINARY_MAGIC: &[u8; 4] = b"YGL\x01";
+// Width, height and border
+const HEADER_LEN: usize = 9;
+
+impl Board {
+    /// Encode the board as a compact, URL-safe string
+    ///
+    /// The width and height (as big-endian `u32`s), a border byte and the
+    /// cells packed 8 to a byte (row-major, most significant bit first)
+    /// are encoded with the URL-safe base64 alphabet (`-` and `_` instead
+    /// of `+` and `/`) without padding, so the result can be used in a
+    /// URL as is.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let encoded = board.encode();
+    /// assert_eq!(Board::decode(&encoded).unwrap(), board);
+    /// ```
+    pub fn encode(&self) -> String {
+        base64_encode(&self.to_packed_bytes())
+    }
+
+    /// Decode a board produced by [`Board::encode`]
+    ///
+    /// # Errors:
+    ///
+    /// If `encoded` isn't valid URL-safe base64, or doesn't hold a whole
+    /// board.
+    pub fn decode(encoded: &str) -> Result<Self, DecodeError> {
+        Self::from_packed_bytes(&base64_decode(encoded)?)
+    }
+
+    /// Write the board to `w` in a compact binary format
+    ///
+    /// The format is the magic bytes `YGL\x01`, followed by the same
+    /// bytes [`Board::encode`] wraps in base64: the width and height as
+    /// big-endian `u32`s, a border byte (`0` Empty, `1` Solid, `2` Loop)
+    /// and the cells packed 8 to a byte, row-major with the most
+    /// significant bit first.
+    ///
+    /// # Errors:
+    ///
+    /// If writing to `w` fails
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut bytes = vec![];
+    ///
+    /// board.to_binary(&mut bytes).unwrap();
+    /// assert_eq!(Board::from_binary(bytes.as_slice()).unwrap(), board);
+    /// ```
+    pub fn to_binary<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(BINARY_MAGIC)?;
+        w.write_all(&self.to_packed_bytes())
+    }
+
+    /// Read a board written by [`Board::to_binary`]
+    ///
+    /// Memory is only allocated as cell data is actually read, so a
+    /// header claiming a huge board can't exhaust memory on its own.
+    ///
+    /// # Errors:
+    ///
+    /// - If reading fails
+    /// - If the magic bytes are wrong or the border byte is invalid
+    ///   (`InvalidData`)
+    /// - If the data ends before the header or all the cells
+    ///   (`UnexpectedEof`)
+    pub fn from_binary<R: Read>(mut r: R) -> io::Result<Self> {
+        let mut magic = [0; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != BINARY_MAGIC {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "not a yagoll binary board",
+            ));
+        }
+        let mut bytes = vec![0; HEADER_LEN];
+        r.read_exact(&mut bytes)?;
+        let width = u32::from_be_bytes(bytes[0..4].try_into().unwrap()) as u64;
+        let height = u32::from_be_bytes(bytes[4..8].try_into().unwrap()) as u64;
+        let packed_len = (width * height).div_ceil(8);
+
+        r.take(packed_len).read_to_end(&mut bytes)?;
+
+        Self::from_packed_bytes(&bytes).map_err(|e| match e {
+            DecodeError::Truncated => Error::new(ErrorKind::UnexpectedEof, e),
+            _ => Error::new(ErrorKind::InvalidData, e),
+        })
+    }
+
+    // The header and packed cells shared by the text and binary encodings
+    fn to_packed_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.cells.len().div_ceil(8));
+
+        bytes.extend_from_slice(&(self.width as u32).to_be_bytes());
+        bytes.extend_from_slice(&(self.height as u32).to_be_bytes());
+        bytes.push(match self.border {
+            BorderOpt::Empty => 0,
+            BorderOpt::Solid => 1,
+            BorderOpt::Loop => 2,
+        });
+        self.cells.chunks(8).for_each(|chunk| {
+            bytes.push(
+                chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &c)| c == Cell::Alive)
+                    .fold(0, |acc, (i, _)| acc | (0x80 >> i)),
+            )
+        });
+
+        bytes
+    }
+
+    fn from_packed_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(DecodeError::Truncated);
+        }
+        let width = u32::from_be_bytes(bytes[0..4].try_into().unwrap()) as usize;
+        let height = u32::from_be_bytes(bytes[4..8].try_into().unwrap()) as usize;
+        let border = match bytes[8] {
+            0 => BorderOpt::Empty,
+            1 => BorderOpt::Solid,
+            2 => BorderOpt::Loop,
+            b => return Err(DecodeError::InvalidBorder(b)),
+        };
+        let len = width.checked_mul(height).ok_or(DecodeError::Truncated)?;
+        let packed = &bytes[HEADER_LEN..];
+        if packed.len() < len.div_ceil(8) {
+            return Err(DecodeError::Truncated);
+        }
+
+        let cells = (0..len)
+            .map(|i| {
+                if packed[i / 8] & (0x80 >> (i % 8)) != 0 {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        Ok(Board::from_cells(width, height, border, cells))
+    }
+
+    /// A link to the board, made by appending its encoding to `base` as
+    /// the `b` query parameter
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new(2, 2, BorderOpt::Empty);
+    /// let url = board.to_share_url("https://example.com/life");
+    /// assert!(url.starts_with("https://example.com/life?b="));
+    /// assert_eq!(Board::from_share_url(&url).unwrap(), board);
+    /// ```
+    pub fn to_share_url(&self, base: &str) -> String {
+        format!("{}?{}{}", base, SHARE_PARAM, self.encode())
+    }
+
+    /// Decode the board from a URL made by [`Board::to_share_url`]
+    ///
+    /// # Errors:
+    ///
+    /// If the URL has no `b` query parameter or its value can't be
+    /// decoded.
+    pub fn from_share_url(url: &str) -> Result<Self, DecodeError> {
+        let (_, query) = url.split_once('?').ok_or(DecodeError::MissingBoard)?;
+        let query = query.split('#').next().unwrap_or("");
+        let encoded = query
+            .split('&')
+            .find_map(|param| param.strip_prefix(SHARE_PARAM))
+            .ok_or(DecodeError::MissingBoard)?;
+
+        Self::decode(encoded)
+    }
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    base64_encode_with(bytes, ALPHABET, false)
+}
+
+// Standard, padded base64
+#[cfg(feature = "image")]
+pub(crate) fn base64_encode_standard(bytes: &[u8]) -> String {
+    base64_encode_with(bytes, STANDARD_ALPHABET, true)
+}
+
+fn base64_encode_with(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    bytes.chunks(3).for_each(|chunk| {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        (0..=chunk.len()).for_each(|i| {
+            out.push(alphabet[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+        });
+        if pad {
+            (chunk.len()..3).for_each(|_| out.push('='));
+        }
+    });
+
+    out
+}
+
+pub(crate) fn base64_decode(text: &str) -> Result<Vec<u8>, DecodeError> {
+    if text.len() % 4 == 1 {
+        return Err(DecodeError::InvalidLength);
+    }
+    let sextets = text
+        .chars()
+        .map(|c| {
+            ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .map(|p| p as u32)
+                .ok_or(DecodeError::InvalidCharacter(c))
+        })
+        .collect::<Result<Vec<u32>, DecodeError>>()?;
+
+    Ok(sextets
+        .chunks(4)
+        .flat_map(|chunk| {
+            let n = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |acc, (i, &s)| acc | s << (18 - 6 * i));
+            (0..chunk.len() - 1).map(move |i| (n >> (16 - 8 * i)) as u8)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{fmt::Debug, fs, path::Path};
+
+    use super::*;
+
+    // The boards every serializer has to reproduce exactly
+    fn fixtures() -> Vec<(&'static str, Board)> {
+        let mut full = Board::new(8, 4, BorderOpt::Empty);
+        full.fill_rect(0, 0, 8, 4, Cell::Alive);
+
+        vec![
+            ("empty", Board::new(6, 5, BorderOpt::Empty)),
+            (
+                "glider",
+                Board::new_from_file("./tests/test-boards/glider.txt"),
+            ),
+            ("full", full),
+            (
+                "unaligned",
+                Board::new_random(13, 7, BorderOpt::Empty, 0.5, 17),
+            ),
+        ]
+    }
+
+    // Write each fixture with `to`, read it back with `from` and check
+    // nothing changed on the way
+    fn assert_fixtures_round_trip<T: Debug>(
+        to: impl Fn(&str, &Board) -> T,
+        from: impl Fn(&str, &T) -> Board,
+    ) {
+        fixtures().iter().for_each(|(name, board)| {
+            let written = to(name, board);
+            let read = from(name, &written);
+
+            assert_eq!(
+                (read.width, read.height),
+                (board.width, board.height),
+                "{}: dimensions changed, via {:?}",
+                name,
+                written
+            );
+            assert_eq!(read, *board, "{}: board changed, via {:?}", name, written);
+        });
+    }
+
+    // Write `board` to a scratch file with `save` and return what ended up
+    // in it
+    fn via_file(name: &str, ext: &str, save: impl Fn(&Path) -> io::Result<()>) -> String {
+        let path = std::env::temp_dir().join(format!("yagoll-fixture-{}.{}", name, ext));
+        save(&path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        contents
+    }
+
+    // Put `contents` in a scratch file and read it back with `load`
+    fn from_file(name: &str, ext: &str, contents: &str, load: impl Fn(&Path) -> Board) -> Board {
+        let path = std::env::temp_dir().join(format!("yagoll-fixture-{}-read.{}", name, ext));
+        fs::write(&path, contents).unwrap();
+        let board = load(&path);
+        fs::remove_file(&path).unwrap();
+
+        board
+    }
+
+    #[test]
+    fn fixtures_via_rle() {
+        assert_fixtures_round_trip(
+            |name, b| b.to_rle(Some(name)),
+            |_, s| Board::from_rle(s, BorderOpt::Empty).unwrap(),
+        );
+    }
+
+    #[test]
+    fn fixtures_via_base64() {
+        assert_fixtures_round_trip(|_, b| b.encode(), |_, s| Board::decode(s).unwrap());
+    }
+
+    #[test]
+    fn fixtures_via_share_url() {
+        assert_fixtures_round_trip(
+            |_, b| b.to_share_url("https://example.com/life"),
+            |_, s| Board::from_share_url(s).unwrap(),
+        );
+    }
+
+    #[test]
+    fn fixtures_via_binary() {
+        assert_fixtures_round_trip(
+            |_, b| {
+                let mut bytes = vec![];
+                b.to_binary(&mut bytes).unwrap();
+                bytes
+            },
+            |_, bytes| Board::from_binary(bytes.as_slice()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn fixtures_via_hex() {
+        assert_fixtures_round_trip(
+            |name, b| via_file(name, "hex", |path| b.to_hex_file(path)),
+            |name, s| {
+                from_file(name, "hex", s, |path| {
+                    Board::from_hex_file(path, BorderOpt::Empty).unwrap()
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn fixtures_via_csv() {
+        assert_fixtures_round_trip(
+            |name, b| via_file(name, "csv", |path| b.to_csv_file(path)),
+            |name, s| {
+                from_file(name, "csv", s, |path| {
+                    Board::from_csv_file(path, BorderOpt::Empty).unwrap()
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn fixtures_via_metadata() {
+        assert_fixtures_round_trip(
+            |_, b| b.to_string_with_metadata(),
+            |_, s| Board::from_reader_limited(s.as_bytes(), 64, 64).unwrap(),
+        );
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        (0..10u8).for_each(|len| {
+            let bytes: Vec<u8> = (0..len).map(|b| b.wrapping_mul(97)).collect();
+            assert_eq!(base64_decode(&base64_encode(&bytes)).unwrap(), bytes);
+        });
+        assert_eq!(base64_encode(b"\xfb\xff"), "-_8");
+    }
+
+    #[test]
+    fn share_url_round_trip() {
+        let board = Board::new_random(13, 7, BorderOpt::Loop, 0.5, 4);
+        let url = board.to_share_url("https://example.com/play");
+        let (_, encoded) = url.split_at("https://example.com/play?b=".len());
+
+        assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(Board::from_share_url(&url).unwrap(), board);
+        assert_eq!(
+            Board::from_share_url(&format!("{}&x=1#top", url)).unwrap(),
+            board
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        // 13 columns, so rows don't line up with bytes
+        let board = Board::new_random(13, 7, BorderOpt::Solid, 0.5, 6);
+        let mut bytes: Vec<u8> = vec![];
+
+        board.to_binary(&mut bytes).unwrap();
+
+        assert_eq!(bytes.len(), 4 + HEADER_LEN + (13 * 7usize).div_ceil(8));
+        assert_eq!(Board::from_binary(bytes.as_slice()).unwrap(), board);
+    }
+
+    #[test]
+    fn binary_errors() {
+        let board = Board::new_random(13, 7, BorderOpt::Empty, 0.5, 6);
+        let mut bytes: Vec<u8> = vec![];
+        board.to_binary(&mut bytes).unwrap();
+
+        let truncated = Board::from_binary(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(truncated.kind(), ErrorKind::UnexpectedEof);
+        let no_header = Board::from_binary(&bytes[..6]).unwrap_err();
+        assert_eq!(no_header.kind(), ErrorKind::UnexpectedEof);
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        let err = Board::from_binary(bad_magic.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // A header claiming a 2³²×2³² board, with no cells behind it
+        let mut huge = BINARY_MAGIC.to_vec();
+        huge.extend_from_slice(&[0xff; 8]);
+        huge.push(0);
+        let err = Board::from_binary(huge.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            Board::from_share_url("https://example.com/play?x=1"),
+            Err(DecodeError::MissingBoard)
+        );
+        assert_eq!(Board::decode("AAAA+"), Err(DecodeError::InvalidLength));
+        assert_eq!(
+            Board::decode("AAAA+A"),
+            Err(DecodeError::InvalidCharacter('+'))
+        );
+        assert_eq!(Board::decode("AAAA"), Err(DecodeError::Truncated));
+
+        let mut encoded = Board::new(4, 4, BorderOpt::Empty).encode();
+        encoded.truncate(encoded.len() - 3);
+        assert_eq!(Board::decode(&encoded), Err(DecodeError::Truncated));
+    }
+}
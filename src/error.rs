@@ -0,0 +1,203 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// A custom rule produced a value that isn't a valid cell state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCellState {
+    /// The x coordinate of the offending cell
+    pub x: usize,
+    /// The y coordinate of the offending cell
+    pub y: usize,
+    /// The value the rule produced
+    pub value: u8,
+}
+
+impl Display for InvalidCellState {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rule produced invalid cell state {} at ({}, {})",
+            self.value, self.x, self.y
+        )
+    }
+}
+
+impl Error for InvalidCellState {}
+
+/// An encoded board couldn't be decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A character outside the URL-safe base64 alphabet
+    InvalidCharacter(char),
+    /// The text has a length no base64 encoding can have
+    InvalidLength,
+    /// The data ends before all of the board's cells
+    Truncated,
+    /// The border byte doesn't name a border option
+    InvalidBorder(u8),
+    /// A share URL has no `b` query parameter
+    MissingBoard,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter(c) => write!(f, "invalid base64 character {:?}", c),
+            DecodeError::InvalidLength => write!(f, "invalid base64 length"),
+            DecodeError::Truncated => write!(f, "encoded board is truncated"),
+            DecodeError::InvalidBorder(b) => write!(f, "invalid border byte {}", b),
+            DecodeError::MissingBoard => write!(f, "url has no board parameter"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Text couldn't be parsed as a board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The zero-based row (line) where parsing failed
+    pub row: usize,
+    /// What was wrong with it
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "row {}: {}", self.row, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Two boards that had to be the same size weren't
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeMismatch {
+    /// The dimensions of the board the operation was called on
+    pub expected: (usize, usize),
+    /// The dimensions of the other board
+    pub found: (usize, usize),
+}
+
+impl Display for ShapeMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "dimension mismatch: self is {}x{}, other is {}x{}",
+            self.expected.0, self.expected.1, self.found.0, self.found.1
+        )
+    }
+}
+
+impl Error for ShapeMismatch {}
+
+/// A rule string couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRule(pub String);
+
+impl Display for InvalidRule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid rule: {}", self.0)
+    }
+}
+
+impl Error for InvalidRule {}
+
+/// A cell would have been placed outside the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The x coordinate on the board the cell mapped to
+    pub x: i64,
+    /// The y coordinate on the board the cell mapped to
+    pub y: i64,
+}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "({}, {}) is outside the board", self.x, self.y)
+    }
+}
+
+impl Error for OutOfBounds {}
+
+/// A board's cell count wouldn't fit in a `usize`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionsTooLarge {
+    /// The requested width
+    pub width: usize,
+    /// The requested height
+    pub height: usize,
+}
+
+impl Display for DimensionsTooLarge {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a {}x{} board has more cells than fit in memory",
+            self.width, self.height
+        )
+    }
+}
+
+impl Error for DimensionsTooLarge {}
+
+/// A board couldn't be created with the requested dimensions, see
+/// [`Board::new_checked`](crate::Board::new_checked)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+    /// The width or height is 0
+    ZeroSized {
+        /// The requested width
+        width: usize,
+        /// The requested height
+        height: usize,
+    },
+    /// The cell count wouldn't fit in a `usize`
+    TooLarge(DimensionsTooLarge),
+    /// The board would have more cells than the given limit
+    OverLimit {
+        /// The requested width
+        width: usize,
+        /// The requested height
+        height: usize,
+        /// The most cells allowed
+        max_cells: usize,
+    },
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BoardError::ZeroSized { width, height } => {
+                write!(f, "a {}x{} board has no cells", width, height)
+            }
+            BoardError::TooLarge(e) => write!(f, "{}", e),
+            BoardError::OverLimit {
+                width,
+                height,
+                max_cells,
+            } => write!(
+                f,
+                "a {}x{} board has more than {} cells",
+                width, height, max_cells
+            ),
+        }
+    }
+}
+
+impl Error for BoardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoardError::TooLarge(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DimensionsTooLarge> for BoardError {
+    fn from(e: DimensionsTooLarge) -> Self {
+        BoardError::TooLarge(e)
+    }
+}
@@ -0,0 +1,393 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{board::Board, cell::Cell};
+
+/// What happens to a cell in the next generation, and why
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellFate {
+    /// A dead cell with a birth count of live neighbors comes to life
+    Born,
+    /// A dead cell stays dead
+    StaysDead,
+    /// A live cell with a survival count of live neighbors stays alive
+    Survives,
+    /// A live cell has fewer live neighbors than it needs to survive
+    Underpopulation,
+    /// A live cell has more live neighbors than it can survive
+    Overpopulation,
+    /// A live cell's neighbor count falls in a gap between the counts it
+    /// survives, under rules where that's possible
+    Dies,
+    /// The cell is frozen and keeps its state regardless
+    Frozen,
+}
+
+impl CellFate {
+    /// Whether the cell is alive in the next generation, given its
+    /// current state
+    pub fn next_state(self, current: Cell) -> Cell {
+        match self {
+            CellFate::Born | CellFate::Survives => Cell::Alive,
+            CellFate::Frozen => current,
+            _ => Cell::Dead,
+        }
+    }
+}
+
+impl Display for CellFate {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CellFate::Born => write!(f, "born"),
+            CellFate::StaysDead => write!(f, "stays dead"),
+            CellFate::Survives => write!(f, "survives"),
+            CellFate::Underpopulation => write!(f, "dies (underpopulation)"),
+            CellFate::Overpopulation => write!(f, "dies (overpopulation)"),
+            CellFate::Dies => write!(f, "dies"),
+            CellFate::Frozen => write!(f, "frozen"),
+        }
+    }
+}
+
+/// Everything that goes into one cell's next state, see
+/// [`Board::explain_cell`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellExplanation {
+    /// The cell's position
+    pub x: usize,
+    /// The cell's position
+    pub y: usize,
+    /// The cell's current state
+    pub state: Cell,
+    /// The number of live cells in its neighborhood
+    pub live_neighbors: usize,
+    /// Where those live neighbors are, relative to the board's origin
+    ///
+    /// Neighbors supplied by the border, i.e. wrapped around on `Loop`
+    /// boards or made up by a `Solid` border, lie outside the board.
+    pub neighbors: Vec<(i64, i64)>,
+    /// What happens next
+    pub fate: CellFate,
+}
+
+impl Display for CellExplanation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}) is {} with {} live neighbor{}: {}",
+            self.x,
+            self.y,
+            if self.state == Cell::Alive {
+                "alive"
+            } else {
+                "dead"
+            },
+            self.live_neighbors,
+            if self.live_neighbors == 1 { "" } else { "s" },
+            self.fate
+        )
+    }
+}
+
+/// How many cells went through each kind of transition in one
+/// generation, see [`Board::advance_cycle_stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransitionStats {
+    /// Dead cells that came to life
+    pub born: usize,
+    /// Live cells that stayed alive
+    pub survived: usize,
+    /// Live cells that died with too few live neighbors
+    pub underpopulation: usize,
+    /// Live cells that died with too many live neighbors
+    pub overpopulation: usize,
+    /// Live cells that died with a count between ones they survive, see
+    /// [`CellFate::Dies`]
+    pub other_deaths: usize,
+}
+
+impl Board {
+    /// Explain what happens to the cell at `x` and `y` in the next
+    /// generation under its rule, and why
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(0, 1, 3, 1, Cell::Alive);
+    ///
+    /// let explanation = board.explain_cell(1, 0);
+    /// assert_eq!(explanation.fate, CellFate::Born);
+    /// assert_eq!(
+    ///     explanation.to_string(),
+    ///     "(1, 0) is dead with 3 live neighbors: born"
+    /// );
+    /// ```
+    pub fn explain_cell(&self, x: usize, y: usize) -> CellExplanation {
+        let state = self.get(x, y);
+        let r = self.neighbor_radius() as i64;
+        let (cx, cy) = (x as i64, y as i64);
+        let neighbors: Vec<(i64, i64)> = (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (cx + dx, cy + dy)))
+            .filter(|&n| n != (cx, cy))
+            .filter(|&(nx, ny)| self.effective_neighbor(nx, ny) == Cell::Alive)
+            .collect();
+        let live_neighbors = neighbors.len();
+
+        CellExplanation {
+            x,
+            y,
+            state,
+            live_neighbors,
+            neighbors,
+            fate: self.fate(x, y, live_neighbors),
+        }
+    }
+
+    /// Advance board state by one cycle, counting how many cells went
+    /// through each kind of transition
+    ///
+    /// The cells are classified like [`Board::explain_cell`] does before
+    /// the board advances. Under Conway's rules underpopulation means at
+    /// most 1 live neighbor and overpopulation at least 4.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(1, 2, 3, 1, Cell::Alive);
+    ///
+    /// let stats = board.advance_cycle_stats();
+    /// assert_eq!((stats.born, stats.survived, stats.underpopulation), (2, 1, 2));
+    /// ```
+    pub fn advance_cycle_stats(&mut self) -> TransitionStats {
+        let mut stats = TransitionStats::default();
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .map(|(x, y)| self.fate(x, y, self.get_live_neighbor_count(x, y)))
+            .for_each(|fate| match fate {
+                CellFate::Born => stats.born += 1,
+                CellFate::Survives => stats.survived += 1,
+                CellFate::Underpopulation => stats.underpopulation += 1,
+                CellFate::Overpopulation => stats.overpopulation += 1,
+                CellFate::Dies => stats.other_deaths += 1,
+                CellFate::StaysDead | CellFate::Frozen => {}
+            });
+        self.advance_cycle();
+
+        stats
+    }
+
+    // What happens to the cell at `x`, `y` with `live_neighbors` live
+    // neighbors under its rule
+    fn fate(&self, x: usize, y: usize, live_neighbors: usize) -> CellFate {
+        let r = self.neighbor_radius();
+        let rule = self.rule_at(x, y);
+        let most = (2 * r + 1) * (2 * r + 1) - 1;
+
+        match self.get(x, y) {
+            _ if self.is_frozen(x, y) => CellFate::Frozen,
+            Cell::Dead if rule.is_born(live_neighbors) => CellFate::Born,
+            Cell::Dead => CellFate::StaysDead,
+            Cell::Alive if rule.survives(live_neighbors) => CellFate::Survives,
+            Cell::Alive if !(0..live_neighbors).any(|n| rule.survives(n)) => {
+                CellFate::Underpopulation
+            }
+            Cell::Alive if !(live_neighbors..=most).any(|n| rule.survives(n)) => {
+                CellFate::Overpopulation
+            }
+            Cell::Alive => CellFate::Dies,
+        }
+    }
+
+    /// Whether each cell keeps its state in the next generation, in
+    /// row-major order like the board's cells
+    ///
+    /// The board isn't advanced. A viewer can use this to dim the parts
+    /// of a board that have settled.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 1, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// assert_eq!(board.stable_mask(), vec![true, false, true]);
+    /// ```
+    pub fn stable_mask(&self) -> Vec<bool> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                self.is_frozen(x, y)
+                    || self.cell_should_live(x, y) == (self.get(x, y) == Cell::Alive)
+            })
+            .collect()
+    }
+
+    /// A copy of the board without the live cells that die in the next
+    /// generation
+    ///
+    /// Only deaths are previewed; nothing is born. Handy for cleaning up
+    /// the lone cells of a noisy soup before classifying what's left.
+    /// Frozen cells never die, so they're kept.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(0, 1, 3, 1, Cell::Alive);
+    /// assert_eq!(board.prune_dying().to_ascii(), "...\n.#.\n...\n");
+    /// ```
+    pub fn prune_dying(&self) -> Board {
+        let mut board = self.clone();
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.get(x, y) == Cell::Alive)
+            .filter(|&(x, y)| !self.is_frozen(x, y) && !self.cell_should_live(x, y))
+            .for_each(|(x, y)| board.set(x, y, Cell::Dead));
+
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{board::BorderOpt, rule::Rule};
+
+    #[test]
+    fn blinker_endpoint_dies() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+
+        let end = board.explain_cell(1, 2);
+        assert_eq!(end.state, Cell::Alive);
+        assert_eq!(end.live_neighbors, 1);
+        assert_eq!(end.neighbors, vec![(2, 2)]);
+        assert_eq!(end.fate.to_string(), "dies (underpopulation)");
+
+        let middle = board.explain_cell(2, 2);
+        assert_eq!(middle.fate, CellFate::Survives);
+
+        // Every explanation agrees with what actually happens
+        let next = board.clone().advanced();
+        (0..5).for_each(|y| {
+            (0..5).for_each(|x| {
+                let explanation = board.explain_cell(x, y);
+                assert_eq!(
+                    explanation.fate.next_state(explanation.state),
+                    next.get(x, y)
+                );
+            })
+        });
+    }
+
+    #[test]
+    fn transition_breakdown() {
+        // A row of four: the ends have 1 neighbor, the middle two have 2,
+        // and above and below the middle two are 3 neighbors each
+        let mut board = Board::new(6, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 4, 1, Cell::Alive);
+        let expected = board.clone().advanced();
+
+        let stats = board.advance_cycle_stats();
+        assert_eq!(
+            stats,
+            TransitionStats {
+                born: 4,
+                survived: 2,
+                underpopulation: 2,
+                overpopulation: 0,
+                other_deaths: 0,
+            }
+        );
+        assert_eq!(board, expected);
+
+        // A plus sign's center has 4 neighbors
+        let mut plus = Board::new(5, 5, BorderOpt::Empty);
+        plus.fill_rect(1, 2, 3, 1, Cell::Alive);
+        plus.fill_rect(2, 1, 1, 3, Cell::Alive);
+        let stats = plus.advance_cycle_stats();
+        assert_eq!((stats.overpopulation, stats.survived), (1, 4));
+        assert_eq!(stats.born, 4);
+    }
+
+    #[test]
+    fn block_is_stable_blinker_is_not() {
+        let mut board = Board::new(10, 6, BorderOpt::Empty);
+        board.fill_rect(1, 1, 2, 2, Cell::Alive);
+        board.fill_rect(6, 2, 3, 1, Cell::Alive);
+
+        let mask = board.stable_mask();
+        let stable = |x: usize, y: usize| mask[y * 10 + x];
+
+        [(1, 1), (2, 1), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| assert!(stable(x, y)));
+        [(6, 2), (8, 2), (7, 1), (7, 3)]
+            .iter()
+            .for_each(|&(x, y)| assert!(!stable(x, y)));
+        assert!(stable(7, 2));
+
+        // Exactly the cells that change are unstable
+        let next = board.clone().advanced();
+        let changed = board.diff(&next).unwrap();
+        assert_eq!(mask.iter().filter(|&&s| !s).count(), changed.len());
+    }
+
+    #[test]
+    fn prune_lone_cells() {
+        let mut board = Board::new(10, 8, BorderOpt::Empty);
+        board.fill_rect(1, 1, 2, 2, Cell::Alive);
+        [(6, 1), (8, 5), (0, 7)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        let mut block = Board::new(10, 8, BorderOpt::Empty);
+        block.fill_rect(1, 1, 2, 2, Cell::Alive);
+
+        assert_eq!(board.prune_dying(), block);
+        assert_eq!(block.prune_dying(), block);
+
+        board.freeze(8, 5);
+        assert_eq!(board.prune_dying().get(8, 5), Cell::Alive);
+    }
+
+    #[test]
+    fn overpopulation_and_gaps() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.fill_rect(0, 0, 3, 3, Cell::Alive);
+        assert_eq!(board.explain_cell(1, 1).fate, CellFate::Overpopulation);
+
+        // Under B3/S14 a cell with 2 neighbors is in neither extreme
+        board.set_rule(Rule::parse("B3/S14").unwrap());
+        board.clear_rect(0, 0, 3, 3);
+        board.fill_rect(0, 1, 3, 1, Cell::Alive);
+        assert_eq!(board.explain_cell(1, 1).fate, CellFate::Dies);
+
+        board.freeze(1, 1);
+        assert_eq!(board.explain_cell(1, 1).fate, CellFate::Frozen);
+    }
+
+    #[test]
+    fn border_neighbors_lie_off_the_board() {
+        let mut board = Board::new(3, 3, BorderOpt::Loop);
+        board.set(2, 0, Cell::Alive);
+
+        assert_eq!(board.explain_cell(0, 0).neighbors, vec![(-1, 0)]);
+        assert_eq!(
+            board.explain_cell(0, 0).to_string(),
+            "(0, 0) is dead with 1 live neighbor: stays dead"
+        );
+    }
+}
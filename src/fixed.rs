@@ -0,0 +1,358 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    rule::Rule,
+};
+
+/// A rectangular grid of cells with a border mode, the part of a board
+/// the neighbor and rule logic needs
+///
+/// Implemented by both the heap-backed [`Board`] and the fixed-size
+/// [`FixedBoard`], which share the provided methods, so the two can't
+/// disagree on what a cell's neighbors are or what it becomes next.
+pub trait Grid {
+    /// The number of columns
+    fn grid_width(&self) -> usize;
+    /// The number of rows
+    fn grid_height(&self) -> usize;
+    /// What's beyond the edges
+    fn grid_border(&self) -> &BorderOpt;
+    /// The cell at `x` and `y`, which are on the grid
+    fn cell(&self, x: usize, y: usize) -> Cell;
+
+    /// The cell a neighbor at `x` and `y` would see, even if that's off
+    /// the grid, as decided by the border mode
+    fn neighbor(&self, x: i64, y: i64) -> Cell {
+        let (w, h) = (self.grid_width() as i64, self.grid_height() as i64);
+
+        if (0..w).contains(&x) && (0..h).contains(&y) {
+            return self.cell(x as usize, y as usize);
+        }
+        match self.grid_border() {
+            BorderOpt::Solid => Cell::Alive,
+            BorderOpt::Empty => Cell::Dead,
+            BorderOpt::Loop => self.cell(x.rem_euclid(w) as usize, y.rem_euclid(h) as usize),
+        }
+    }
+
+    /// The number of live cells within `radius` of the cell at `x` and
+    /// `y`, not counting the cell itself
+    fn live_neighbors(&self, x: usize, y: usize, radius: usize) -> usize {
+        let r = radius as i64;
+        let (x, y) = (x as i64, y as i64);
+
+        (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter(|&(dx, dy)| self.neighbor(x + dx, y + dy) == Cell::Alive)
+            .count()
+    }
+
+    /// The number of live cells at each of `offsets` from the cell at `x`
+    /// and `y`, for neighborhoods other than the square around the cell
+    ///
+    /// An offset listed twice counts twice, and `(0, 0)` counts the cell
+    /// itself.
+    fn live_neighbors_at_offsets(&self, x: usize, y: usize, offsets: &[(i32, i32)]) -> usize {
+        let (x, y) = (x as i64, y as i64);
+
+        offsets
+            .iter()
+            .filter(|&&(dx, dy)| self.neighbor(x + i64::from(dx), y + i64::from(dy)) == Cell::Alive)
+            .count()
+    }
+
+    /// The state of the cell at `x` and `y` after one generation under
+    /// `rule`, with neighbors within `radius`
+    fn next_cell(&self, x: usize, y: usize, rule: &Rule, radius: usize) -> Cell {
+        let neighbors = self.live_neighbors(x, y, radius);
+        let lives = match self.cell(x, y) {
+            Cell::Alive => rule.survives(neighbors),
+            Cell::Dead => rule.is_born(neighbors),
+        };
+
+        if lives {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+}
+
+impl Grid for Board {
+    fn grid_width(&self) -> usize {
+        self.width
+    }
+
+    fn grid_height(&self) -> usize {
+        self.height
+    }
+
+    fn grid_border(&self) -> &BorderOpt {
+        &self.border
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        self.get(x, y)
+    }
+}
+
+/// A board whose size is part of its type, stored inline without any
+/// heap allocation
+///
+/// Meant for small, known sizes, like embedded targets or tight loops
+/// over many tiny boards. Neighbors and rules work exactly like on
+/// [`Board`], through [`Grid`], with the neighbor radius fixed at 1.
+/// Stepping and printing don't allocate; use [`FixedBoard::to_board`]
+/// for everything else a [`Board`] can do.
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// let mut blinker = FixedBoard::<5, 5>::new(BorderOpt::Empty);
+/// (1..4).for_each(|x| blinker.set(x, 2, Cell::Alive));
+///
+/// blinker.advance_cycle();
+/// assert_eq!(blinker.get(2, 1), Cell::Alive);
+/// assert_eq!(blinker.get(1, 2), Cell::Dead);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedBoard<const W: usize, const H: usize> {
+    cells: [[Cell; W]; H],
+    border: BorderOpt,
+}
+
+impl<const W: usize, const H: usize> FixedBoard<W, H> {
+    /// Create an empty board
+    pub fn new(border: BorderOpt) -> Self {
+        FixedBoard {
+            cells: [[Cell::Dead; W]; H],
+            border,
+        }
+    }
+
+    /// Get the cell at `x` and `y`
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` is off the board
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[y][x]
+    }
+
+    /// Set the cell at `x` and `y`
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` is off the board
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y][x] = cell;
+    }
+
+    /// Get the border mode
+    pub fn border(&self) -> &BorderOpt {
+        &self.border
+    }
+
+    /// Set the border mode
+    pub fn set_border(&mut self, border: BorderOpt) {
+        self.border = border;
+    }
+
+    /// Advance the board by one generation under Conway's rules (B3/S23)
+    pub fn advance_cycle(&mut self) {
+        // Spelled out rather than through `Rule`, whose lists live on the
+        // heap
+        self.step(
+            |board, x, y| match (board.get(x, y), board.live_neighbors(x, y, 1)) {
+                (Cell::Alive, 2) | (_, 3) => Cell::Alive,
+                _ => Cell::Dead,
+            },
+        );
+    }
+
+    /// Advance the board by one generation under `rule`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = FixedBoard::<3, 3>::new(BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    ///
+    /// // Seeds (B2/S): a lone cell dies and nothing is born
+    /// board.advance_cycle_with_rule(&Rule::new(&[2], &[]));
+    /// assert_eq!(board, FixedBoard::new(BorderOpt::Empty));
+    /// ```
+    pub fn advance_cycle_with_rule(&mut self, rule: &Rule) {
+        self.step(|board, x, y| board.next_cell(x, y, rule, 1));
+    }
+
+    /// The number of live cells
+    pub fn population(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|&&c| c == Cell::Alive)
+            .count()
+    }
+
+    /// Copy the board into a [`Board`] with the same cells and border
+    pub fn to_board(&self) -> Board {
+        let mut board = Board::new(W, H, self.border.clone());
+        (0..H)
+            .flat_map(|y| (0..W).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.get(x, y) == Cell::Alive)
+            .for_each(|(x, y)| board.set(x, y, Cell::Alive));
+
+        board
+    }
+
+    // Replace every cell with what `next` makes of it, reading from a
+    // copy of the old cells on the stack
+    fn step<F: Fn(&Self, usize, usize) -> Cell>(&mut self, next: F) {
+        let previous = FixedBoard::<W, H> {
+            cells: self.cells,
+            border: self.border.clone(),
+        };
+
+        (0..H).for_each(|y| {
+            (0..W).for_each(|x| {
+                self.cells[y][x] = next(&previous, x, y);
+            })
+        });
+    }
+}
+
+impl<const W: usize, const H: usize> Grid for FixedBoard<W, H> {
+    fn grid_width(&self) -> usize {
+        W
+    }
+
+    fn grid_height(&self) -> usize {
+        H
+    }
+
+    fn grid_border(&self) -> &BorderOpt {
+        &self.border
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        self.get(x, y)
+    }
+}
+
+impl<const W: usize, const H: usize> Display for FixedBoard<W, H> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.cells.iter().try_for_each(|row| {
+            row.iter().try_for_each(|c| write!(f, "{}", c))?;
+            writeln!(f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const BORDERS: [BorderOpt; 3] = [BorderOpt::Empty, BorderOpt::Solid, BorderOpt::Loop];
+
+    fn glider<const W: usize, const H: usize>(border: BorderOpt) -> FixedBoard<W, H> {
+        let mut board = FixedBoard::new(border);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        board
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        // A Solid border brings the edges to life, see
+        // `matches_dynamic_board` for that
+        [BorderOpt::Empty, BorderOpt::Loop]
+            .iter()
+            .for_each(|border| {
+                let mut board = FixedBoard::<5, 5>::new(border.clone());
+                (1..4).for_each(|x| board.set(x, 2, Cell::Alive));
+                let start = board.clone();
+
+                board.advance_cycle();
+                assert_ne!(board, start);
+                assert!((1..4).all(|y| board.get(2, y) == Cell::Alive));
+                assert_eq!(board.population(), 3);
+                board.advance_cycle();
+                assert_eq!(board, start, "{:?}", border);
+            });
+    }
+
+    #[test]
+    fn glider_moves_diagonally() {
+        let mut board = glider::<8, 8>(BorderOpt::Empty);
+        (0..4).for_each(|_| board.advance_cycle());
+
+        let mut moved = FixedBoard::<8, 8>::new(BorderOpt::Empty);
+        [(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]
+            .iter()
+            .for_each(|&(x, y)| moved.set(x, y, Cell::Alive));
+        assert_eq!(board, moved);
+
+        // Around the torus and back to where it started
+        let mut looped = glider::<5, 5>(BorderOpt::Loop);
+        let start = looped.clone();
+        (0..20).for_each(|_| looped.advance_cycle());
+        assert_eq!(looped, start);
+    }
+
+    #[test]
+    fn matches_dynamic_board() {
+        BORDERS.iter().for_each(|border| {
+            let mut blinker = FixedBoard::<6, 5>::new(border.clone());
+            (1..4).for_each(|x| blinker.set(x, 2, Cell::Alive));
+
+            [glider::<6, 5>(border.clone()), blinker]
+                .iter()
+                .for_each(|start| {
+                    let mut fixed = start.clone();
+                    let mut dynamic = fixed.to_board();
+
+                    (0..12).for_each(|step| {
+                        assert_eq!(fixed.to_board(), dynamic, "{:?} step {}", border, step);
+                        assert_eq!(fixed.to_string(), dynamic.to_string());
+                        fixed.advance_cycle();
+                        dynamic.advance_cycle();
+                    });
+                });
+        });
+    }
+
+    #[test]
+    fn custom_rule_matches_dynamic_board() {
+        let highlife = Rule::new(&[3, 6], &[2, 3]);
+        let mut fixed = glider::<7, 7>(BorderOpt::Loop);
+        let mut dynamic = fixed.to_board();
+        dynamic.set_rule(highlife.clone());
+
+        (0..10).for_each(|_| {
+            fixed.advance_cycle_with_rule(&highlife);
+            dynamic.advance_cycle();
+            assert_eq!(fixed.to_string(), dynamic.to_string());
+        });
+    }
+
+    #[test]
+    fn shared_neighbor_logic() {
+        let fixed = glider::<3, 3>(BorderOpt::Solid);
+        let dynamic = fixed.to_board();
+
+        (-1..4).for_each(|y| {
+            (-1..4).for_each(|x| assert_eq!(fixed.neighbor(x, y), dynamic.neighbor(x, y)))
+        });
+        assert_eq!(fixed.live_neighbors(0, 0, 1), 6);
+    }
+}
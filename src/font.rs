@@ -0,0 +1,148 @@
+use crate::{board::Board, cell::Cell};
+
+const GLYPH_WIDTH: usize = 3;
+const GLYPH_HEIGHT: usize = 5;
+const GLYPH_SPACING: usize = 1;
+const LINE_SPACING: usize = 1;
+
+// Each glyph is five rows, top to bottom, where bit 0b100 is the leftmost
+// column of a row
+fn glyph(c: char) -> [u8; GLYPH_HEIGHT] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+        '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+        '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '?' => [0b110, 0b001, 0b010, 0b000, 0b010],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        _ => [0; GLYPH_HEIGHT],
+    }
+}
+
+impl Board {
+    /// Write `text` onto the board as live cells, with the top-left of
+    /// the first character at `x` and `y`
+    ///
+    /// Characters are drawn with a built-in 3×5 font covering ASCII
+    /// letters (case-insensitive), digits and `!?.-`, with one dead
+    /// column between characters. Any other character is drawn as a
+    /// blank space and `\n` starts a new line below. Only live cells are
+    /// written, so existing live cells are kept, and anything that falls
+    /// off the board, or past the range of `usize`, is clipped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(12, 7, BorderOpt::Empty);
+    /// board.place_text("Hi", 1, 1);
+    /// assert_eq!(board.get(1, 1), Cell::Alive);
+    /// assert_eq!(board.get(4, 1), Cell::Dead);
+    /// ```
+    pub fn place_text(&mut self, text: &str, x: usize, y: usize) {
+        let (width, height) = (self.width, self.height);
+
+        text.lines().enumerate().for_each(|(line, l)| {
+            let y0 = line
+                .checked_mul(GLYPH_HEIGHT + LINE_SPACING)
+                .and_then(|dy| y.checked_add(dy));
+
+            l.chars().enumerate().for_each(|(i, c)| {
+                let x0 = i
+                    .checked_mul(GLYPH_WIDTH + GLYPH_SPACING)
+                    .and_then(|dx| x.checked_add(dx));
+
+                glyph(c).iter().enumerate().for_each(|(dy, row)| {
+                    (0..GLYPH_WIDTH)
+                        .filter(|dx| row & (0b100 >> dx) != 0)
+                        .filter_map(|dx| Some((x0?.checked_add(dx)?, y0?.checked_add(dy)?)))
+                        .filter(|&(cx, cy)| cx < width && cy < height)
+                        .for_each(|(cx, cy)| self.set(cx, cy, Cell::Alive));
+                });
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    #[test]
+    fn place_hi() {
+        let mut board = Board::new(9, 7, BorderOpt::Empty);
+
+        board.place_text("HI", 1, 1);
+
+        // H
+        (1..=5).for_each(|y| {
+            assert_eq!(board.get(1, y), Cell::Alive);
+            assert_eq!(board.get(3, y), Cell::Alive);
+        });
+        assert_eq!(board.get(2, 3), Cell::Alive);
+        assert_eq!(board.get(2, 2), Cell::Dead);
+        // Spacing column
+        (0..7).for_each(|y| assert_eq!(board.get(4, y), Cell::Dead));
+        // I
+        assert_eq!(board.get(5, 1), Cell::Alive);
+        assert_eq!(board.get(6, 3), Cell::Alive);
+        assert_eq!(board.get(7, 5), Cell::Alive);
+        assert_eq!(board.get(5, 3), Cell::Dead);
+    }
+
+    #[test]
+    fn place_text_clips() {
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+
+        board.place_text("HI\nHI", 2, 1);
+
+        assert_eq!(board.get(2, 1), Cell::Alive);
+        assert_eq!(board.get(3, 1), Cell::Dead);
+    }
+
+    #[test]
+    fn place_text_past_usize() {
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+
+        board.place_text("HI\nHI", usize::MAX - 1, usize::MAX - 1);
+        board.place_text("HI", usize::MAX, 0);
+        assert_eq!(board.live_in_rect(0, 0, 4, 3), 0);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+};
+
+impl Board {
+    /// Freeze the cell at `x` and `y`, so it keeps its current state
+    /// through every later cycle
+    ///
+    /// A frozen cell still counts as a neighbor as usual; it just never
+    /// changes on its own. It can still be changed with [`Board::set`].
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.freeze(1, 1);
+    ///
+    /// // A lone cell would normally die of loneliness
+    /// board.advance_cycle();
+    /// assert_eq!(board.get(1, 1), Cell::Alive);
+    /// ```
+    pub fn freeze(&mut self, x: usize, y: usize) {
+        self.set_frozen(x, y, true);
+    }
+
+    /// Let the cell at `x` and `y` follow the rules again
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn unfreeze(&mut self, x: usize, y: usize) {
+        self.set_frozen(x, y, false);
+    }
+
+    /// Whether the cell at `x` and `y` is frozen
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn is_frozen(&self, x: usize, y: usize) -> bool {
+        self.get(x, y);
+        self.frozen[y * self.width + x]
+    }
+
+    /// Like [`Board::stamp`], but also freeze every cell the pattern
+    /// makes alive
+    ///
+    /// Handy for placing scenery, like a still life, that should stay
+    /// exactly as it is no matter what runs into it. The frozen cells
+    /// still count as neighbors. Cells falling outside the board are
+    /// clipped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut dot = Board::new(1, 1, BorderOpt::Empty);
+    /// dot.set(0, 0, Cell::Alive);
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.stamp_frozen(&dot, 1, 1);
+    /// board.advance_cycle();
+    /// assert!(board.is_frozen(1, 1));
+    /// assert_eq!(board.get(1, 1), Cell::Alive);
+    /// ```
+    pub fn stamp_frozen(&mut self, pattern: &Board, x: usize, y: usize) {
+        self.stamp(pattern, x, y);
+
+        let (width, height) = (self.width, self.height);
+        pattern
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .filter_map(|(i, _)| {
+                Some((
+                    x.checked_add(i % pattern.width)?,
+                    y.checked_add(i / pattern.width)?,
+                ))
+            })
+            .filter(|&(px, py)| px < width && py < height)
+            .for_each(|(px, py)| self.freeze(px, py));
+    }
+
+    /// Initialize a new `Empty` board surrounded by a frozen, one cell
+    /// thick wall of live cells
+    ///
+    /// Unlike a [`BorderOpt::Solid`] border the wall is part of the
+    /// board, so it takes up the outermost ring of cells and patterns can
+    /// be drawn right up against it.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut arena = Board::with_wall(6, 5);
+    /// arena.advance_n_cycles(3);
+    /// assert_eq!(arena.get(0, 0), Cell::Alive);
+    /// assert_eq!(arena.get(5, 4), Cell::Alive);
+    /// ```
+    pub fn with_wall(width: usize, height: usize) -> Self {
+        let mut board = Board::new(width, height, BorderOpt::Empty);
+
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&(x, y)| x == 0 || y == 0 || x + 1 == width || y + 1 == height)
+            .for_each(|(x, y)| {
+                board.set(x, y, Cell::Alive);
+                board.freeze(x, y);
+            });
+
+        board
+    }
+
+    fn set_frozen(&mut self, x: usize, y: usize, frozen: bool) {
+        // Bounds check
+        self.get(x, y);
+        self.frozen[y * self.width + x] = frozen;
+        // A thawed cell may need evaluating even if nothing changed
+        self.invalidate_frontier();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::Backend;
+
+    #[test]
+    fn frozen_block_survives_overpopulation() {
+        let mut block = Board::new(2, 2, BorderOpt::Empty);
+        block.fill_rect(0, 0, 2, 2, Cell::Alive);
+
+        // A ring of live cells hugging the block, overcrowding it
+        let mut board = Board::new(8, 8, BorderOpt::Empty);
+        board.fill_rect(2, 2, 4, 4, Cell::Alive);
+        board.clear_rect(3, 3, 2, 2);
+        let mut unfrozen = board.clone();
+        unfrozen.stamp(&block, 3, 3);
+        board.stamp_frozen(&block, 3, 3);
+
+        unfrozen.advance_cycle();
+        assert_eq!(unfrozen.live_in_rect(3, 3, 2, 2), 0);
+
+        (0..10).for_each(|_| {
+            board.advance_cycle();
+            assert_eq!(board.live_in_rect(3, 3, 2, 2), 4);
+        });
+        assert!(!board.is_frozen(2, 2));
+
+        // Clipped, even where the coordinates themselves would overflow
+        let before = board.clone();
+        board.stamp_frozen(&block, usize::MAX, 7);
+        board.stamp_frozen(&block, 7, usize::MAX - 1);
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn wall_persists() {
+        [Backend::Scalar, Backend::Bitboard]
+            .iter()
+            .for_each(|&backend| {
+                let mut arena = Board::with_wall(8, 7);
+                arena.set_backend(backend);
+
+                arena.advance_n_cycles(5);
+
+                (0..8).for_each(|x| {
+                    assert!(arena.is_frozen(x, 0));
+                    assert_eq!(arena.get(x, 0), Cell::Alive);
+                    assert_eq!(arena.get(x, 6), Cell::Alive);
+                });
+                (0..7).for_each(|y| {
+                    assert_eq!(arena.get(0, y), Cell::Alive);
+                    assert_eq!(arena.get(7, y), Cell::Alive);
+                });
+            });
+    }
+
+    #[test]
+    fn interior_follows_rules() {
+        // A blinker in the middle of a 9×9 arena is far enough from the
+        // wall to blink as usual
+        let mut arena = Board::with_wall(9, 9);
+        (3..6).for_each(|x| arena.set(x, 4, Cell::Alive));
+
+        arena.advance_cycle();
+
+        assert!(!arena.is_frozen(4, 4));
+        assert_eq!(arena.get(4, 3), Cell::Alive);
+        assert_eq!(arena.get(4, 5), Cell::Alive);
+        assert_eq!(arena.get(3, 4), Cell::Dead);
+
+        // Next to the wall, cells see its live cells as neighbors: (2, 1)
+        // has three and is born, the corner (1, 1) has five and isn't
+        assert_eq!(arena.get(2, 1), Cell::Alive);
+        assert_eq!(arena.get(1, 1), Cell::Dead);
+    }
+
+    #[test]
+    fn unfrozen_cells_change() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.set(1, 1, Cell::Alive);
+        board.freeze(1, 1);
+        board.unfreeze(1, 1);
+
+        board.advance_cycle();
+
+        assert_eq!(board.get(1, 1), Cell::Dead);
+    }
+}
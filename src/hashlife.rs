@@ -0,0 +1,260 @@
+//! Memoized block stepping, a much simplified take on HashLife
+//!
+//! The board is split into 8×8 tiles. After `STEPS` generations a tile's
+//! contents only depend on the 16×16 window around it, so the result for
+//! each distinct window is computed once and looked up afterwards. Boards
+//! made of repeated structure (and empty space, the most repeated
+//! structure of all) hit the cache almost every time.
+//!
+//! Unlike real HashLife this runs on the board's own bounded grid, so it
+//! respects the border mode: window cells that fall off an Empty or Solid
+//! board are pinned dead or alive for every step and recorded in the key,
+//! and on Loop boards the window simply wraps around.
+
+use std::collections::HashMap;
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+};
+
+const TILE: usize = 8;
+const STEPS: usize = 4;
+const WINDOW: usize = TILE + 2 * STEPS;
+/// Once the cache holds this many tiles it is cleared and refilled
+const CACHE_CAPACITY: usize = 1 << 16;
+
+// One u16 per window row, with bit x holding column x
+type Rows = [u16; WINDOW];
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct WindowKey {
+    cells: Rows,
+    // Window cells that are off the board, and pinned for every step
+    pinned: Rows,
+}
+
+impl WindowKey {
+    fn build(board: &Board, x0: i64, y0: i64) -> Self {
+        let mut key = WindowKey {
+            cells: [0; WINDOW],
+            pinned: [0; WINDOW],
+        };
+        let (w, h) = (board.width as i64, board.height as i64);
+
+        if x0 >= 0 && y0 >= 0 && x0 + WINDOW as i64 <= w && y0 + WINDOW as i64 <= h {
+            let (x0, y0, w) = (x0 as usize, y0 as usize, w as usize);
+            (0..WINDOW).for_each(|wy| {
+                let start = (y0 + wy) * w + x0;
+                key.cells[wy] = board.cells[start..start + WINDOW]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &c)| c == Cell::Alive)
+                    .fold(0, |acc, (wx, _)| acc | 1 << wx);
+            });
+            return key;
+        }
+
+        (0..WINDOW).for_each(|wy| {
+            let y = y0 + wy as i64;
+            (0..WINDOW).for_each(|wx| {
+                let x = x0 + wx as i64;
+                let on_board = (0..w).contains(&x) && (0..h).contains(&y);
+                let cell = if on_board {
+                    board.cells[(y * w + x) as usize]
+                } else {
+                    board.effective_neighbor(x, y)
+                };
+                if cell == Cell::Alive {
+                    key.cells[wy] |= 1 << wx;
+                }
+                if !on_board && board.border != BorderOpt::Loop {
+                    key.pinned[wy] |= 1 << wx;
+                }
+            })
+        });
+
+        key
+    }
+
+    // Run the window for STEPS generations and pack the center tile, row
+    // by row, into a u64. Each step the ring of cells without a full
+    // neighborhood grows by one, which is exactly why the window is
+    // STEPS cells wider than the tile on each side. Neighbors are
+    // counted for a whole row at once with bit-sliced half adders.
+    fn evolve(&self) -> u64 {
+        let mut rows = self.cells;
+
+        (0..STEPS).for_each(|_| {
+            let mut next = rows;
+            (1..WINDOW - 1).for_each(|y| {
+                let (above, middle, below) = (rows[y - 1], rows[y], rows[y + 1]);
+                let neighbors = [
+                    above << 1,
+                    above,
+                    above >> 1,
+                    middle << 1,
+                    middle >> 1,
+                    below << 1,
+                    below,
+                    below >> 1,
+                ];
+                let (mut ones, mut twos, mut fours) = (0u16, 0u16, 0u16);
+
+                neighbors.iter().for_each(|&n| {
+                    let carry_one = ones & n;
+                    ones ^= n;
+                    let carry_two = twos & carry_one;
+                    twos ^= carry_one;
+                    fours ^= carry_two;
+                });
+
+                let born_or_kept = (ones & twos & !fours) | (!ones & twos & !fours & middle);
+                next[y] = (born_or_kept & !self.pinned[y]) | (middle & self.pinned[y]);
+            });
+            rows = next;
+        });
+
+        (0..TILE).fold(0, |acc, ty| {
+            let row = (rows[STEPS + ty] >> STEPS) as u64 & 0xFF;
+            acc | row << (ty * TILE)
+        })
+    }
+}
+
+impl Board {
+    /// Advance board state by `cycles` cycles, memoizing the evolution
+    /// of repeated blocks
+    ///
+    /// Produces exactly the same result as
+    /// [`Board::advance_n_cycles`], but works four generations at a time
+    /// on 8×8 tiles and caches the outcome of every distinct tile
+    /// neighborhood it sees. Boards with lots of empty space or repeating
+    /// structure, like glider guns and their streams, mostly hit the
+    /// cache. The cache lives for the duration of the call and is bounded
+    /// in size.
+    ///
+    /// Only the standard Conway rule with a neighbor radius of 1, no rule
+    /// map, no frozen cells and history turned off is memoized; otherwise
+    /// this falls back to stepping normally, so every generation is
+    /// recorded.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+    /// let mut expected = board.clone();
+    ///
+    /// board.advance_hashlife(30);
+    /// expected.advance_n_cycles(30);
+    /// assert_eq!(board, expected);
+    /// ```
+    pub fn advance_hashlife(&mut self, cycles: usize) {
+        if self.neighbor_radius() != 1
+            || !self.rule().is_conway()
+            || self.rule_map.is_some()
+            || self.frozen.contains(&true)
+            || self.history_capacity() > 0
+        {
+            self.advance_n_cycles(cycles);
+            return;
+        }
+        let mut cache: HashMap<WindowKey, u64> = HashMap::new();
+        // The last generation is always stepped normally, so it leaves
+        // behind the changes `Board::last_diff` reports
+        let blocks = cycles.saturating_sub(1) / STEPS;
+
+        (0..blocks).for_each(|_| {
+            let mut cells = self.cells.clone();
+
+            (0..self.height).step_by(TILE).for_each(|ty| {
+                (0..self.width).step_by(TILE).for_each(|tx| {
+                    let offset = STEPS as i64;
+                    let key = WindowKey::build(self, tx as i64 - offset, ty as i64 - offset);
+                    if cache.len() >= CACHE_CAPACITY {
+                        cache.clear();
+                    }
+                    let tile = *cache.entry(key).or_insert_with(|| key.evolve());
+
+                    (0..TILE * TILE)
+                        .map(|i| (tx + i % TILE, ty + i / TILE, tile & (1 << i) != 0))
+                        .filter(|&(x, y, _)| x < self.width && y < self.height)
+                        .for_each(|(x, y, alive)| {
+                            cells[y * self.width + x] =
+                                if alive { Cell::Alive } else { Cell::Dead };
+                        });
+                })
+            });
+
+            self.cells = cells;
+            self.skip_generations(STEPS);
+        });
+        self.invalidate_frontier();
+
+        self.advance_n_cycles(cycles - blocks * STEPS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::Generation;
+
+    #[test]
+    fn hashlife_matches_naive_on_glider_gun() {
+        let gun = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+        let mut board = gun.clone();
+        let mut expected = gun;
+
+        board.advance_hashlife(303);
+        expected.advance_n_cycles(303);
+
+        assert_eq!(board, expected);
+    }
+
+    #[test]
+    fn hashlife_matches_naive_on_borders() {
+        [BorderOpt::Empty, BorderOpt::Solid, BorderOpt::Loop]
+            .iter()
+            .enumerate()
+            .for_each(|(seed, border)| {
+                // Not a multiple of the tile size, to cover partial tiles
+                let soup = Board::new_random(21, 13, border.clone(), 0.4, seed as u64);
+                let mut board = soup.clone();
+                let mut expected = soup;
+
+                board.advance_hashlife(41);
+                expected.advance_n_cycles(41);
+
+                assert_eq!(board, expected, "{:?}", border);
+            });
+    }
+
+    #[test]
+    fn hashlife_on_tiny_loop() {
+        let soup = Board::new_random(3, 2, BorderOpt::Loop, 0.5, 11);
+        let mut board = soup.clone();
+        let mut expected = soup;
+
+        board.advance_hashlife(12);
+        expected.advance_n_cycles(12);
+
+        assert_eq!(board, expected);
+    }
+
+    #[test]
+    fn hashlife_records_history() {
+        let mut board = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+        let initial = board.clone();
+
+        board.set_history_capacity(100);
+        board.advance_hashlife(20);
+        assert_eq!(board.history_len(), 20);
+        assert_eq!(board.step_back_n(100), 20);
+        assert_eq!(board.generation(), Generation(0));
+        assert_eq!(board, initial);
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::Path,
+};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+};
+
+const CELLS_PER_DIGIT: usize = 4;
+
+impl Board {
+    /// Initialize new board from the hex-encoded file at `path`.
+    ///
+    /// # File format:
+    ///
+    /// The first line holds the board width as a decimal number. Every
+    /// following line is one row, written as hex digits (either case)
+    /// that each encode 4 cells. The most significant bit of a digit is
+    /// the leftmost of its cells and a set bit is alive, so `8` is
+    /// `#___` and `3` is `__##`.
+    ///
+    /// When the width isn't a multiple of 4 the last digit of each row is
+    /// padded with dead (zero) bits on the right, e.g. a 6-wide row of
+    /// `##_#_#` is written `D4`. Each row holds exactly `ceil(width / 4)`
+    /// digits.
+    ///
+    /// # Errors:
+    ///
+    /// - If the file can't be read
+    /// - If the width line is missing or not a number
+    /// - If a row has the wrong number of digits or a non-hex character
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let path = std::env::temp_dir().join("yagoll-hex-doc.txt");
+    /// std::fs::write(&path, "5\n00\n20\n10\n70\n00\n").unwrap();
+    ///
+    /// let board = Board::from_hex_file(&path, BorderOpt::Empty).unwrap();
+    /// assert_eq!(board, Board::new_from_file("./tests/test-boards/glider.txt"));
+    /// ```
+    pub fn from_hex_file<P: AsRef<Path>>(path: P, border: BorderOpt) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines().map(str::trim);
+        let width: usize = lines
+            .next()
+            .and_then(|l| l.parse().ok())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "missing or invalid width line"))?;
+        let digits_per_row = width.div_ceil(CELLS_PER_DIGIT);
+
+        let rows = lines
+            .filter(|l| !l.is_empty())
+            .enumerate()
+            .map(|(i, l)| {
+                if l.len() != digits_per_row {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "row {} has {} digits, expected {}",
+                            i,
+                            l.len(),
+                            digits_per_row
+                        ),
+                    ));
+                }
+                Self::parse_hex_row(l, width).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("row {} is not valid hex", i),
+                    )
+                })
+            })
+            .collect::<io::Result<Vec<Vec<Cell>>>>()?;
+
+        let mut board = Board::new(width, rows.len(), border);
+        rows.iter().enumerate().for_each(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .for_each(|(x, &c)| board.set(x, y, c));
+        });
+
+        Ok(board)
+    }
+
+    /// Write the board to the file at `path` in the hex format read by
+    /// [`Board::from_hex_file`]
+    ///
+    /// The border option isn't stored and has to be supplied again when
+    /// loading.
+    pub fn to_hex_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = format!("{}\n", self.width);
+
+        (0..self.height).for_each(|y| {
+            (0..self.width).step_by(CELLS_PER_DIGIT).for_each(|x0| {
+                let nibble = (0..CELLS_PER_DIGIT)
+                    .filter(|i| x0 + i < self.width && self.get(x0 + i, y) == Cell::Alive)
+                    .fold(0, |acc, i| acc | (0b1000 >> i));
+                out.push_str(&format!("{:X}", nibble));
+            });
+            out.push('\n');
+        });
+
+        fs::write(path, out)
+    }
+}
+
+impl Board {
+    fn parse_hex_row(row: &str, width: usize) -> Option<Vec<Cell>> {
+        let mut cells = Vec::with_capacity(width);
+
+        for c in row.chars() {
+            let nibble = c.to_digit(16)?;
+            (0..CELLS_PER_DIGIT).for_each(|i| {
+                cells.push(if nibble & (0b1000 >> i) != 0 {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                })
+            });
+        }
+        cells.truncate(width);
+
+        Some(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn hex_round_trip() {
+        let path = std::env::temp_dir().join("yagoll-hex-round-trip.txt");
+        let mut board = Board::new(12, 3, BorderOpt::Empty);
+
+        board.set(0, 0, Cell::Alive);
+        board.set(5, 1, Cell::Alive);
+        board.set(6, 1, Cell::Alive);
+        board.set(11, 2, Cell::Alive);
+
+        board.to_hex_file(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "12\n800\n060\n001\n"
+        );
+        assert_eq!(
+            Board::from_hex_file(&path, BorderOpt::Empty).unwrap(),
+            board
+        );
+    }
+
+    #[test]
+    fn hex_padding() {
+        let path = std::env::temp_dir().join("yagoll-hex-padding.txt");
+        let mut board = Board::new(6, 1, BorderOpt::Empty);
+
+        [0, 1, 3, 5]
+            .iter()
+            .for_each(|&x| board.set(x, 0, Cell::Alive));
+
+        board.to_hex_file(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "6\nD4\n");
+        assert_eq!(
+            Board::from_hex_file(&path, BorderOpt::Empty).unwrap(),
+            board
+        );
+    }
+
+    #[test]
+    fn hex_bad_row() {
+        let path = std::env::temp_dir().join("yagoll-hex-bad-row.txt");
+        std::fs::write(&path, "8\nFF\nF\n").unwrap();
+
+        assert!(Board::from_hex_file(&path, BorderOpt::Empty).is_err());
+    }
+}
@@ -0,0 +1,408 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug, Formatter},
+    mem,
+};
+
+use crate::{
+    board::{Board, Generation},
+    cell::Cell,
+    error::ShapeMismatch,
+};
+
+// Earlier generations of a board, newest last, kept for stepping back
+#[derive(Clone, Default)]
+pub(crate) struct History {
+    capacity: usize,
+    states: VecDeque<Vec<Cell>>,
+}
+
+impl History {
+    // Remember `cells` as the generation before the one about to be
+    // computed, dropping the oldest generation when full
+    pub(crate) fn record(&mut self, cells: &[Cell]) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.states.len() == self.capacity {
+            self.states.pop_front();
+        }
+        self.states.push_back(cells.to_vec());
+    }
+}
+
+impl Debug for History {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "History {{ {} of {} generations }}",
+            self.states.len(),
+            self.capacity
+        )
+    }
+}
+
+/// A saved copy of a board's cells and generation, see
+/// [`Board::checkpoint`]
+///
+/// Cells are packed eight to a byte, so keeping many snapshots around is
+/// cheap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoardSnapshot {
+    width: usize,
+    height: usize,
+    generation: Generation,
+    packed: Vec<u8>,
+}
+
+impl BoardSnapshot {
+    /// The width of the board the snapshot was taken from
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the board the snapshot was taken from
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The generation the board was at
+    pub fn generation(&self) -> Generation {
+        self.generation
+    }
+}
+
+impl Board {
+    /// Save the board's cells and generation, to go back to later with
+    /// [`Board::restore`]
+    ///
+    /// Unlike the history, checkpoints are taken by hand, kept by the
+    /// caller and can be returned to any number of times.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let start = board.checkpoint();
+    ///
+    /// board.advance_n_cycles(3);
+    /// board.restore(&start).unwrap();
+    /// assert_eq!(board, Board::new_from_file("./tests/test-boards/glider.txt"));
+    /// assert_eq!(board.generation(), Generation(0));
+    /// ```
+    pub fn checkpoint(&self) -> BoardSnapshot {
+        BoardSnapshot {
+            width: self.width,
+            height: self.height,
+            generation: self.generation(),
+            packed: self
+                .cells
+                .chunks(8)
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, &c)| c == Cell::Alive)
+                        .fold(0, |acc, (i, _)| acc | (0x80 >> i))
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the board's cells and generation to what they were when
+    /// `snapshot` was taken
+    ///
+    /// Everything else, like the border, rule and history, is left as
+    /// it is.
+    ///
+    /// # Errors:
+    ///
+    /// If the snapshot was taken from a board of different dimensions,
+    /// leaving the board untouched
+    pub fn restore(&mut self, snapshot: &BoardSnapshot) -> Result<(), ShapeMismatch> {
+        if (snapshot.width, snapshot.height) != (self.width, self.height) {
+            return Err(ShapeMismatch {
+                expected: (self.width, self.height),
+                found: (snapshot.width, snapshot.height),
+            });
+        }
+
+        self.cells = (0..self.cells.len())
+            .map(|i| {
+                if snapshot.packed[i / 8] & (0x80 >> (i % 8)) != 0 {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+        self.invalidate_frontier();
+        let (now, then) = (self.generation().0, snapshot.generation.0);
+        if then < now {
+            self.rewind_generation(now - then);
+        } else {
+            self.skip_generations(then - now);
+        }
+
+        Ok(())
+    }
+
+    /// A digest of the next `cycles` generations, for caching or
+    /// deduplicating whole runs
+    ///
+    /// Folds the [`Board::grid_hash`] of the board and of each generation
+    /// it advances through into one value, then puts the board back as
+    /// it was with a [`Board::checkpoint`]. The history and
+    /// [`Board::last_diff`] aren't touched.
+    /// Equal boards give equal digests, but like the hash, the digest is
+    /// only stable within one build of the program.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut a = Board::new_random(8, 8, BorderOpt::Loop, 0.4, 2);
+    /// let mut b = a.clone();
+    ///
+    /// assert_eq!(a.run_digest(10), b.run_digest(10));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn run_digest(&mut self, cycles: usize) -> u64 {
+        let start = self.checkpoint();
+        let history = mem::take(&mut self.history);
+        let changes = mem::take(&mut self.changes);
+
+        let digest = (0..cycles).fold(self.grid_hash(), |digest, _| {
+            self.advance_cycle();
+            digest.rotate_left(5) ^ self.grid_hash()
+        });
+
+        self.history = history;
+        self.restore(&start)
+            .expect("the board can't change size while advancing");
+        self.changes = changes;
+
+        digest
+    }
+
+    /// Keep up to `capacity` past generations around so they can be
+    /// returned to with [`Board::step_back`]
+    ///
+    /// History is off (a capacity of 0) by default. Every way of
+    /// advancing the board records the generation it replaces; edits
+    /// with [`Board::set`] and friends are not recorded. Lowering the
+    /// capacity forgets the oldest generations.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_random(8, 8, BorderOpt::Empty, 0.5, 3);
+    /// let initial = board.clone();
+    ///
+    /// board.set_history_capacity(10);
+    /// board.advance_cycle();
+    /// assert!(board.step_back());
+    /// assert_eq!(board, initial);
+    /// ```
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        let states = &mut self.history.states;
+        while states.len() > capacity {
+            states.pop_front();
+        }
+        self.history.capacity = capacity;
+    }
+
+    /// The most past generations kept around, 0 if history is off, see
+    /// [`Board::set_history_capacity`]
+    pub fn history_capacity(&self) -> usize {
+        self.history.capacity
+    }
+
+    /// The number of past generations that can currently be stepped back
+    /// to
+    pub fn history_len(&self) -> usize {
+        self.history.states.len()
+    }
+
+    /// Return to the previous generation, if there is one in the history
+    ///
+    /// Returns whether the board stepped back.
+    pub fn step_back(&mut self) -> bool {
+        self.step_back_n(1) == 1
+    }
+
+    /// Step back up to `n` generations, returning how many were undone
+    ///
+    /// Fewer than `n` are undone if the history doesn't go back that far.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_random(8, 8, BorderOpt::Empty, 0.5, 3);
+    ///
+    /// board.set_history_capacity(2);
+    /// board.advance_n_cycles(5);
+    /// assert_eq!(board.step_back_n(5), 2);
+    /// ```
+    pub fn step_back_n(&mut self, n: usize) -> usize {
+        let undone = n.min(self.history.states.len());
+        let len = self.history.states.len();
+        let target = self.history.states.drain(len - undone..).next();
+
+        if let Some(cells) = target {
+            // A board resized since the generation was recorded can't go
+            // back to it
+            if cells.len() == self.cells.len() {
+                self.cells = cells;
+                self.invalidate_frontier();
+                self.rewind_generation(undone);
+            } else {
+                self.history.states.clear();
+                return 0;
+            }
+        }
+
+        undone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::board::BorderOpt;
+
+    use super::*;
+
+    // ░░░░░░░░░░
+    // ░░░░▓▓░░░░
+    // ░░░░▓▓░░░░
+    // ░░░░▓▓░░░░
+    // ░░░░░░░░░░
+    fn get_blinker_board() -> Board {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        (1..4).for_each(|y| board.set(2, y, Cell::Alive));
+        board
+    }
+
+    #[test]
+    fn blinker_steps_back() {
+        let mut board = get_blinker_board();
+        let vertical = board.clone();
+        let horizontal = board.clone().advanced();
+
+        board.set_history_capacity(10);
+        board.advance_n_cycles(5);
+        assert_eq!(board, horizontal);
+
+        assert_eq!(board.step_back_n(3), 3);
+        assert_eq!(board.history_len(), 2);
+        // Two generations in
+        assert_eq!(board, vertical);
+
+        board.step_back();
+        assert_eq!(board, horizontal);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut board = get_blinker_board();
+        let initial = board.clone();
+
+        board.set_history_capacity(3);
+        board.advance_n_cycles(10);
+        assert_eq!(board.history_len(), 3);
+        assert_eq!(board.step_back_n(10), 3);
+        assert!(!board.step_back());
+
+        board.set_history_capacity(0);
+        board.advance_cycle();
+        assert_eq!(board.history_len(), 0);
+        // Back at generation 7, then one more
+        assert_eq!(board, initial.advanced_n(8));
+    }
+
+    #[test]
+    fn restore_checkpoint() {
+        let mut board = Board::new_random(13, 7, BorderOpt::Loop, 0.4, 8);
+        board.advance_n_cycles(2);
+        let initial = board.clone();
+        let checkpoint = board.checkpoint();
+        assert_eq!(checkpoint.generation(), Generation(2));
+        assert_eq!(checkpoint.packed.len(), 12);
+
+        board.advance_n_cycles(10);
+        assert_ne!(board, initial);
+        board.restore(&checkpoint).unwrap();
+        assert_eq!(board, initial);
+        assert_eq!(board.generation(), Generation(2));
+
+        // The same checkpoint works again, going forward too
+        let mut fresh = Board::new(13, 7, BorderOpt::Loop);
+        fresh.restore(&checkpoint).unwrap();
+        assert_eq!(fresh, initial);
+        assert_eq!(fresh.generation(), Generation(2));
+        assert_eq!(fresh.advanced_n(3), initial.advanced_n(3));
+    }
+
+    #[test]
+    fn run_digest_is_deterministic() {
+        let mut a = Board::new_random(12, 9, BorderOpt::Loop, 0.4, 6);
+        let mut b = Board::new_random(12, 9, BorderOpt::Loop, 0.4, 6);
+        a.set_history_capacity(3);
+        a.advance_cycle();
+        b.advance_cycle();
+        let before = a.clone();
+
+        assert_eq!(a.run_digest(20), b.run_digest(20));
+        assert_eq!(a, before);
+        assert_eq!(a.history_len(), 1);
+        assert_eq!(a.generation(), Generation(1));
+
+        assert_ne!(a.run_digest(20), a.run_digest(21));
+        assert_ne!(a.run_digest(20), get_blinker_board().run_digest(20));
+        assert_eq!(a.run_digest(0), a.grid_hash());
+
+        let mut blinker = get_blinker_board();
+        blinker.advance_cycle();
+        let diff = blinker.last_diff().to_vec();
+        assert_eq!(diff.len(), 4);
+        blinker.run_digest(3);
+        assert_eq!(blinker.last_diff(), &diff[..]);
+    }
+
+    #[test]
+    fn restore_needs_same_dimensions() {
+        let mut board = get_blinker_board();
+        let err = board
+            .restore(&Board::new(4, 5, BorderOpt::Empty).checkpoint())
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "dimension mismatch: self is 5x5, other is 4x5"
+        );
+        assert_eq!(board, get_blinker_board());
+    }
+
+    #[test]
+    fn every_stepper_records() {
+        let mut board = get_blinker_board();
+        let initial = board.clone();
+
+        board.set_history_capacity(10);
+        board.advance_cycle_with(|_, n| if n == 1 { Cell::Alive } else { Cell::Dead });
+        board.advance_region(0, 0, 2, 2);
+        board.advance_hashlife(5);
+        assert_eq!(board.history_len(), 7);
+
+        board.step_back_n(7);
+        assert_eq!(board, initial);
+    }
+}
@@ -0,0 +1,198 @@
+use std::{io::Cursor, path::Path};
+
+use image::{ImageFormat, ImageResult, RgbaImage};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    encode::base64_encode_standard,
+};
+
+impl Board {
+    /// Initialize new board from the image at `path`, one cell per pixel
+    ///
+    /// The image is converted to grayscale and every pixel darker than
+    /// `threshold` (`0` is black, `255` white) becomes a live cell, so
+    /// black-on-white logos and drawings turn straight into seeds.
+    ///
+    /// Only available with the `image` feature.
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be read or isn't a supported image.
+    pub fn from_png<P: AsRef<Path>>(
+        path: P,
+        border: BorderOpt,
+        threshold: u8,
+    ) -> ImageResult<Self> {
+        let pixels = image::open(path)?.into_luma8();
+        let (width, height) = (pixels.width() as usize, pixels.height() as usize);
+        let cells = pixels
+            .pixels()
+            .map(|p| {
+                if p.0[0] < threshold {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        Ok(Board::from_cells(width, height, border, cells))
+    }
+
+    /// Initialize new board from the image at `path`, one cell per pixel,
+    /// dithered so that shades of gray become denser or sparser live
+    /// cells
+    ///
+    /// The image is converted to grayscale and run through Floyd–Steinberg
+    /// dithering: each pixel becomes a live cell if it's darker than
+    /// middle gray, and the difference between its shade and black or
+    /// white is carried over to the pixels right of and below it. Where
+    /// [`Board::from_png`] turns a photo into flat blobs, this keeps its
+    /// shading, so the board looks like the picture at a glance.
+    ///
+    /// Only available with the `image` feature.
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be read or isn't a supported image.
+    pub fn from_grayscale_dithered<P: AsRef<Path>>(
+        path: P,
+        border: BorderOpt,
+    ) -> ImageResult<Self> {
+        let pixels = image::open(path)?.into_luma8();
+        let (width, height) = (pixels.width() as usize, pixels.height() as usize);
+        let mut shades: Vec<f32> = pixels.pixels().map(|p| f32::from(p.0[0])).collect();
+        let mut cells = Vec::with_capacity(shades.len());
+
+        for y in 0..height {
+            for x in 0..width {
+                let shade = shades[y * width + x];
+                let (cell, out) = if shade < 128.0 {
+                    (Cell::Alive, 0.0)
+                } else {
+                    (Cell::Dead, 255.0)
+                };
+                cells.push(cell);
+
+                let error = shade - out;
+                let mut spread = |dx: i64, dy: usize, weight: f32| {
+                    let nx = x as i64 + dx;
+                    if (0..width as i64).contains(&nx) && y + dy < height {
+                        shades[(y + dy) * width + nx as usize] += error * weight / 16.0;
+                    }
+                };
+                spread(1, 0, 7.0);
+                spread(-1, 1, 3.0);
+                spread(0, 1, 5.0);
+                spread(1, 1, 1.0);
+            }
+        }
+
+        Ok(Board::from_cells(width, height, border, cells))
+    }
+
+    /// Render the board to a PNG, black on white with each cell a
+    /// `scale`×`scale` square, and encode it as standard base64
+    ///
+    /// The result is ready to embed in HTML as
+    /// `<img src="data:image/png;base64,...">`.
+    ///
+    /// Only available with the `image` feature.
+    ///
+    /// # Panics:
+    ///
+    /// If `scale` is 0, or the image is too large to encode
+    pub fn to_png_base64(&self, scale: usize) -> String {
+        let (pixels, width, height) = self.to_rgba(scale, [0, 0, 0, 255], [255; 4]);
+        let image = RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .expect("pixel buffer matches the image size");
+        let mut png = Cursor::new(vec![]);
+        image
+            .write_to(&mut png, ImageFormat::Png)
+            .expect("encoding a PNG in memory can't fail");
+
+        base64_encode_standard(png.get_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use image::{GrayImage, Luma};
+
+    use super::*;
+
+    #[test]
+    fn png_base64_decodes() {
+        let board = Board::new_from_file("./tests/test-boards/glider.txt");
+        let encoded = board.to_png_base64(3);
+        assert!(encoded.len().is_multiple_of(4));
+
+        let url_safe = encoded
+            .trim_end_matches('=')
+            .replace('+', "-")
+            .replace('/', "_");
+        let png = crate::encode::base64_decode(&url_safe).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        // And it's the board again, scaled
+        let image = image::load_from_memory(&png).unwrap().into_luma8();
+        assert_eq!(image.dimensions(), (15, 15));
+        assert_eq!(image.get_pixel(6, 3).0[0], 0);
+        assert_eq!(image.get_pixel(0, 0).0[0], 255);
+    }
+
+    #[test]
+    fn dithered_gradient_darkens() {
+        let path = std::env::temp_dir().join("yagoll-gradient.png");
+        // White on the left to black on the right
+        let image = GrayImage::from_fn(64, 16, |x, _| Luma([255 - (x * 4) as u8]));
+        image.save(&path).unwrap();
+
+        let board = Board::from_grayscale_dithered(&path, BorderOpt::Empty).unwrap();
+
+        assert!(board.width == 64 && board.height == 16);
+        let bands: Vec<usize> = (0..4)
+            .map(|i| board.live_in_rect(i * 16, 0, 16, 16))
+            .collect();
+        assert!(bands.windows(2).all(|w| w[0] < w[1]), "{:?}", bands);
+        assert_eq!(board.live_in_rect(0, 0, 2, 16), 0);
+        assert_eq!(board.live_in_rect(62, 0, 2, 16), 32);
+        // Roughly half of the board, like the gradient's average shade
+        let live = board.live_in_rect(0, 0, 64, 16);
+        assert!((448..=576).contains(&live), "{}", live);
+    }
+
+    #[test]
+    fn checkerboard_png() {
+        let path = std::env::temp_dir().join("yagoll-checkerboard.png");
+        let image = GrayImage::from_fn(4, 3, |x, y| {
+            if (x + y) % 2 == 0 {
+                Luma([20])
+            } else {
+                Luma([230])
+            }
+        });
+        image.save(&path).unwrap();
+
+        let board = Board::from_png(&path, BorderOpt::Empty, 128).unwrap();
+
+        assert!(board.width == 4 && board.height == 3);
+        (0..3).for_each(|y| {
+            (0..4).for_each(|x| {
+                let expected = if (x + y) % 2 == 0 {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                };
+                assert_eq!(board.get(x, y), expected);
+            })
+        });
+
+        // Nothing is darker than a threshold of 0
+        let board = Board::from_png(&path, BorderOpt::Empty, 0).unwrap();
+        assert_eq!(board.bounding_box(), None);
+    }
+}
@@ -0,0 +1,345 @@
+//! Isotropic non-totalistic ("INT") rules
+//!
+//! Under a totalistic rule like Conway's only the number of live
+//! neighbors matters. INT rules look at their arrangement too, while
+//! still treating every rotation and reflection of an arrangement alike.
+
+use crate::{board::Board, cell::Cell, error::InvalidRule};
+
+type Offset = (i64, i64);
+
+// Neighbor offsets in bit order: NW, N, NE, W, E, SW, S, SE
+const OFFSETS: [Offset; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A representative arrangement for each supported Hensel letter, by
+// neighbor count. Counts 6 and 7 are the complements of 2 and 1.
+const HENSEL_LETTERS: [(usize, char, &[Offset]); 8] = [
+    (1, 'c', &[(-1, -1)]),
+    (1, 'e', &[(0, -1)]),
+    (2, 'c', &[(-1, -1), (1, -1)]),
+    (2, 'e', &[(0, -1), (1, 0)]),
+    (2, 'k', &[(0, -1), (-1, 1)]),
+    (2, 'a', &[(0, -1), (1, -1)]),
+    (2, 'i', &[(0, -1), (0, 1)]),
+    (2, 'n', &[(-1, -1), (1, 1)]),
+];
+
+fn mask_of(offsets: &[Offset]) -> u8 {
+    offsets
+        .iter()
+        .map(|o| OFFSETS.iter().position(|p| p == o).unwrap())
+        .fold(0, |acc, bit| acc | 1 << bit)
+}
+
+// Every rotation and reflection of the arrangement `mask`
+fn symmetries(mask: u8) -> Vec<u8> {
+    let transforms: [fn(Offset) -> Offset; 8] = [
+        |(x, y)| (x, y),
+        |(x, y)| (-x, y),
+        |(x, y)| (x, -y),
+        |(x, y)| (-x, -y),
+        |(x, y)| (y, x),
+        |(x, y)| (-y, x),
+        |(x, y)| (y, -x),
+        |(x, y)| (-y, -x),
+    ];
+    let offsets: Vec<Offset> = (0..8)
+        .filter(|bit| mask & 1 << bit != 0)
+        .map(|bit| OFFSETS[bit])
+        .collect();
+
+    transforms
+        .iter()
+        .map(|t| mask_of(&offsets.iter().map(|&o| t(o)).collect::<Vec<_>>()))
+        .collect()
+}
+
+/// The outcome of every possible neighbor arrangement, for birth and for
+/// survival
+#[derive(Clone, PartialEq, Eq)]
+pub struct RuleTable {
+    born: [bool; 256],
+    survive: [bool; 256],
+}
+
+impl RuleTable {
+    /// A table where nothing is ever born and nothing survives
+    pub fn new() -> Self {
+        RuleTable {
+            born: [false; 256],
+            survive: [false; 256],
+        }
+    }
+
+    /// Parse a rule in Hensel notation, such as `B3/S23` or `B2-a/S12e`
+    ///
+    /// A neighbor count may be followed by letters picking out specific
+    /// arrangements, or by `-` and letters to exclude them; a bare count
+    /// includes every arrangement. Letters are supported for counts 1, 2,
+    /// 6 and 7 (`c`, `e` and `c`, `e`, `k`, `a`, `i`, `n`); counts 0, 3,
+    /// 4, 5 and 8 can only be given as a whole.
+    ///
+    /// # Errors:
+    ///
+    /// If the rule isn't of the form `B.../S...` or uses an unsupported
+    /// count or letter.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let conway = RuleTable::parse("B3/S23").unwrap();
+    /// let mut board = Board::new_random(10, 10, BorderOpt::Empty, 0.4, 1);
+    /// let mut expected = board.clone();
+    ///
+    /// board.advance_cycle_int(&conway);
+    /// expected.advance_cycle();
+    /// assert_eq!(board, expected);
+    /// ```
+    pub fn parse(rule: &str) -> Result<Self, InvalidRule> {
+        let rule = rule.trim();
+        let (b, s) = rule
+            .split_once('/')
+            .ok_or_else(|| InvalidRule(format!("{:?} has no '/'", rule)))?;
+        let strip = |part: &str, prefix: char| {
+            part.strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .map(str::to_string)
+                .ok_or_else(|| InvalidRule(format!("{:?} doesn't start with {}", part, prefix)))
+        };
+        let mut table = RuleTable::new();
+
+        Self::parse_conditions(&strip(b, 'B')?, &mut table.born)?;
+        Self::parse_conditions(&strip(s, 'S')?, &mut table.survive)?;
+
+        Ok(table)
+    }
+
+    /// Set whether a cell is born (if `alive` is false) or survives (if
+    /// true) when its neighbors are arranged like `neighbors`, along with
+    /// every rotation and reflection of that arrangement
+    ///
+    /// `neighbors` lists live neighbors as offsets from the cell, e.g.
+    /// `(0, -1)` for the neighbor above.
+    ///
+    /// # Panics:
+    ///
+    /// If an offset isn't one of the eight neighbors.
+    pub fn set(&mut self, alive: bool, neighbors: &[(i64, i64)], outcome: bool) {
+        let table = if alive {
+            &mut self.survive
+        } else {
+            &mut self.born
+        };
+        if neighbors.iter().any(|o| !OFFSETS.contains(o)) {
+            panic!("{:?} contains an offset that isn't a neighbor", neighbors);
+        }
+        symmetries(mask_of(neighbors))
+            .into_iter()
+            .for_each(|m| table[m as usize] = outcome);
+    }
+
+    fn next(&self, cell: Cell, mask: u8) -> Cell {
+        let table = if cell == Cell::Alive {
+            &self.survive
+        } else {
+            &self.born
+        };
+        if table[mask as usize] {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+
+    fn parse_conditions(conditions: &str, table: &mut [bool; 256]) -> Result<(), InvalidRule> {
+        let mut chars = conditions.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let count = c
+                .to_digit(10)
+                .filter(|&d| d <= 8)
+                .ok_or_else(|| InvalidRule(format!("unexpected {:?}", c)))?
+                as usize;
+            let negate = chars.peek() == Some(&'-');
+            if negate {
+                chars.next();
+            }
+            let mut letters = vec![];
+            while let Some(&l) = chars.peek().filter(|l| l.is_ascii_alphabetic()) {
+                letters.push(l);
+                chars.next();
+            }
+
+            let masks: Vec<u8> = if letters.is_empty() {
+                (0..=255u8)
+                    .filter(|m| m.count_ones() as usize == count)
+                    .collect()
+            } else {
+                let all: Vec<(char, u8)> = Self::lettered(count)
+                    .ok_or_else(|| InvalidRule(format!("count {} takes no letters", count)))?;
+                if let Some(l) = letters.iter().find(|l| !all.iter().any(|(a, _)| a == *l)) {
+                    return Err(InvalidRule(format!("no arrangement {}{}", count, l)));
+                }
+                all.iter()
+                    .filter(|(l, _)| letters.contains(l) != negate)
+                    .flat_map(|&(_, m)| symmetries(m))
+                    .collect()
+            };
+            masks.into_iter().for_each(|m| table[m as usize] = true);
+        }
+
+        Ok(())
+    }
+
+    // The lettered arrangements for `count`, if it has any supported ones
+    fn lettered(count: usize) -> Option<Vec<(char, u8)>> {
+        let (base, complement) = match count {
+            1 | 2 => (count, false),
+            6 | 7 => (8 - count, true),
+            _ => return None,
+        };
+
+        Some(
+            HENSEL_LETTERS
+                .iter()
+                .filter(|(n, _, _)| *n == base)
+                .map(|&(_, l, offsets)| {
+                    let mask = mask_of(offsets);
+                    (l, if complement { !mask } else { mask })
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Default for RuleTable {
+    fn default() -> Self {
+        RuleTable::new()
+    }
+}
+
+impl std::fmt::Debug for RuleTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let count = |t: &[bool; 256]| t.iter().filter(|&&b| b).count();
+        write!(
+            f,
+            "RuleTable {{ born: {} arrangements, survive: {} arrangements }}",
+            count(&self.born),
+            count(&self.survive)
+        )
+    }
+}
+
+impl Board {
+    /// Advance board state by one cycle using an isotropic
+    /// non-totalistic rule
+    ///
+    /// Each cell looks up the arrangement of its eight neighbors in
+    /// `table`. The board's border mode applies as usual; the neighbor
+    /// radius is always 1.
+    pub fn advance_cycle_int(&mut self, table: &RuleTable) {
+        self.begin_generation();
+        let updates: Vec<(usize, usize, Cell)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter_map(|(x, y)| {
+                let mask = OFFSETS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &(dx, dy))| {
+                        self.effective_neighbor(x as i64 + dx, y as i64 + dy) == Cell::Alive
+                    })
+                    .fold(0u8, |acc, (bit, _)| acc | 1 << bit);
+                let cell = self.get(x, y);
+                let next = table.next(cell, mask);
+                if next != cell {
+                    Some((x, y, next))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        self.apply_updates(&updates);
+        self.invalidate_frontier();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    #[test]
+    fn letter_classes_partition_counts() {
+        [1usize, 2, 6, 7].iter().for_each(|&count| {
+            let mut seen: Vec<u8> = RuleTable::lettered(count)
+                .unwrap()
+                .iter()
+                .flat_map(|&(_, m)| symmetries(m))
+                .collect();
+            seen.sort();
+            seen.dedup();
+            let all = (0..=255u8)
+                .filter(|m| m.count_ones() as usize == count)
+                .count();
+            assert_eq!(seen.len(), all, "count {}", count);
+        });
+    }
+
+    #[test]
+    fn hand_worked_2a_birth() {
+        // ░░▓▓▓▓
+        // ░░░░░░
+        // ░░░░░░
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.set(1, 0, Cell::Alive);
+        board.set(2, 0, Cell::Alive);
+
+        // (1, 1) sees N and NE, (2, 1) sees NW and N: both are 2a.
+        // (0, 0) and (0, 1) see a single neighbor.
+        let mut a = board.clone();
+        a.advance_cycle_int(&RuleTable::parse("B2a/S").unwrap());
+        assert_eq!(
+            format!("{}", a),
+            "░░░░░░\n\
+             ░░▓▓▓▓\n\
+             ░░░░░░\n"
+        );
+
+        let mut not_a = board.clone();
+        not_a.advance_cycle_int(&RuleTable::parse("B2-a/S").unwrap());
+        assert_eq!(not_a.bounding_box(), None);
+    }
+
+    #[test]
+    fn set_matches_parse() {
+        let mut table = RuleTable::new();
+        table.set(false, &[(0, -1), (1, -1)], true);
+
+        assert_eq!(table, RuleTable::parse("b2a/s").unwrap());
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(RuleTable::parse("B3S23").is_err());
+        assert!(RuleTable::parse("B3x/S23").is_err());
+        assert!(RuleTable::parse("B3a/S23").is_err());
+        assert!(RuleTable::parse("B9/S23").is_err());
+        assert_eq!(
+            RuleTable::parse("B2z/S").unwrap_err().to_string(),
+            "invalid rule: no arrangement 2z"
+        );
+    }
+}
@@ -1,5 +1,58 @@
+mod bitboard;
 mod board;
 mod cell;
+mod combine;
+mod components;
+mod delimited;
+mod encode;
+mod error;
+mod explain;
+mod fixed;
+mod font;
+mod freeze;
+mod hashlife;
+mod hex;
+mod history;
+#[cfg(feature = "image")]
+mod image_io;
+mod int_rule;
+mod observe;
+mod pattern;
+mod playback;
+mod region;
+mod render;
+mod resize;
+mod rle;
+mod rng;
+mod rule;
+mod search;
+mod sparse;
+mod stats;
+mod svg;
+mod symmetry;
+mod update_mode;
+#[cfg(any(feature = "wasm", test))]
+mod wasm;
 
 pub use crate::board::*;
 pub use crate::cell::*;
+pub use crate::components::*;
+pub use crate::error::*;
+pub use crate::explain::*;
+pub use crate::fixed::*;
+pub use crate::history::BoardSnapshot;
+pub use crate::int_rule::*;
+pub use crate::observe::StepObserver;
+pub use crate::pattern::*;
+pub use crate::region::Spaceship;
+pub use crate::render::*;
+pub use crate::resize::DownscaleMode;
+pub use crate::rle::RLE_MAX_CELLS;
+pub use crate::rng::SplitMix64;
+pub use crate::rule::*;
+pub use crate::search::*;
+pub use crate::sparse::*;
+pub use crate::symmetry::*;
+pub use crate::update_mode::*;
+#[cfg(feature = "wasm")]
+pub use crate::wasm::WasmBoard;
@@ -0,0 +1,132 @@
+use std::ops::ControlFlow;
+
+use crate::{board::Board, cell::Cell};
+
+/// Something that watches a board advance, one generation at a time,
+/// like a logger, a metrics collector or a frame grabber
+///
+/// Closures taking the same arguments are observers too.
+pub trait StepObserver {
+    /// Called after each generation with the board's new generation
+    /// number, the board itself and the cells that changed, as in
+    /// [`Board::last_diff`]
+    ///
+    /// Return `ControlFlow::Break(())` to stop the run early.
+    fn on_step(
+        &mut self,
+        gen: usize,
+        board: &Board,
+        diff: &[(usize, usize, Cell)],
+    ) -> ControlFlow<()>;
+}
+
+impl<F> StepObserver for F
+where
+    F: FnMut(usize, &Board, &[(usize, usize, Cell)]) -> ControlFlow<()>,
+{
+    fn on_step(
+        &mut self,
+        gen: usize,
+        board: &Board,
+        diff: &[(usize, usize, Cell)],
+    ) -> ControlFlow<()> {
+        self(gen, board, diff)
+    }
+}
+
+impl Board {
+    /// Advance the board up to `cycles` times, showing `observer` every
+    /// generation, and return how many cycles were run
+    ///
+    /// The run stops early as soon as the observer breaks.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut changes = vec![];
+    /// let mut log = |gen: usize, _: &Board, diff: &[(usize, usize, Cell)]| {
+    ///     changes.push((gen, diff.len()));
+    ///     ControlFlow::Continue(())
+    /// };
+    ///
+    /// assert_eq!(board.run_observed(2, &mut log), 2);
+    /// assert_eq!(changes.len(), 2);
+    /// assert_eq!(changes[0].0, 1);
+    /// ```
+    pub fn run_observed(&mut self, cycles: usize, observer: &mut dyn StepObserver) -> usize {
+        for cycle in 1..=cycles {
+            self.advance_cycle();
+            let gen = self.generation().0;
+            if observer.on_step(gen, self, self.last_diff()).is_break() {
+                return cycle;
+            }
+        }
+
+        cycles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    // Records the population of every generation, asking to stop once
+    // everything has died
+    #[derive(Default)]
+    struct PopulationLog(Vec<usize>);
+
+    impl StepObserver for PopulationLog {
+        fn on_step(
+            &mut self,
+            _gen: usize,
+            board: &Board,
+            _diff: &[(usize, usize, Cell)],
+        ) -> ControlFlow<()> {
+            let population = board.population();
+            self.0.push(population);
+            if population == 0 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    #[test]
+    fn stops_when_everything_dies() {
+        // A diagonal of three shrinks to its middle cell, then dies out
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        (1..4).for_each(|i| board.set(i, i, Cell::Alive));
+
+        let mut log = PopulationLog::default();
+        let ran = board.run_observed(100, &mut log);
+
+        assert_eq!(log.0, vec![1, 0]);
+        assert_eq!(ran, 2);
+        assert_eq!(board.generation().0, ran);
+    }
+
+    #[test]
+    fn runs_every_cycle_without_a_break() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+
+        let mut log = PopulationLog::default();
+        assert_eq!(board.run_observed(6, &mut log), 6);
+        assert_eq!(log.0, vec![3; 6]);
+
+        let mut gens = vec![];
+        board.run_observed(2, &mut |gen, _: &Board, diff: &[(usize, usize, Cell)]| {
+            assert_eq!(diff.len(), 4);
+            gens.push(gen);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(gens, vec![7, 8]);
+    }
+}
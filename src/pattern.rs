@@ -0,0 +1,309 @@
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::ParseError,
+    symmetry::TRANSFORMS,
+};
+
+// The live cells of a glider heading south-east
+const GLIDER: [(usize, usize); 5] = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+
+/// A pattern cell, which unlike a board cell can also leave whatever is
+/// underneath it alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternCell {
+    /// Stamping makes the cell alive
+    Alive,
+    /// Stamping makes the cell dead
+    Dead,
+    /// Stamping leaves the cell as it was
+    Transparent,
+}
+
+/// One of the four diagonal directions a glider can travel in, see
+/// [`Board::inject_glider`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Up and to the right
+    NorthEast,
+    /// Up and to the left
+    NorthWest,
+    /// Down and to the right
+    SouthEast,
+    /// Down and to the left
+    SouthWest,
+}
+
+/// A rectangular pattern with transparent cells, for composing boards
+/// with [`Board::stamp_pattern`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// Width of the pattern
+    pub width: usize,
+    /// Height of the pattern
+    pub height: usize,
+    cells: Vec<PatternCell>,
+}
+
+impl Pattern {
+    /// Parse a pattern drawn in ASCII art, with `#` for alive, `.` for
+    /// dead and `transparent` for cells to leave alone
+    ///
+    /// This is the same format [`Board::to_ascii`] writes, plus the
+    /// transparent character. Whitespace around rows and blank lines are
+    /// ignored.
+    ///
+    /// # Errors:
+    ///
+    /// - If a row is longer or shorter than the first
+    /// - If a row contains any other character
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let pattern = Pattern::from_ascii_art_grid("#?\n.#\n", '?').unwrap();
+    /// assert_eq!(pattern.get(1, 0), PatternCell::Transparent);
+    /// assert_eq!(pattern.get(0, 1), PatternCell::Dead);
+    /// ```
+    pub fn from_ascii_art_grid(input: &str, transparent: char) -> Result<Self, ParseError> {
+        let mut cells: Vec<PatternCell> = vec![];
+        let (mut width, mut height) = (0, 0);
+
+        for (row, line) in input
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .enumerate()
+        {
+            let len = line.chars().count();
+            width = if row == 0 { len } else { width };
+            if len != width {
+                return Err(ParseError {
+                    row,
+                    message: format!("{} columns, expected {}", len, width),
+                });
+            }
+            for (column, c) in line.chars().enumerate() {
+                cells.push(match c {
+                    '#' => PatternCell::Alive,
+                    '.' => PatternCell::Dead,
+                    c if c == transparent => PatternCell::Transparent,
+                    _ => {
+                        return Err(ParseError {
+                            row,
+                            message: format!("invalid character {:?} in column {}", c, column),
+                        })
+                    }
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Pattern {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Get the pattern cell at `(x, y)`
+    ///
+    /// # Panics:
+    ///
+    /// If `(x, y)` is outside the pattern
+    pub fn get(&self, x: usize, y: usize) -> PatternCell {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the pattern",
+            x,
+            y
+        );
+        self.cells[y * self.width + x]
+    }
+}
+
+impl From<&Board> for Pattern {
+    /// A fully opaque pattern with the board's cells
+    fn from(board: &Board) -> Self {
+        Pattern {
+            width: board.width,
+            height: board.height,
+            cells: board
+                .cells
+                .iter()
+                .map(|&c| match c {
+                    Cell::Alive => PatternCell::Alive,
+                    Cell::Dead => PatternCell::Dead,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Board {
+    /// Stamp a glider heading in `direction` onto the board, with the
+    /// top-left corner of its 3×3 box at `x` and `y`
+    ///
+    /// The glider is the one in its phase pointing along `direction`,
+    /// e.g. for [`Direction::SouthEast`]:
+    ///
+    /// ```text
+    /// .#.
+    /// ..#
+    /// ###
+    /// ```
+    ///
+    /// Like [`Board::stamp`], existing live cells are kept and anything
+    /// falling off the board is clipped. Calling this every few
+    /// generations makes a glider stream, e.g. for logic gate demos.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(10, 10, BorderOpt::Empty);
+    /// board.inject_glider(6, 6, Direction::NorthWest);
+    /// assert_eq!(board.bounding_box(), Some((6, 6, 3, 3)));
+    ///
+    /// board.advance_n_cycles(4);
+    /// assert_eq!(board.bounding_box(), Some((5, 5, 3, 3)));
+    /// ```
+    pub fn inject_glider(&mut self, x: usize, y: usize, direction: Direction) {
+        // Mirrored left to right, top to bottom, or both
+        let transform = match direction {
+            Direction::SouthEast => TRANSFORMS[0],
+            Direction::SouthWest => TRANSFORMS[1],
+            Direction::NorthEast => TRANSFORMS[2],
+            Direction::NorthWest => TRANSFORMS[3],
+        };
+        let mut glider = Board::new(3, 3, BorderOpt::Empty);
+        GLIDER
+            .iter()
+            .for_each(|&(gx, gy)| glider.set(gx, gy, Cell::Alive));
+
+        self.stamp(&glider.transformed(transform), x, y);
+    }
+
+    /// Stamp `pattern` onto the board with its top left corner at
+    /// `(x, y)`, overwriting only the pattern's non-transparent cells
+    ///
+    /// Unlike [`Board::stamp`], dead pattern cells do clear what's
+    /// underneath. Cells falling outside the board are clipped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 1, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 3, 1, Cell::Alive);
+    ///
+    /// let pattern = Pattern::from_ascii_art_grid(".?.", '?').unwrap();
+    /// board.stamp_pattern(&pattern, 0, 0);
+    /// assert_eq!(board.to_ascii(), ".#.\n");
+    /// ```
+    pub fn stamp_pattern(&mut self, pattern: &Pattern, x: usize, y: usize) {
+        let (width, height) = (self.width, self.height);
+
+        pattern
+            .cells
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &c)| {
+                Some((
+                    x.checked_add(i % pattern.width)?,
+                    y.checked_add(i / pattern.width)?,
+                    c,
+                ))
+            })
+            .filter(|&(px, py, _)| px < width && py < height)
+            .for_each(|(px, py, c)| match c {
+                PatternCell::Alive => self.set(px, py, Cell::Alive),
+                PatternCell::Dead => self.set(px, py, Cell::Dead),
+                PatternCell::Transparent => {}
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    #[test]
+    fn transparent_cells_preserve_board() {
+        let mut board = Board::new(4, 4, BorderOpt::Empty);
+        board.fill_rect(0, 0, 4, 4, Cell::Alive);
+        board.set(3, 3, Cell::Dead);
+
+        let pattern = Pattern::from_ascii_art_grid(
+            "
+            ?.?
+            .?.
+            ?.#
+            ",
+            '?',
+        )
+        .unwrap();
+        board.stamp_pattern(&pattern, 1, 1);
+
+        assert_eq!(board.to_ascii(), "####\n##.#\n#.#.\n##.#\n");
+
+        // Clipped, even where the coordinates themselves would overflow
+        board.stamp_pattern(&pattern, usize::MAX - 1, 0);
+        board.stamp_pattern(&pattern, 3, usize::MAX);
+        assert_eq!(board.to_ascii(), "####\n##.#\n#.#.\n##.#\n");
+    }
+
+    #[test]
+    fn injected_gliders_travel_diagonally() {
+        let mut board = Board::new(12, 12, BorderOpt::Empty);
+        board.inject_glider(2, 2, Direction::SouthEast);
+        assert_eq!(board.to_ascii().lines().nth(2), Some("...#........"));
+
+        board.advance_n_cycles(4);
+        assert_eq!(board.bounding_box(), Some((3, 3, 3, 3)));
+        let mut expected = Board::new(12, 12, BorderOpt::Empty);
+        expected.inject_glider(3, 3, Direction::SouthEast);
+        assert_eq!(board.to_ascii(), expected.to_ascii());
+
+        [
+            (Direction::NorthEast, (5, 3)),
+            (Direction::SouthWest, (3, 5)),
+            (Direction::NorthWest, (3, 3)),
+        ]
+        .iter()
+        .for_each(|&(direction, corner)| {
+            let mut board = Board::new(12, 12, BorderOpt::Empty);
+            board.inject_glider(4, 4, direction);
+            board.advance_n_cycles(4);
+            assert_eq!(board.bounding_box(), Some((corner.0, corner.1, 3, 3)));
+        });
+    }
+
+    #[test]
+    fn pattern_errors() {
+        let err = Pattern::from_ascii_art_grid("#.\n#\n", '?').unwrap_err();
+        assert_eq!(err.to_string(), "row 1: 1 columns, expected 2");
+
+        let err = Pattern::from_ascii_art_grid("#?x\n", '?').unwrap_err();
+        assert_eq!(err.to_string(), "row 0: invalid character 'x' in column 2");
+    }
+
+    #[test]
+    fn opaque_pattern_from_board() {
+        let mut board = Board::new(3, 2, BorderOpt::Empty);
+        board.set(2, 1, Cell::Alive);
+        let pattern = Pattern::from(&board);
+
+        let mut target = Board::new(3, 2, BorderOpt::Empty);
+        target.fill_rect(0, 0, 3, 2, Cell::Alive);
+        target.stamp_pattern(&pattern, 0, 0);
+
+        assert!(target.cells_eq(&board).unwrap());
+    }
+}
@@ -0,0 +1,326 @@
+#[cfg(feature = "parallel")]
+use std::mem;
+use std::{
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{board::Board, cell::Cell};
+
+impl Board {
+    /// Advance the board `cycles` times at `fps` generations per second,
+    /// handing every frame to `render`
+    ///
+    /// `render` sees the starting board and then the board after each
+    /// cycle, so it's called `cycles + 1` times. Frames are scheduled
+    /// against a fixed clock, so time spent advancing and rendering is
+    /// taken out of the wait instead of adding up; if a frame runs long,
+    /// the next one follows right away.
+    ///
+    /// # Panics:
+    ///
+    /// If `fps` isn't a positive, finite number
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut frames = 0;
+    ///
+    /// board.play(200.0, 3, |_| frames += 1);
+    /// assert_eq!(frames, 4);
+    /// ```
+    pub fn play<F: FnMut(&Board)>(&mut self, fps: f64, cycles: usize, mut render: F) {
+        if !(fps.is_finite() && fps > 0.0) {
+            panic!("fps must be positive and finite, got {}", fps);
+        }
+        let frame = Duration::from_secs_f64(1.0 / fps);
+        let start = Instant::now();
+
+        render(self);
+        (1..=cycles).for_each(|i| {
+            let deadline = start + frame.mul_f64(i as f64);
+            if let Some(wait) = deadline.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+            self.advance_cycle();
+            render(self);
+        });
+    }
+
+    /// Write the board to `w` under a header with its generation,
+    /// population and how many cells were born and died getting there
+    ///
+    /// The counts come from [`Board::last_diff`], so they describe the
+    /// latest cycle. Pass this to [`Board::play`] to watch a run in the
+    /// console.
+    ///
+    /// # Errors:
+    ///
+    /// If writing to `w` fails
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(1, 0, 1, 3, Cell::Alive);
+    /// board.advance_cycle();
+    /// let mut out = vec![];
+    ///
+    /// board.write_frame(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "generation 1 | population 3 | 2 born, 2 died\n░░░░░░\n▓▓▓▓▓▓\n░░░░░░\n"
+    /// );
+    /// ```
+    pub fn write_frame<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let population = self.population();
+        let born = self
+            .last_diff()
+            .iter()
+            .filter(|&&(_, _, c)| c == Cell::Alive)
+            .count();
+        let died = self.last_diff().len() - born;
+
+        writeln!(
+            w,
+            "{} | population {} | {} born, {} died",
+            self.generation(),
+            population,
+            born,
+            died
+        )?;
+        write!(w, "{}", self)
+    }
+
+    /// Advance the board `cycles` times, writing the starting board and
+    /// every generation after it to `w` with [`Board::write_frame`]
+    ///
+    /// # Errors:
+    ///
+    /// If writing to `w` fails, leaving the board at the generation it
+    /// was writing
+    pub fn print_run_to<W: Write>(&mut self, w: &mut W, cycles: usize) -> io::Result<()> {
+        self.write_frame(w)?;
+        (0..cycles).try_for_each(|_| {
+            self.advance_cycle();
+            self.write_frame(w)
+        })
+    }
+
+    /// Advance the board `cycles` times, writing a CSV row of metrics for
+    /// each new generation to `w`
+    ///
+    /// The columns are `generation,population,births,deaths,density`,
+    /// after a header row, where density is the fraction of cells alive.
+    /// Ready to load into a spreadsheet or plotting tool.
+    ///
+    /// # Errors:
+    ///
+    /// If writing to `w` fails, leaving the board at the generation it
+    /// was writing
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    /// let mut out = vec![];
+    ///
+    /// board.run_to_csv(1, &mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "generation,population,births,deaths,density\n1,4,0,0,0.25\n"
+    /// );
+    /// ```
+    pub fn run_to_csv<W: Write>(&mut self, cycles: usize, w: &mut W) -> io::Result<()> {
+        writeln!(w, "generation,population,births,deaths,density")?;
+        (0..cycles).try_for_each(|_| {
+            let (born, died) = self.advance_cycle_summary();
+            let population = self.population();
+            let density = if self.cells.is_empty() {
+                0.0
+            } else {
+                population as f64 / self.cells.len() as f64
+            };
+            writeln!(
+                w,
+                "{},{},{},{},{}",
+                self.generation().0,
+                population,
+                born,
+                died,
+                density
+            )
+        })
+    }
+
+    /// Advance the board `cycles` times, then hand the starting board and
+    /// every generation after it to `render`, spread over all cores
+    ///
+    /// Generations have to be computed one after another, but rendering
+    /// them, e.g. to strings or PNGs, doesn't. The frames are returned in
+    /// order, like calling `render` from [`Board::play`]. Each frame is a
+    /// copy of the board at that generation, without its history.
+    ///
+    /// Only available with the `parallel` feature.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let frames = board.render_frames_parallel(4, |frame| frame.to_ascii());
+    ///
+    /// assert_eq!(frames.len(), 5);
+    /// assert_eq!(frames[4], board.to_ascii());
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn render_frames_parallel<T, F>(&mut self, cycles: usize, render: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&Board) -> T + Sync,
+    {
+        let mut frames = Vec::with_capacity(cycles.saturating_add(1));
+        frames.push(self.frame());
+        (0..cycles).for_each(|_| {
+            self.advance_cycle();
+            frames.push(self.frame());
+        });
+
+        frames.par_iter().map(&render).collect()
+    }
+
+    // A copy of the board without the history, which would only be
+    // dead weight in every frame
+    #[cfg(feature = "parallel")]
+    fn frame(&mut self) -> Board {
+        let history = mem::take(&mut self.history);
+        let frame = self.clone();
+        self.history = history;
+
+        frame
+    }
+
+    /// Advance the board `cycles` times, printing every generation to
+    /// stdout, see [`Board::print_run_to`]
+    ///
+    /// # Panics:
+    ///
+    /// Like `println!`, if writing to stdout fails
+    pub fn print_run(&mut self, cycles: usize) {
+        let stdout = io::stdout();
+        self.print_run_to(&mut stdout.lock(), cycles)
+            .expect("failed printing to stdout");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    #[test]
+    fn play_renders_every_frame() {
+        let mut board = Board::new_random(10, 10, BorderOpt::Loop, 0.4, 2);
+        let expected = board.clone().advanced_n(5);
+        let mut rendered = vec![];
+        let start = Instant::now();
+
+        board.play(250.0, 5, |b| rendered.push(b.clone()));
+
+        assert_eq!(rendered.len(), 6);
+        assert_eq!(rendered[5], expected);
+        assert_eq!(board, expected);
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn print_run_headers() {
+        let mut board = Board::new(7, 7, BorderOpt::Empty);
+        board.fill_rect(1, 3, 3, 1, Cell::Alive);
+        board.set(6, 0, Cell::Alive);
+        let mut out = vec![];
+
+        board.print_run_to(&mut out, 2).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let headers: Vec<&str> = out
+            .lines()
+            .filter(|l| l.starts_with("generation"))
+            .collect();
+
+        assert_eq!(
+            headers,
+            vec![
+                "generation 0 | population 4 | 0 born, 0 died",
+                "generation 1 | population 3 | 2 born, 3 died",
+                "generation 2 | population 3 | 2 born, 2 died",
+            ]
+        );
+        assert_eq!(out.lines().count(), 3 * 8);
+    }
+
+    #[test]
+    fn blinker_metrics_csv() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+        let mut out = vec![];
+
+        board.run_to_csv(4, &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "generation,population,births,deaths,density");
+        assert_eq!(lines[1], "1,3,2,2,0.12");
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[4], "4,3,2,2,0.12");
+        assert_eq!(board.generation().0, 4);
+
+        let mut out = vec![];
+        Board::new(0, 0, BorderOpt::Empty)
+            .run_to_csv(1, &mut out)
+            .unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with("\n1,0,0,0,0\n"));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_frames_match_sequential() {
+        let mut board = Board::new_random(24, 16, BorderOpt::Loop, 0.4, 12);
+        board.set_history_capacity(4);
+        let mut sequential = board.clone();
+        let render = |frame: &Board| {
+            let mut out = vec![];
+            frame.write_frame(&mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let frames = board.render_frames_parallel(30, render);
+        let mut expected = vec![render(&sequential)];
+        (0..30).for_each(|_| {
+            sequential.advance_cycle();
+            expected.push(render(&sequential));
+        });
+
+        assert_eq!(frames, expected);
+        assert_eq!(board, sequential);
+        assert_eq!(board.history_len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn play_rejects_zero_fps() {
+        Board::default().play(0.0, 1, |_| ());
+    }
+}
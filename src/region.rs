@@ -0,0 +1,1419 @@
+use std::collections::HashMap;
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::{DimensionsTooLarge, OutOfBounds},
+};
+
+/// A pattern that reappears with the same shape, moved by `dx` and `dy`
+/// cells, every `period` generations, see [`Board::classify_spaceship`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spaceship {
+    /// Generations until the shape repeats
+    pub period: usize,
+    /// How far the pattern moves right per period, negative for left
+    pub dx: i64,
+    /// How far the pattern moves down per period, negative for up
+    pub dy: i64,
+}
+
+impl Board {
+    /// The smallest rectangle containing every live cell, as
+    /// `(x, y, width, height)`, or `None` if no cell is alive
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.bounding_box(), Some((1, 1, 3, 3)));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(usize, usize, usize, usize)> {
+        let live = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.get(x, y) == Cell::Alive);
+
+        live.fold(None, |bbox, (x, y)| {
+            let (x0, y0, x1, y1) = bbox.unwrap_or((x, y, x, y));
+            Some((x0.min(x), y0.min(y), x1.max(x), y1.max(y)))
+        })
+        .map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0 + 1, y1 - y0 + 1))
+    }
+
+    /// Set every cell in the `w`×`h` rectangle whose top-left corner is
+    /// at `x` and `y` to `c`
+    ///
+    /// The rectangle is clipped to the board, so it may hang off the
+    /// edge.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(2, 2, 10, 10, Cell::Alive);
+    /// assert_eq!(board.live_in_rect(0, 0, 4, 4), 4);
+    /// ```
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, c: Cell) {
+        let x1 = x.saturating_add(w).min(self.width);
+        let y1 = y.saturating_add(h).min(self.height);
+
+        (y..y1).for_each(|cy| (x..x1).for_each(|cx| self.set(cx, cy, c)));
+    }
+
+    /// Kill every cell in the `w`×`h` rectangle whose top-left corner is
+    /// at `x` and `y`, see [`Board::fill_rect`]
+    pub fn clear_rect(&mut self, x: usize, y: usize, w: usize, h: usize) {
+        self.fill_rect(x, y, w, h, Cell::Dead);
+    }
+
+    /// Copy the live cells inside the `w`×`h` rectangle whose top-left
+    /// corner is at `x` and `y` into a new board just large enough to
+    /// hold them
+    ///
+    /// The rectangle is clipped to the board. The pattern is trimmed to
+    /// its bounding box and placed on an `Empty` board, ready to be
+    /// stamped elsewhere; a region without live cells gives a 0×0 board.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let glider = board.extract_pattern(0, 0, 5, 5);
+    /// assert_eq!((glider.width, glider.height), (3, 3));
+    /// assert_eq!(glider.get(1, 0), Cell::Alive);
+    /// ```
+    pub fn extract_pattern(&self, x: usize, y: usize, w: usize, h: usize) -> Board {
+        let x1 = x.saturating_add(w).min(self.width);
+        let y1 = y.saturating_add(h).min(self.height);
+        let mut region = Board::new(x1.saturating_sub(x), y1.saturating_sub(y), BorderOpt::Empty);
+        (y..y1)
+            .flat_map(|cy| (x..x1).map(move |cx| (cx, cy)))
+            .for_each(|(cx, cy)| region.cells[(cy - y) * region.width + cx - x] = self.get(cx, cy));
+
+        match region.bounding_box() {
+            Some((bx, by, bw, bh)) => {
+                let cells = (by..by + bh)
+                    .flat_map(|cy| (bx..bx + bw).map(move |cx| (cx, cy)))
+                    .map(|(cx, cy)| region.get(cx, cy))
+                    .collect();
+                Board::from_cells(bw, bh, BorderOpt::Empty, cells)
+            }
+            None => Board::new(0, 0, BorderOpt::Empty),
+        }
+    }
+
+    /// Where `needle` would have to be stamped for its pattern to line up
+    /// with the first place, scanning row by row, the pattern appears on
+    /// this board
+    ///
+    /// `needle` is trimmed to the bounding box of its live cells, and
+    /// that box has to match exactly: live cells over live cells and dead
+    /// cells over dead ones, though the cells around the box don't matter.
+    /// The returned position is that of `needle`'s own top-left corner,
+    /// so it can be handed straight to [`Board::stamp`]; matches that
+    /// would put the corner off the board are skipped. Nothing wraps
+    /// around the edges, whatever the border mode. A needle without live
+    /// cells is found at `(0, 0)`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let board = glider.pad(3, 0, 0, 4);
+    ///
+    /// assert_eq!(board.contains_pattern(&glider), Some((4, 3)));
+    /// ```
+    pub fn contains_pattern(&self, needle: &Board) -> Option<(usize, usize)> {
+        let (left, top, _, _) = match needle.bounding_box() {
+            Some(bbox) => bbox,
+            None => return Some((0, 0)),
+        };
+        let needle = needle.extract_pattern(0, 0, needle.width, needle.height);
+        if needle.width > self.width || needle.height > self.height {
+            return None;
+        }
+
+        (0..=self.height - needle.height)
+            .flat_map(|y| (0..=self.width - needle.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| {
+                (0..needle.height).all(|ny| {
+                    let row = (y + ny) * self.width + x;
+                    self.cells[row..row + needle.width]
+                        == needle.cells[ny * needle.width..(ny + 1) * needle.width]
+                })
+            })
+            .find_map(|(x, y)| Some((x.checked_sub(left)?, y.checked_sub(top)?)))
+    }
+
+    /// A copy of the board with `top`, `right`, `bottom` and `left` rows
+    /// or columns of dead cells added around it
+    ///
+    /// The original content ends up offset by `left` and `top`. Handy for
+    /// giving a pattern room to grow before running it. The copy keeps the
+    /// board's settings: frozen cells move along with the content, new
+    /// cells follow the rule of the nearest old one, and the neighbor
+    /// radius, rule, backend and generation count carry over. Only the
+    /// history is left behind.
+    ///
+    /// # Panics:
+    ///
+    /// If the padded board would have more cells than a `usize` can
+    /// count, see [`Board::side_by_side`] for a checked alternative
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let padded = board.pad(1, 0, 0, 2);
+    /// assert_eq!((padded.width, padded.height), (7, 6));
+    /// assert_eq!(padded.get(4, 2), Cell::Alive);
+    /// ```
+    pub fn pad(&self, top: usize, right: usize, bottom: usize, left: usize) -> Board {
+        let side =
+            |before: usize, len: usize, after: usize| before.checked_add(len)?.checked_add(after);
+        let (width, height) = match (
+            side(left, self.width, right),
+            side(top, self.height, bottom),
+        ) {
+            (Some(width), Some(height)) => (width, height),
+            (width, height) => panic!(
+                "{}",
+                DimensionsTooLarge {
+                    width: width.unwrap_or(usize::MAX),
+                    height: height.unwrap_or(usize::MAX),
+                }
+            ),
+        };
+        let mut board = Board::new(width, height, self.border.clone());
+        board.set_neighbor_radius(self.neighbor_radius());
+        board.set_rule(self.rule().clone());
+        board.set_backend(self.backend());
+        board.skip_generations(self.generation().into());
+        (0..self.height).for_each(|y| {
+            let (from, to) = (y * self.width, (top + y) * width + left);
+            board.cells[to..to + self.width].copy_from_slice(&self.cells[from..from + self.width]);
+            board.frozen[to..to + self.width]
+                .copy_from_slice(&self.frozen[from..from + self.width]);
+        });
+        // New cells follow the rule of the nearest old one
+        board.rule_map = self
+            .rule_map
+            .clone()
+            .filter(|_| !self.cells.is_empty())
+            .map(|mut map| {
+                let nearest =
+                    |v: usize, margin: usize, len: usize| v.saturating_sub(margin).min(len - 1);
+                map.ids = (0..height)
+                    .flat_map(|y| (0..width).map(move |x| (x, y)))
+                    .map(|(x, y)| {
+                        map.ids[nearest(y, top, self.height) * self.width
+                            + nearest(x, left, self.width)]
+                    })
+                    .collect();
+                map
+            });
+
+        board
+    }
+
+    /// Advance only the cells inside the `w`×`h` rectangle whose top-left
+    /// corner is at `x` and `y` by one cycle, leaving the rest frozen
+    ///
+    /// Cells in the rectangle still see their neighbors outside it, so
+    /// the edge of the region behaves just like the rest of the board;
+    /// it's only the cells outside that don't update. The rectangle is
+    /// clipped to the board.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// board.set(1, 1, Cell::Alive);
+    /// board.set(1, 2, Cell::Alive);
+    ///
+    /// // Only the left column may change, so just (0, 1) is born
+    /// board.advance_region(0, 0, 1, 3);
+    /// assert_eq!(board.get(0, 1), Cell::Alive);
+    /// assert_eq!(board.get(1, 0), Cell::Alive);
+    /// ```
+    pub fn advance_region(&mut self, x: usize, y: usize, w: usize, h: usize) {
+        self.begin_generation();
+        let x1 = x.saturating_add(w).min(self.width);
+        let y1 = y.saturating_add(h).min(self.height);
+        let updates: Vec<(usize, usize, Cell)> = (y..y1)
+            .flat_map(|cy| (x..x1).map(move |cx| (cx, cy)))
+            .filter_map(
+                |(cx, cy)| match (self.cell_should_live(cx, cy), self.get(cx, cy)) {
+                    (true, Cell::Dead) => Some((cx, cy, Cell::Alive)),
+                    (false, Cell::Alive) => Some((cx, cy, Cell::Dead)),
+                    _ => None,
+                },
+            )
+            .collect();
+
+        self.apply_updates(&updates);
+    }
+
+    /// The number of live cells inside the `w`×`h` rectangle whose
+    /// top-left corner is at `x` and `y`
+    ///
+    /// Only the cells inside the rectangle are visited.
+    ///
+    /// # Panics:
+    ///
+    /// If the rectangle extends past the edge of the board
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.live_in_rect(0, 0, 5, 5), 5);
+    /// assert_eq!(board.live_in_rect(0, 0, 3, 2), 1);
+    /// ```
+    pub fn live_in_rect(&self, x: usize, y: usize, w: usize, h: usize) -> usize {
+        let (x1, y1) = (x.saturating_add(w), y.saturating_add(h));
+        if x1 > self.width || y1 > self.height {
+            panic!(
+                "out of bounds: {}x{} rectangle at ({}, {}) doesn't fit the {}x{} board",
+                w, h, x, y, self.width, self.height
+            );
+        }
+
+        (y..y1)
+            .map(|cy| {
+                self.cells[cy * self.width + x..cy * self.width + x1]
+                    .iter()
+                    .filter(|&&c| c == Cell::Alive)
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Set the cells at `coords` alive, where each coordinate is relative
+    /// to an origin placed at `origin_x` and `origin_y` on the board
+    ///
+    /// Pattern formats often use coordinates centered on the pattern, so
+    /// they can be negative; e.g. a coordinate of `(-1, 0)` with an origin
+    /// of `(5, 5)` is the cell at `(4, 5)`. Cells that map outside the
+    /// board, including past the range of `i64`, are skipped, unless
+    /// `strict` is set.
+    ///
+    /// # Errors:
+    ///
+    /// If `strict` is set and any cell maps outside the board, the first
+    /// such cell is reported and the board is left unchanged. A position
+    /// past the range of `i64` is reported at that limit.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// let blinker = [(-1, 0), (0, 0), (1, 0)];
+    ///
+    /// board.place_coords(&blinker, 2, 2, true).unwrap();
+    /// assert_eq!(board.get(1, 2), Cell::Alive);
+    ///
+    /// assert!(board.place_coords(&blinker, 0, 0, true).is_err());
+    /// ```
+    pub fn place_coords(
+        &mut self,
+        coords: &[(i64, i64)],
+        origin_x: i64,
+        origin_y: i64,
+        strict: bool,
+    ) -> Result<(), OutOfBounds> {
+        let (w, h) = (self.width as i64, self.height as i64);
+        let on_board = |&(x, y): &(i64, i64)| {
+            let (x, y) = (origin_x.checked_add(x)?, origin_y.checked_add(y)?);
+            Some((x, y)).filter(|_| (0..w).contains(&x) && (0..h).contains(&y))
+        };
+
+        if strict {
+            if let Some(&(x, y)) = coords.iter().find(|c| on_board(c).is_none()) {
+                return Err(OutOfBounds {
+                    x: origin_x.saturating_add(x),
+                    y: origin_y.saturating_add(y),
+                });
+            }
+        }
+        coords
+            .iter()
+            .filter_map(on_board)
+            .for_each(|(x, y)| self.set(x as usize, y as usize, Cell::Alive));
+
+        Ok(())
+    }
+
+    /// The average coordinate of the live cells, or `None` if there are
+    /// none
+    ///
+    /// Tracking this across generations gives a pattern's velocity, e.g.
+    /// a glider's centroid moves a quarter cell diagonally per
+    /// generation. Coordinates aren't unwrapped on `Loop` boards, so a
+    /// pattern straddling an edge averages to somewhere in between.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(1, 2, 3, 1, Cell::Alive);
+    /// assert_eq!(board.centroid(), Some((2.0, 2.0)));
+    /// ```
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        let (n, sx, sy) = self
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .fold((0usize, 0usize, 0usize), |(n, sx, sy), (i, _)| {
+                (n + 1, sx + i % self.width, sy + i / self.width)
+            });
+        if n == 0 {
+            return None;
+        }
+
+        Some((sx as f64 / n as f64, sy as f64 / n as f64))
+    }
+
+    /// Advance the board `cycles` cycles and return how far the centroid
+    /// of the live cells moved per generation, as `(dx, dy)`
+    ///
+    /// This measures a spaceship's speed, e.g. about `(0.25, 0.25)` for a
+    /// glider heading down and to the right. Measure over a multiple of
+    /// the pattern's period for an exact result. If the board is empty
+    /// before or after, or `cycles` is 0, the velocity is `(0.0, 0.0)`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(6, 6, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    /// assert_eq!(board.estimate_velocity(4), (0.0, 0.0));
+    /// ```
+    pub fn estimate_velocity(&mut self, cycles: usize) -> (f64, f64) {
+        let start = self.centroid();
+        self.advance_n_cycles(cycles);
+
+        match (start, self.centroid()) {
+            (Some((x0, y0)), Some((x1, y1))) if cycles > 0 => {
+                let n = cycles as f64;
+                ((x1 - x0) / n, (y1 - y0) / n)
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// The number of generations after which the board's live cells
+    /// repeat their shape, possibly somewhere else, or `None` if that
+    /// doesn't happen within `max` generations
+    ///
+    /// Generations are compared up to translation, unwrapped across the
+    /// edges on `Loop` boards, so a spaceship reports its own period
+    /// rather than the time it takes to travel around the board, which
+    /// is what [`Board::detect_stability`] sees. Like that method, the
+    /// shape doesn't have to repeat from the first generation; the period
+    /// of whatever cycle the board settles into is returned. An empty
+    /// board has a period of 1.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(8, 8, BorderOpt::Loop);
+    /// board
+    ///     .place_coords(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)], 0, 0, true)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(board.intrinsic_period(40), Some(4));
+    /// assert_eq!(board.detect_stability(40).map(|(_, period)| period), Some(32));
+    /// ```
+    pub fn intrinsic_period(&self, max: usize) -> Option<usize> {
+        let mut board = self.clone();
+        let mut seen: HashMap<Vec<(usize, usize)>, usize> = HashMap::new();
+
+        for generation in 0..=max {
+            if let Some(&start) = seen.get(&board.shape()) {
+                return Some(generation - start);
+            }
+            seen.insert(board.shape(), generation);
+            board.advance_cycle();
+        }
+
+        None
+    }
+
+    /// Recognize the board's live cells as a spaceship, a pattern that
+    /// travels by repeating its shape further along, with a period of
+    /// at most `max_period`
+    ///
+    /// The pattern is run on a board of its own, with enough room that it
+    /// can't reach an edge, under this board's rules and neighbor radius.
+    /// On `Loop` boards a pattern straddling an edge is unwrapped first.
+    /// Returns `None` for oscillators and still lifes, which repeat
+    /// without moving, and for anything that doesn't repeat in time.
+    ///
+    /// That board is padded by `max_period` times the neighbor radius on
+    /// every side, so its memory grows with the square of `max_period`;
+    /// keep it to the periods of interest. If the padded board would have
+    /// more cells than a `usize` can count, this returns `None`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(
+    ///     glider.classify_spaceship(8),
+    ///     Some(Spaceship { period: 4, dx: 1, dy: 1 })
+    /// );
+    /// ```
+    pub fn classify_spaceship(&self, max_period: usize) -> Option<Spaceship> {
+        let mut source = self.clone();
+        if self.border == BorderOpt::Loop {
+            source.follow_on_torus();
+        }
+        source.bounding_box()?;
+        source.set_border(BorderOpt::Empty);
+        source.crop_to_active(0);
+        // Nothing travels further than the neighbor radius per generation
+        let room = max_period
+            .checked_add(1)?
+            .checked_mul(self.neighbor_radius())?;
+        let side = |len: usize| room.checked_mul(2)?.checked_add(len);
+        side(source.width)?.checked_mul(side(source.height)?)?;
+        let mut board = source.pad(room, room, room, room);
+
+        let start = board.shape();
+        let (x0, y0, _, _) = board.bounding_box()?;
+        for period in 1..=max_period {
+            board.advance_cycle();
+            if board.shape() == start {
+                let (x, y, _, _) = board.bounding_box()?;
+                let (dx, dy) = (x as i64 - x0 as i64, y as i64 - y0 as i64);
+                return match (dx, dy) {
+                    (0, 0) => None,
+                    _ => Some(Spaceship { period, dx, dy }),
+                };
+            }
+        }
+
+        None
+    }
+
+    /// The oscillator on the board cropped to the smallest rectangle
+    /// that holds all of its phases, in its current phase
+    ///
+    /// Runs the board through `period` generations, which leaves an
+    /// oscillator of that period back where it started, and takes the
+    /// bounding box of all the phases together. The current phase is
+    /// copied out of that box onto an `Empty` board, ready to be stamped
+    /// elsewhere without any phase spilling out. A board without live
+    /// cells gives a 0×0 board.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(7, 7, BorderOpt::Empty);
+    /// board.fill_rect(2, 3, 3, 1, Cell::Alive);
+    ///
+    /// let blinker = board.minimal_oscillator(2);
+    /// assert_eq!(blinker.to_ascii(), "...\n###\n...\n");
+    /// ```
+    pub fn minimal_oscillator(&mut self, period: usize) -> Board {
+        let start = self.clone();
+        let mut bounds = self.bounding_box();
+        (0..period).for_each(|_| {
+            self.advance_cycle();
+            bounds = match (bounds, self.bounding_box()) {
+                (Some((x0, y0, w0, h0)), Some((x1, y1, w1, h1))) => {
+                    let (x, y) = (x0.min(x1), y0.min(y1));
+                    let (w, h) = ((x0 + w0).max(x1 + w1) - x, (y0 + h0).max(y1 + h1) - y);
+                    Some((x, y, w, h))
+                }
+                (bounds, other) => bounds.or(other),
+            };
+        });
+
+        match bounds {
+            Some((x, y, w, h)) => {
+                let cells = (y..y + h)
+                    .flat_map(|cy| (x..x + w).map(move |cx| (cx, cy)))
+                    .map(|(cx, cy)| start.get(cx, cy))
+                    .collect();
+                Board::from_cells(w, h, BorderOpt::Empty, cells)
+            }
+            None => Board::new(0, 0, BorderOpt::Empty),
+        }
+    }
+
+    // The live cells relative to where the pattern starts on each axis,
+    // in row-major order, so translated copies of a pattern have the same
+    // shape
+    fn shape(&self) -> Vec<(usize, usize)> {
+        let live: Vec<(usize, usize)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.get(x, y) == Cell::Alive)
+            .collect();
+        let origin = |mut coords: Vec<usize>, len: usize| {
+            coords.sort_unstable();
+            coords.dedup();
+            match (coords.first(), &self.border) {
+                (None, _) => 0,
+                (Some(_), BorderOpt::Loop) => Self::ring_origin(&coords, len),
+                (Some(&first), _) => first,
+            }
+        };
+        let ox = origin(live.iter().map(|&(x, _)| x).collect(), self.width);
+        let oy = origin(live.iter().map(|&(_, y)| y).collect(), self.height);
+
+        let mut shape: Vec<(usize, usize)> = live
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    (x + self.width - ox) % self.width,
+                    (y + self.height - oy) % self.height,
+                )
+            })
+            .collect();
+        shape.sort_unstable_by_key(|&(x, y)| (y, x));
+
+        shape
+    }
+
+    /// Shift every cell so the centroid of the live cells lands as close
+    /// to the center of the board as possible
+    ///
+    /// Handy for keeping a traveling pattern like a glider in view. On a
+    /// `Loop` board cells wrap around; otherwise anything shifted off the
+    /// board is lost. Frozen cells and per-cell rules move along with the
+    /// cells. A board with no live cells is left alone.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    ///
+    /// board.recenter_on_mass();
+    /// assert_eq!(board.get(2, 2), Cell::Alive);
+    /// assert_eq!(board.get(0, 0), Cell::Dead);
+    /// ```
+    pub fn recenter_on_mass(&mut self) {
+        let (cx, cy) = match self.centroid() {
+            Some(centroid) => centroid,
+            None => return,
+        };
+        let dx = ((self.width - 1) as f64 / 2.0 - cx).round() as i64;
+        let dy = ((self.height - 1) as f64 / 2.0 - cy).round() as i64;
+
+        self.shift(dx, dy);
+    }
+
+    /// Shift a `Loop` board so its live cells are centered, unwrapping
+    /// them across the edges first
+    ///
+    /// Calling this every generation keeps a spaceship still in view
+    /// while it travels around the torus. Unlike
+    /// [`Board::recenter_on_mass`], a pattern straddling an edge is
+    /// treated as the one piece it is, rather than averaged to somewhere
+    /// in between. Since the board wraps, no cells are lost, and frozen
+    /// cells and per-cell rules keep their place relative to the pattern.
+    /// On other borders this is the same as [`Board::recenter_on_mass`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // A blinker wrapped around the top left corner
+    /// let mut board = Board::new(5, 5, BorderOpt::Loop);
+    /// board.set(4, 0, Cell::Alive);
+    /// board.set(0, 0, Cell::Alive);
+    /// board.set(1, 0, Cell::Alive);
+    ///
+    /// board.follow_on_torus();
+    /// assert_eq!(board.live_in_rect(1, 2, 3, 1), 3);
+    /// ```
+    pub fn follow_on_torus(&mut self) {
+        if self.border != BorderOpt::Loop {
+            return self.recenter_on_mass();
+        }
+        let live: Vec<(usize, usize)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.get(x, y) == Cell::Alive)
+            .collect();
+        if live.is_empty() {
+            return;
+        }
+
+        // Unwrap each axis from just after its widest gap, then move the
+        // mean of the unwrapped coordinates to the middle
+        let offset = |coords: Vec<usize>, len: usize| -> i64 {
+            let mut occupied = coords.clone();
+            occupied.sort_unstable();
+            occupied.dedup();
+            let origin = Self::ring_origin(&occupied, len);
+            let unwrapped: usize = coords.iter().map(|&c| (c + len - origin) % len).sum();
+            let mean = unwrapped as f64 / coords.len() as f64;
+
+            ((len - 1) as f64 / 2.0 - mean).round() as i64 - origin as i64
+        };
+        let dx = offset(live.iter().map(|&(x, _)| x).collect(), self.width);
+        let dy = offset(live.iter().map(|&(_, y)| y).collect(), self.height);
+
+        self.shift(dx, dy);
+    }
+
+    // The first of the sorted, distinct `occupied` positions on a ring of
+    // `len` after the widest run of unoccupied ones, where a pattern on
+    // the ring can be cut open without splitting it
+    fn ring_origin(occupied: &[usize], len: usize) -> usize {
+        let (first, last) = (occupied[0], occupied[occupied.len() - 1]);
+        let mut widest = (first + len - last - 1, first);
+
+        occupied.windows(2).for_each(|pair| {
+            let gap = pair[1] - pair[0] - 1;
+            if gap > widest.0 {
+                widest = (gap, pair[1]);
+            }
+        });
+
+        widest.1
+    }
+
+    // Move every cell by `dx` and `dy`, wrapping on Loop boards and
+    // dropping cells that fall off otherwise
+    //
+    // Frozen cells move along with the rest. So do per-cell rules, with
+    // any cell uncovered by the move following the rule of the nearest
+    // cell that was there.
+    pub(crate) fn shift(&mut self, dx: i64, dy: i64) {
+        let (w, h) = (self.width as i64, self.height as i64);
+        let wrap = self.border == BorderOpt::Loop;
+        let positions = || (0..h).flat_map(move |y| (0..w).map(move |x| (x, y)));
+        let mut cells = vec![Cell::Dead; self.cells.len()];
+        let mut frozen = vec![false; self.frozen.len()];
+
+        positions()
+            .filter_map(|(x, y)| {
+                let (nx, ny) = (x + dx, y + dy);
+                let from = (y * w + x) as usize;
+                match wrap {
+                    true => Some((from, nx.rem_euclid(w), ny.rem_euclid(h))),
+                    _ if (0..w).contains(&nx) && (0..h).contains(&ny) => Some((from, nx, ny)),
+                    _ => None,
+                }
+            })
+            .for_each(|(from, x, y)| {
+                let to = (y * w + x) as usize;
+                cells[to] = self.cells[from];
+                frozen[to] = self.frozen[from];
+            });
+        if let Some(map) = self.rule_map.as_mut() {
+            let source = |v: i64, len: i64| match wrap {
+                true => v.rem_euclid(len),
+                false => v.max(0).min(len - 1),
+            };
+            map.ids = positions()
+                .map(|(x, y)| map.ids[(source(y - dy, h) * w + source(x - dx, w)) as usize])
+                .collect();
+        }
+
+        self.cells = cells;
+        self.frozen = frozen;
+        self.invalidate_frontier();
+    }
+
+    /// The number of fully dead rows or columns on each side of the live
+    /// cells, as `(top, right, bottom, left)`
+    ///
+    /// A board with no live cells is all margin, so every side reports
+    /// the full height or width.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.margins(), (1, 1, 1, 1));
+    /// ```
+    pub fn margins(&self) -> (usize, usize, usize, usize) {
+        match self.bounding_box() {
+            Some((x, y, w, h)) => (y, self.width - x - w, self.height - y - h, x),
+            None => (self.height, self.width, self.height, self.width),
+        }
+    }
+
+    /// Whether any live cell is on the outermost row or column of the
+    /// board
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert!(!board.touches_edge());
+    /// assert!(board.advanced_n(4).touches_edge());
+    /// ```
+    pub fn touches_edge(&self) -> bool {
+        self.bounding_box().is_some() && {
+            let (top, right, bottom, left) = self.margins();
+            top.min(right).min(bottom).min(left) == 0
+        }
+    }
+
+    /// Advance the board until a live cell reaches the edge, returning
+    /// how many cycles that took, or `None` if it doesn't happen within
+    /// `max` cycles
+    ///
+    /// Started with a glider gun in the middle of the board, this counts
+    /// the cycles until the first glider is out. A board already
+    /// touching the edge returns `Some(0)` without advancing. The board
+    /// is left where it stopped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(2, 2, 1, 1, Cell::Alive);
+    /// assert_eq!(board.cycles_until_edge_contact(10), None);
+    /// ```
+    pub fn cycles_until_edge_contact(&mut self, max: usize) -> Option<usize> {
+        for cycles in 0..=max {
+            if self.touches_edge() {
+                return Some(cycles);
+            }
+            if cycles < max {
+                self.advance_cycle();
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        board::Generation,
+        rule::{Rule, RuleId},
+    };
+
+    // ░░░░░░░░░░
+    // ░░░░░░░░░░
+    // ░░▓▓▓▓▓▓░░
+    // ░░░░░░░░░░
+    // ░░░░░░░░░░
+    fn get_centered_blinker_board() -> Board {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+
+        board.set(1, 2, Cell::Alive);
+        board.set(2, 2, Cell::Alive);
+        board.set(3, 2, Cell::Alive);
+
+        board
+    }
+
+    #[test]
+    fn finds_blinker() {
+        let blinker = get_centered_blinker_board();
+
+        let mut board = Board::new(12, 9, BorderOpt::Loop);
+        board.fill_rect(0, 0, 2, 2, Cell::Alive);
+        board.fill_rect(6, 4, 3, 1, Cell::Alive);
+        // The blinker's box starts at (1, 2) on its own board
+        assert_eq!(board.contains_pattern(&blinker), Some((5, 2)));
+        let vertical = blinker.clone().advanced();
+        assert_eq!(board.contains_pattern(&vertical), None);
+
+        // Next generation, both boards are in the other phase, so the
+        // needle lines up at the same place
+        board.advance_cycle();
+        assert_eq!(board.contains_pattern(&vertical), Some((5, 2)));
+        let mut stamped = Board::new(12, 9, BorderOpt::Empty);
+        stamped.fill_rect(0, 0, 2, 2, Cell::Alive);
+        stamped.stamp(&vertical, 5, 2);
+        assert_eq!(stamped.to_string(), board.to_string());
+
+        // Only the bounding box has to match, not what's around it
+        let mut board = Board::new(12, 9, BorderOpt::Empty);
+        board.fill_rect(6, 4, 4, 1, Cell::Alive);
+        assert_eq!(board.contains_pattern(&blinker), Some((5, 2)));
+        board.set(7, 4, Cell::Dead);
+        assert_eq!(board.contains_pattern(&blinker), None);
+    }
+
+    #[test]
+    fn pattern_search_edges() {
+        let mut block = Board::new(2, 2, BorderOpt::Empty);
+        block.fill_rect(0, 0, 2, 2, Cell::Alive);
+        let mut board = Board::new(4, 4, BorderOpt::Empty);
+        board.fill_rect(2, 2, 2, 2, Cell::Alive);
+
+        assert_eq!(board.contains_pattern(&block), Some((2, 2)));
+        // Larger than the board it's looked for on
+        let mut corners = Board::new(4, 4, BorderOpt::Empty);
+        corners.set(0, 0, Cell::Alive);
+        corners.set(3, 3, Cell::Alive);
+        assert_eq!(block.contains_pattern(&corners), None);
+        assert_eq!(board.contains_pattern(&corners), None);
+        let empty = Board::new(9, 9, BorderOpt::Empty);
+        assert_eq!(board.contains_pattern(&empty), Some((0, 0)));
+
+        // Matches that would put the needle's corner off the board are
+        // skipped in favor of later ones
+        let margined = block.pad(3, 0, 0, 3);
+        assert_eq!(board.contains_pattern(&margined), None);
+        let mut two = Board::new(6, 6, BorderOpt::Empty);
+        two.fill_rect(0, 0, 2, 2, Cell::Alive);
+        two.fill_rect(4, 4, 2, 2, Cell::Alive);
+        assert_eq!(two.contains_pattern(&margined), Some((1, 1)));
+    }
+
+    #[test]
+    fn blinker_margins() {
+        let mut board = get_centered_blinker_board();
+
+        assert_eq!(board.bounding_box(), Some((1, 2, 3, 1)));
+        assert_eq!(board.margins(), (2, 1, 2, 1));
+
+        board.advance_cycle();
+        assert_eq!(board.margins(), (1, 2, 1, 2));
+    }
+
+    #[test]
+    fn advance_region_freezes_outside() {
+        // Two vertical blinkers, one on each side of the board
+        let mut board = Board::new(9, 5, BorderOpt::Empty);
+
+        (1..4).for_each(|y| {
+            board.set(1, y, Cell::Alive);
+            board.set(7, y, Cell::Alive);
+        });
+
+        board.advance_region(0, 0, 4, 5);
+
+        assert_eq!(board.get(0, 2), Cell::Alive);
+        assert_eq!(board.get(2, 2), Cell::Alive);
+        assert_eq!(board.get(1, 1), Cell::Dead);
+        assert_eq!(board.get(1, 3), Cell::Dead);
+
+        assert_eq!(board.get(7, 1), Cell::Alive);
+        assert_eq!(board.get(7, 3), Cell::Alive);
+        assert_eq!(board.get(6, 2), Cell::Dead);
+        assert_eq!(board.get(8, 2), Cell::Dead);
+    }
+
+    #[test]
+    fn advance_region_clips() {
+        let mut board = get_centered_blinker_board();
+        let mut expected = get_centered_blinker_board();
+
+        board.advance_region(0, 0, 100, 100);
+        expected.advance_cycle();
+
+        assert_eq!(board, expected);
+    }
+
+    #[test]
+    fn live_around_blinker() {
+        let mut board = get_centered_blinker_board();
+
+        assert_eq!(board.live_in_rect(1, 1, 3, 3), 3);
+        assert_eq!(board.live_in_rect(2, 1, 3, 3), 2);
+        assert_eq!(board.live_in_rect(0, 0, 5, 2), 0);
+
+        board.advance_cycle();
+        assert_eq!(board.live_in_rect(1, 1, 3, 3), 3);
+        assert_eq!(board.live_in_rect(2, 1, 3, 3), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn live_in_rect_out_of_bounds() {
+        get_centered_blinker_board().live_in_rect(3, 3, 3, 3);
+    }
+
+    fn centroid(board: &Board) -> (f64, f64) {
+        let live: Vec<(usize, usize)> = (0..board.height)
+            .flat_map(|y| (0..board.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| board.get(x, y) == Cell::Alive)
+            .collect();
+        let n = live.len() as f64;
+
+        (
+            live.iter().map(|&(x, _)| x as f64).sum::<f64>() / n,
+            live.iter().map(|&(_, y)| y as f64).sum::<f64>() / n,
+        )
+    }
+
+    #[test]
+    fn glider_centroid_velocity() {
+        let mut board = Board::new(20, 20, BorderOpt::Empty);
+        board
+            .place_coords(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)], 2, 2, true)
+            .unwrap();
+        let (x0, y0) = board.centroid().unwrap();
+
+        board.advance_n_cycles(24);
+        let (x1, y1) = board.centroid().unwrap();
+
+        assert!(((x1 - x0) / 24.0 - 0.25).abs() < 1e-9);
+        assert!(((y1 - y0) / 24.0 - 0.25).abs() < 1e-9);
+        assert_eq!(Board::new(3, 3, BorderOpt::Empty).centroid(), None);
+    }
+
+    #[test]
+    fn glider_estimated_velocity() {
+        let mut board = Board::new(20, 20, BorderOpt::Empty);
+        board
+            .place_coords(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)], 2, 2, true)
+            .unwrap();
+
+        let (vx, vy) = board.estimate_velocity(40);
+        assert!((vx - 0.25).abs() < 1e-9 && (vy - 0.25).abs() < 1e-9);
+        assert_eq!(board.generation(), Generation(40));
+
+        let (vx, vy) = board.clone().estimate_velocity(0);
+        assert_eq!((vx, vy), (0.0, 0.0));
+    }
+
+    #[test]
+    fn recenter_tracks_glider() {
+        let mut board = Board::new(12, 10, BorderOpt::Empty);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+
+        (0..10).for_each(|_| {
+            board.advance_n_cycles(4);
+            board.recenter_on_mass();
+
+            let (cx, cy) = centroid(&board);
+            assert!((cx - 5.5).abs() <= 1.0 && (cy - 4.5).abs() <= 1.0);
+            assert_eq!(board.live_in_rect(0, 0, 12, 10), 5);
+        });
+    }
+
+    #[test]
+    fn recenter_wraps_on_loop() {
+        let mut board = Board::new(5, 5, BorderOpt::Loop);
+        board.set(0, 0, Cell::Alive);
+        board.set(4, 4, Cell::Alive);
+
+        board.recenter_on_mass();
+
+        assert_eq!(board.get(0, 0), Cell::Alive);
+        assert_eq!(board.get(4, 4), Cell::Alive);
+    }
+
+    #[test]
+    fn glider_reaches_edge() {
+        let mut board = Board::new(10, 10, BorderOpt::Empty);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x + 3, y + 3, Cell::Alive));
+
+        // Four cells from the bottom edge, the glider's leading row gets
+        // one closer at generations 1, 5 and 9 and touches at 13
+        assert_eq!(board.clone().cycles_until_edge_contact(12), None);
+        assert_eq!(board.cycles_until_edge_contact(100), Some(13));
+        assert_eq!(board.generation(), Generation(13));
+        assert_eq!(board.margins().2, 0);
+        assert_eq!(board.cycles_until_edge_contact(100), Some(0));
+    }
+
+    #[test]
+    fn follow_glider_on_torus() {
+        // Start the glider straddling the bottom right corner
+        let mut board = Board::new(12, 10, BorderOpt::Loop);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set((x + 10) % 12, (y + 8) % 10, Cell::Alive));
+
+        (0..200).for_each(|_| {
+            board.advance_cycle();
+            board.follow_on_torus();
+
+            let (cx, cy) = centroid(&board);
+            assert!((cx - 5.5).abs() <= 1.0 && (cy - 4.5).abs() <= 1.0);
+            assert_eq!(
+                board.bounding_box().map(|(_, _, w, h)| (w, h)),
+                Some((3, 3))
+            );
+            assert_eq!(board.live_in_rect(0, 0, 12, 10), 5);
+        });
+    }
+
+    #[test]
+    fn glider_intrinsic_period() {
+        let mut board = Board::new(12, 10, BorderOpt::Loop);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+
+        // Back where it started only after lapping both dimensions
+        assert_eq!(board.detect_stability(300), Some((Generation(0), 240)));
+        assert_eq!(board.intrinsic_period(200), Some(4));
+        assert_eq!(board.intrinsic_period(3), None);
+
+        // Oscillators and still lifes are unaffected
+        assert_eq!(get_centered_blinker_board().intrinsic_period(10), Some(2));
+        assert_eq!(Board::default().intrinsic_period(10), Some(1));
+    }
+
+    #[test]
+    fn classify_glider_and_lwss() {
+        let mut glider = Board::new(6, 6, BorderOpt::Loop);
+        [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .for_each(|&(x, y)| glider.set((x + 4) % 6, (y + 4) % 6, Cell::Alive));
+        assert_eq!(
+            glider.classify_spaceship(10),
+            Some(Spaceship {
+                period: 4,
+                dx: 1,
+                dy: 1
+            })
+        );
+
+        let mut lwss = Board::new(5, 4, BorderOpt::Empty);
+        [
+            (0, 0),
+            (3, 0),
+            (4, 1),
+            (0, 2),
+            (4, 2),
+            (1, 3),
+            (2, 3),
+            (3, 3),
+            (4, 3),
+        ]
+        .iter()
+        .for_each(|&(x, y)| lwss.set(x, y, Cell::Alive));
+        assert_eq!(
+            lwss.classify_spaceship(10),
+            Some(Spaceship {
+                period: 4,
+                dx: 2,
+                dy: 0
+            })
+        );
+        assert_eq!(lwss.classify_spaceship(3), None);
+    }
+
+    #[test]
+    fn classify_with_too_much_room() {
+        // The board to run it on can't be made, rather than overflowing
+        let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+        assert_eq!(glider.classify_spaceship(usize::MAX), None);
+        assert_eq!(glider.classify_spaceship(usize::MAX / 4), None);
+    }
+
+    #[test]
+    fn classify_under_other_rules() {
+        // Under Seeds (B2/S) this shape moves a row down every generation,
+        // while under Conway's rules it goes nowhere
+        let mut board = Board::new(4, 2, BorderOpt::Empty);
+        [(0, 0), (3, 0), (1, 1), (2, 1)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        assert_eq!(board.classify_spaceship(4), None);
+
+        board.set_rule(Rule::parse("B2/S").unwrap());
+        assert_eq!(
+            board.classify_spaceship(4),
+            Some(Spaceship {
+                period: 1,
+                dx: 0,
+                dy: 1
+            })
+        );
+    }
+
+    #[test]
+    fn minimal_blinker_and_toad() {
+        let mut board = get_centered_blinker_board();
+        let blinker = board.minimal_oscillator(2);
+        assert_eq!((blinker.width, blinker.height), (3, 3));
+        assert_eq!(blinker.to_ascii(), "...\n###\n...\n");
+        assert_eq!(board, get_centered_blinker_board());
+        // The blinker turns within its box
+        let turned = blinker
+            .pad(1, 1, 1, 1)
+            .advanced()
+            .extract_pattern(0, 0, 5, 5);
+        assert_eq!(turned.to_ascii(), "#\n#\n#\n");
+
+        let mut toad = Board::new(10, 10, BorderOpt::Empty);
+        toad.fill_rect(4, 4, 3, 1, Cell::Alive);
+        toad.fill_rect(3, 5, 3, 1, Cell::Alive);
+        assert_eq!(
+            toad.minimal_oscillator(2).to_ascii(),
+            "....\n.###\n###.\n....\n"
+        );
+
+        assert_eq!(Board::default().minimal_oscillator(2).width, 0);
+    }
+
+    #[test]
+    fn oscillators_are_not_spaceships() {
+        assert_eq!(get_centered_blinker_board().classify_spaceship(10), None);
+        assert_eq!(Board::default().classify_spaceship(10), None);
+    }
+
+    #[test]
+    fn follow_on_torus_keeps_still_pattern_centered() {
+        let mut board = get_centered_blinker_board();
+        board.set_border(BorderOpt::Loop);
+        let expected = board.clone();
+
+        board.shift(3, 4);
+        board.follow_on_torus();
+        assert_eq!(board, expected);
+
+        // Other borders fall back to recentering
+        let mut empty = Board::new(5, 5, BorderOpt::Empty);
+        empty.set(0, 0, Cell::Alive);
+        empty.follow_on_torus();
+        assert_eq!(empty.get(2, 2), Cell::Alive);
+    }
+
+    #[test]
+    fn follow_on_torus_carries_frozen_cells_and_rules() {
+        // A blinker wrapped around the top left corner, with a frozen
+        // cell and a HighLife cell a fixed distance from it
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let mut board = Board::new(5, 5, BorderOpt::Loop);
+        [(4, 0), (0, 0), (1, 0)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        board.freeze(0, 3);
+        let mut ids = vec![RuleId(0); 25];
+        ids[3 * 5 + 4] = RuleId(1);
+        board.set_rule_map(vec![Rule::conway(), highlife.clone()], ids);
+
+        // The blinker moves by (2, 2), and so does everything else
+        board.follow_on_torus();
+        assert_eq!(board.live_in_rect(1, 2, 3, 1), 3);
+        assert!(board.is_frozen(2, 0));
+        assert_eq!((0..25).filter(|i| board.is_frozen(i % 5, i / 5)).count(), 1);
+        assert_eq!(board.rule_at(1, 0), &highlife);
+        assert_eq!(
+            (0..25)
+                .filter(|i| board.rule_at(i % 5, i / 5) == &highlife)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn shift_moves_frozen_cells_and_rules() {
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+        board.set(1, 1, Cell::Alive);
+        board.freeze(0, 0);
+        board.freeze(3, 2);
+        let mut ids = vec![RuleId(0); 12];
+        ids[5] = RuleId(1);
+        board.set_rule_map(vec![Rule::conway(), highlife.clone()], ids);
+
+        board.shift(1, 1);
+        assert_eq!(board.get(2, 2), Cell::Alive);
+        assert!(board.is_frozen(1, 1));
+        // Frozen cells shifted off the board are gone
+        assert!(!board.is_frozen(0, 0) && !board.is_frozen(3, 2));
+        assert_eq!(board.rule_at(2, 2), &highlife);
+        assert_eq!(board.rule_at(1, 1), &Rule::conway());
+        // The uncovered top row and left column copy their neighbors
+        assert_eq!(board.rule_at(2, 0), board.rule_at(2, 1));
+
+        let mut looped = Board::new(3, 3, BorderOpt::Loop);
+        looped.freeze(2, 2);
+        let mut ids = vec![RuleId(0); 9];
+        ids[8] = RuleId(1);
+        looped.set_rule_map(vec![Rule::conway(), highlife.clone()], ids);
+        looped.shift(1, 1);
+        assert!(looped.is_frozen(0, 0));
+        assert_eq!(looped.rule_at(0, 0), &highlife);
+        assert_eq!(looped.rule_at(2, 2), &Rule::conway());
+    }
+
+    #[test]
+    fn place_coords_ignores_out_of_bounds() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        let glider = [(0, -1), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+        board.place_coords(&glider, 2, 1, false).unwrap();
+
+        assert_eq!(board.live_in_rect(0, 0, 3, 3), 3);
+        assert_eq!(board.get(2, 0), Cell::Alive);
+        assert_eq!(board.get(1, 2), Cell::Alive);
+        assert_eq!(board.get(2, 2), Cell::Alive);
+    }
+
+    #[test]
+    fn place_coords_strict_errors() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        let glider = [(0, -1), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+        assert_eq!(
+            board.place_coords(&glider, 2, 1, true),
+            Err(OutOfBounds { x: 3, y: 1 })
+        );
+        assert_eq!(board.bounding_box(), None);
+
+        board.place_coords(&glider, 1, 1, true).unwrap();
+        assert_eq!(board.live_in_rect(0, 0, 3, 3), 5);
+    }
+
+    #[test]
+    fn place_coords_past_i64() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        let coords = [(i64::MAX, 0), (0, i64::MIN), (1, 1)];
+
+        assert_eq!(
+            board.place_coords(&coords, 1, -1, true),
+            Err(OutOfBounds { x: i64::MAX, y: -1 })
+        );
+        assert_eq!(
+            board.place_coords(&coords[1..], 0, -1, true),
+            Err(OutOfBounds { x: 0, y: i64::MIN })
+        );
+        assert_eq!(board.bounding_box(), None);
+
+        board.place_coords(&coords, 1, -1, false).unwrap();
+        assert_eq!(board.live_in_rect(0, 0, 3, 3), 1);
+        assert_eq!(board.get(2, 0), Cell::Alive);
+    }
+
+    #[test]
+    fn extract_blinker() {
+        let mut board = Board::new(12, 10, BorderOpt::Loop);
+        (5..8).for_each(|y| board.set(8, y, Cell::Alive));
+        // Outside the region, so not part of the pattern
+        board.set(0, 0, Cell::Alive);
+
+        let blinker = board.extract_pattern(6, 3, 6, 6);
+
+        assert_eq!((blinker.width, blinker.height), (1, 3));
+        assert_eq!(blinker.border, BorderOpt::Empty);
+        assert_eq!(blinker.live_in_rect(0, 0, 1, 3), 3);
+    }
+
+    #[test]
+    fn extract_empty_region() {
+        let board = get_centered_blinker_board();
+
+        let nothing = board.extract_pattern(0, 0, 5, 2);
+        assert_eq!((nothing.width, nothing.height), (0, 0));
+
+        let clipped = board.extract_pattern(2, 2, 10, 10);
+        assert_eq!((clipped.width, clipped.height), (2, 1));
+    }
+
+    #[test]
+    fn fill_2x2() {
+        let empty = Board::new(5, 5, BorderOpt::Empty);
+        let mut board = empty.clone();
+
+        board.fill_rect(1, 2, 2, 2, Cell::Alive);
+
+        assert_eq!(
+            empty.diff(&board).unwrap(),
+            vec![
+                (1, 2, Cell::Alive),
+                (2, 2, Cell::Alive),
+                (1, 3, Cell::Alive),
+                (2, 3, Cell::Alive),
+            ]
+        );
+
+        board.clear_rect(0, 0, 5, 5);
+        assert_eq!(board, empty);
+    }
+
+    #[test]
+    fn pad_centers_blinker() {
+        let mut blinker = Board::new(3, 3, BorderOpt::Empty);
+        blinker.fill_rect(0, 1, 3, 1, Cell::Alive);
+
+        let padded = blinker.pad(2, 2, 2, 2);
+
+        assert_eq!((padded.width, padded.height), (7, 7));
+        assert_eq!(padded.bounding_box(), Some((2, 3, 3, 1)));
+        assert_eq!(padded.margins(), (3, 2, 3, 2));
+    }
+
+    #[test]
+    fn pad_keeps_configuration() {
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let mut board = Board::new(2, 2, BorderOpt::Empty);
+        board.set_rule(highlife.clone());
+        board.set_neighbor_radius(2);
+        board.freeze(1, 0);
+        board.set_rule_map(
+            vec![Rule::conway(), highlife.clone()],
+            vec![RuleId(0), RuleId(1), RuleId(0), RuleId(0)],
+        );
+        board.skip_generations(5);
+
+        let padded = board.pad(1, 2, 0, 1);
+        assert_eq!((padded.width, padded.height), (5, 3));
+        assert_eq!(padded.rule(), &highlife);
+        assert_eq!(padded.neighbor_radius(), 2);
+        assert_eq!(padded.generation(), Generation(5));
+        assert!(padded.is_frozen(2, 1));
+        assert_eq!(padded.frozen.iter().filter(|&&f| f).count(), 1);
+        // The new right-hand columns and top row copy their nearest cell
+        assert_eq!(padded.rule_at(4, 0), &highlife);
+        assert_eq!(padded.rule_at(0, 2), &Rule::conway());
+    }
+
+    #[test]
+    #[should_panic(expected = "more cells than fit in memory")]
+    fn pad_past_usize() {
+        Board::new(3, 3, BorderOpt::Empty).pad(0, usize::MAX, 0, 1);
+    }
+
+    #[test]
+    fn empty_board_margins() {
+        let board = Board::new(4, 3, BorderOpt::Empty);
+
+        assert_eq!(board.bounding_box(), None);
+        assert_eq!(board.margins(), (3, 4, 3, 4));
+    }
+}
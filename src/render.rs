@@ -0,0 +1,608 @@
+use std::io::{self, Write};
+
+use crate::{
+    board::Board,
+    cell::{Cell, ALIVE_GLYPH, DEAD_GLYPH},
+    error::ShapeMismatch,
+};
+
+/// The default emoji for a live cell in [`Board::to_emoji`]
+pub const EMOJI_ALIVE: &str = "⬛";
+/// The default emoji for a dead cell in [`Board::to_emoji`]
+pub const EMOJI_DEAD: &str = "⬜";
+
+/// How many generations ahead to look for the period shown by
+/// [`Board::render_with_status`], a limit that keeps finding it cheap
+/// enough to do every so often while streaming
+pub const STATUS_MAX_PERIOD: usize = 64;
+
+/// The RGB colors of the indices in [`Board::to_indexed_frame`]: white
+/// for dead cells at index 0, black for live cells at index 1
+///
+/// Ready to use as a GIF's global color table.
+pub const INDEXED_PALETTE: [[u8; 3]; 2] = [[255, 255, 255], [0, 0, 0]];
+
+/// What to draw for each cell when rendering a board
+///
+/// The default matches `Display`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderStyle {
+    /// Drawn for every live cell
+    pub alive: String,
+    /// Drawn for every dead cell
+    pub dead: String,
+}
+
+impl RenderStyle {
+    /// A style drawing `alive` and `dead` for each cell
+    pub fn new(alive: &str, dead: &str) -> Self {
+        RenderStyle {
+            alive: alive.to_string(),
+            dead: dead.to_string(),
+        }
+    }
+}
+
+impl Default for RenderStyle {
+    fn default() -> Self {
+        RenderStyle::new(ALIVE_GLYPH, DEAD_GLYPH)
+    }
+}
+
+impl Board {
+    /// Render the board exactly as `Display` does, but faster
+    ///
+    /// The output buffer is sized up front and each row is written with
+    /// plain string pushes instead of going through the formatter per
+    /// cell, which matters for very large boards.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_random(20, 10, BorderOpt::Empty, 0.3, 1);
+    /// assert_eq!(board.render_fast(), format!("{}", board));
+    /// ```
+    pub fn render_fast(&self) -> String {
+        self.render_with(ALIVE_GLYPH, DEAD_GLYPH)
+    }
+
+    /// Render the board using `alive` and `dead` for each cell, one line
+    /// per row
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert!(board.render_with("#", "_").starts_with("_____\n__#__\n"));
+    /// ```
+    pub fn render_with(&self, alive: &str, dead: &str) -> String {
+        let glyph_len = alive.len().max(dead.len());
+        let mut out = String::with_capacity(self.height * (self.width * glyph_len + 1));
+
+        if self.width == 0 {
+            return out;
+        }
+        self.cells.chunks(self.width).for_each(|row| {
+            row.iter().for_each(|&c| {
+                out.push_str(if c == Cell::Alive { alive } else { dead });
+            });
+            out.push('\n');
+        });
+
+        out
+    }
+
+    /// Render the board in `style`, followed by a status line with its
+    /// generation, population and `period`
+    ///
+    /// The footer reads `gen N | pop M | period P`, with `-` for a period
+    /// of `None`. Everything a viewer of a stream needs in one frame.
+    /// Finding the period means running the board ahead, so it's up to
+    /// the caller, e.g. with [`Board::detect_background_period`] within
+    /// [`STATUS_MAX_PERIOD`] generations, once the board has settled or
+    /// every so many frames rather than on every one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(1, 0, 1, 3, Cell::Alive);
+    /// board.advance_cycle();
+    /// let period = board.detect_background_period(STATUS_MAX_PERIOD);
+    ///
+    /// assert_eq!(
+    ///     board.render_with_status(&RenderStyle::new("#", "."), period),
+    ///     "...\n###\n...\ngen 1 | pop 3 | period 2\n"
+    /// );
+    /// ```
+    pub fn render_with_status(&self, style: &RenderStyle, period: Option<usize>) -> String {
+        let population = self.population();
+        let period = period.map_or_else(|| "-".to_string(), |p| p.to_string());
+
+        format!(
+            "{}gen {} | pop {} | period {}\n",
+            self.render_with(&style.alive, &style.dead),
+            self.generation().0,
+            population,
+            period
+        )
+    }
+
+    /// Render the board with one ASCII character per cell, `#` for alive
+    /// and `.` for dead
+    ///
+    /// Unlike the `Display` glyphs, which some terminals and fonts draw
+    /// at inconsistent widths, this lines up in any monospace font, e.g.
+    /// in Markdown code blocks.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 1, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// assert_eq!(board.to_ascii(), ".#.\n");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        self.render_with("#", ".")
+    }
+
+    /// Render the board like `Display`, with column numbers across the
+    /// top and row numbers down the left
+    ///
+    /// Makes it easy to read off a cell's `x` and `y`. Columns are
+    /// widened to keep at least one space between their numbers, with the
+    /// glyphs padded to match.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 2, BorderOpt::Empty);
+    /// board.set(2, 1, Cell::Alive);
+    /// assert_eq!(board.debug_grid(), "   0 1 2\n0 ░░░░░░\n1 ░░░░▓▓\n");
+    /// ```
+    pub fn debug_grid(&self) -> String {
+        let digits = |n: usize| n.max(1).saturating_sub(1).to_string().len();
+        let label_width = digits(self.height);
+        let column_width = (digits(self.width) + 1).max(ALIVE_GLYPH.chars().count());
+        let mut out = " ".repeat(label_width + 1);
+
+        (0..self.width).for_each(|x| out.push_str(&format!("{:>1$}", x, column_width)));
+        out.push('\n');
+        (0..self.height).for_each(|y| {
+            out.push_str(&format!("{:>1$} ", y, label_width));
+            (0..self.width).for_each(|x| {
+                out.push_str(&format!("{:>1$}", self.get(x, y).glyph(), column_width))
+            });
+            out.push('\n');
+        });
+
+        out
+    }
+
+    /// Render the board into a raw RGBA pixel buffer, ready to blit into
+    /// a framebuffer or texture
+    ///
+    /// Each cell becomes a `scale`×`scale` square of `alive` or `dead`
+    /// pixels. Returns the pixels, 4 bytes each in row-major order, along
+    /// with the image's width and height in pixels.
+    ///
+    /// # Panics:
+    ///
+    /// - If `scale` is 0
+    /// - If the image would have more bytes than a `usize` can count
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let (pixels, w, h) = board.to_rgba(4, [0, 0, 0, 255], [255; 4]);
+    /// assert_eq!((w, h), (20, 20));
+    /// assert_eq!(pixels.len(), 20 * 20 * 4);
+    /// ```
+    pub fn to_rgba(&self, scale: usize, alive: [u8; 4], dead: [u8; 4]) -> (Vec<u8>, usize, usize) {
+        if scale == 0 {
+            panic!("scale must be at least 1");
+        }
+        let (w, h, len) = self
+            .width
+            .checked_mul(scale)
+            .zip(self.height.checked_mul(scale))
+            .and_then(|(w, h)| Some((w, h, w.checked_mul(h)?.checked_mul(4)?)))
+            .unwrap_or_else(|| {
+                panic!(
+                    "a {}x{} board at scale {} has more pixels than fit in memory",
+                    self.width, self.height, scale
+                )
+            });
+        let mut pixels = Vec::with_capacity(len);
+
+        if self.width == 0 {
+            return (pixels, w, h);
+        }
+        self.cells.chunks(self.width).for_each(|row| {
+            let mut line = Vec::with_capacity(w * 4);
+            row.iter().for_each(|&c| {
+                let color = if c == Cell::Alive { alive } else { dead };
+                (0..scale).for_each(|_| line.extend_from_slice(&color));
+            });
+            (0..scale).for_each(|_| pixels.extend_from_slice(&line));
+        });
+
+        (pixels, w, h)
+    }
+
+    /// The board as palette indices, one byte per cell in row-major
+    /// order: 0 for dead and 1 for alive, matching [`INDEXED_PALETTE`]
+    ///
+    /// Indexed formats like GIF take frames in this shape, so a GIF
+    /// encoder can consume them directly without going through RGBA.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 1, BorderOpt::Empty);
+    /// board.set(1, 0, Cell::Alive);
+    /// assert_eq!(board.to_indexed_frame(), vec![0, 1, 0]);
+    /// ```
+    pub fn to_indexed_frame(&self) -> Vec<u8> {
+        self.cells
+            .iter()
+            .map(|&c| if c == Cell::Alive { 1 } else { 0 })
+            .collect()
+    }
+
+    /// Write the board to `w` in `style`, one line per row
+    ///
+    /// Only a single row is ever buffered, so even huge boards can be
+    /// streamed to a file or `stdout().lock()` without building the whole
+    /// rendering in memory first.
+    ///
+    /// # Errors:
+    ///
+    /// If writing to `w` fails
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let mut out = vec![];
+    ///
+    /// board.write_to(&mut out, &RenderStyle::new("#", "_")).unwrap();
+    /// assert!(out.starts_with(b"_____\n__#__\n"));
+    /// ```
+    pub fn write_to<W: Write>(&self, w: &mut W, style: &RenderStyle) -> io::Result<()> {
+        if self.width == 0 {
+            return Ok(());
+        }
+        let glyph_len = style.alive.len().max(style.dead.len());
+        let mut line = String::with_capacity(self.width * glyph_len + 1);
+
+        for row in self.cells.chunks(self.width) {
+            line.clear();
+            row.iter().for_each(|&c| {
+                line.push_str(if c == Cell::Alive {
+                    &style.alive
+                } else {
+                    &style.dead
+                });
+            });
+            line.push('\n');
+            w.write_all(line.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Render the board as emoji, for chat bots and social posts
+    ///
+    /// Pass [`EMOJI_ALIVE`] and [`EMOJI_DEAD`] for the default `⬛`/`⬜`
+    /// look. Each cell gets the whole of `alive` or `dead`, so emoji made
+    /// of several code points (variation selectors, skin tones, ZWJ
+    /// sequences) are never split. Note that how wide such emoji display
+    /// depends on the font, so pick two that render at the same width.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(2, 1, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    /// assert_eq!(board.to_emoji(EMOJI_ALIVE, EMOJI_DEAD), "⬛⬜\n");
+    /// ```
+    pub fn to_emoji(&self, alive: &str, dead: &str) -> String {
+        self.render_with(alive, dead)
+    }
+
+    /// Render the board with Unicode Braille patterns, each character
+    /// showing a 2×4 block of cells as raised dots
+    ///
+    /// Packs 8 cells into every character, so much larger boards fit in a
+    /// terminal. Blocks hanging off the right or bottom edge are padded
+    /// with dead cells, and a block without live cells is the blank
+    /// pattern `U+2800`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(2, 4, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 1, 4, Cell::Alive);
+    /// assert_eq!(board.to_braille(), "⡇\n");
+    /// ```
+    pub fn to_braille(&self) -> String {
+        // The dot each cell of a block raises, by row then column
+        const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+        let mut out = String::new();
+
+        (0..self.height).step_by(4).for_each(|by| {
+            (0..self.width).step_by(2).for_each(|bx| {
+                let dots: u32 = (0..4)
+                    .flat_map(|dy| (0..2).map(move |dx| (dx, dy)))
+                    .filter(|&(dx, dy)| bx + dx < self.width && by + dy < self.height)
+                    .filter(|&(dx, dy)| self.get(bx + dx, by + dy) == Cell::Alive)
+                    .map(|(dx, dy)| DOTS[dy][dx])
+                    .sum();
+                out.push(std::char::from_u32(0x2800 + dots).unwrap());
+            });
+            out.push('\n');
+        });
+
+        out
+    }
+
+    /// Per-cell intensities, in row-major order, blending this board
+    /// with recent generations for a "motion blur" look
+    ///
+    /// `weights[0]` is the weight of this board and `weights[i + 1]` the
+    /// weight of `history[i]`, usually most recent first with decreasing
+    /// weights. A cell's intensity is the sum of the weights of the
+    /// boards it's alive on, so weights summing to 1.0 keep intensities
+    /// between 0.0 and 1.0. Boards without a weight are ignored.
+    ///
+    /// # Errors:
+    ///
+    /// If any board in `history` has different dimensions.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 1, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    /// let previous = board.clone();
+    /// board.set(0, 0, Cell::Dead);
+    /// board.set(1, 0, Cell::Alive);
+    ///
+    /// let intensity = board.ghost_render(&[previous], &[0.75, 0.25]).unwrap();
+    /// assert_eq!(intensity, vec![0.25, 0.75, 0.0]);
+    /// ```
+    pub fn ghost_render(
+        &self,
+        history: &[Board],
+        weights: &[f64],
+    ) -> Result<Vec<f64>, ShapeMismatch> {
+        history
+            .iter()
+            .try_for_each(|board| self.ensure_same_dims(board))?;
+        let mut intensity = vec![0.0; self.cells.len()];
+
+        std::iter::once(self)
+            .chain(history.iter())
+            .zip(weights.iter())
+            .for_each(|(board, &weight)| {
+                board
+                    .cells
+                    .iter()
+                    .zip(intensity.iter_mut())
+                    .filter(|(&c, _)| c == Cell::Alive)
+                    .for_each(|(_, i)| *i += weight);
+            });
+
+        Ok(intensity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    fn get_2x2_board() -> Board {
+        let mut board = Board::new(2, 2, BorderOpt::Empty);
+
+        board.set(0, 0, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+
+        board
+    }
+
+    #[test]
+    fn ghost_render_single_board() {
+        let board = get_2x2_board();
+
+        assert_eq!(
+            board.ghost_render(&[], &[1.0]),
+            Ok(vec![1.0, 0.0, 0.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn ghost_render_fades_history() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.fill_rect(1, 0, 1, 3, Cell::Alive);
+        let history = vec![board.clone()];
+        board.advance_cycle();
+
+        let intensity = board.ghost_render(&history, &[1.0, 0.5]).unwrap();
+        assert_eq!(intensity, vec![0.0, 0.5, 0.0, 1.0, 1.5, 1.0, 0.0, 0.5, 0.0]);
+
+        let mismatched = board.ghost_render(&[Board::new(2, 2, BorderOpt::Empty)], &[1.0]);
+        assert!(mismatched.is_err());
+    }
+
+    #[test]
+    fn render_fast_matches_display() {
+        let boards = [
+            get_2x2_board(),
+            Board::new_random(37, 23, BorderOpt::Empty, 0.4, 9),
+        ];
+
+        boards
+            .iter()
+            .for_each(|board| assert_eq!(board.render_fast(), format!("{}", board)));
+    }
+
+    #[test]
+    fn write_to_matches_display() {
+        let board = Board::new_random(37, 23, BorderOpt::Empty, 0.4, 2);
+        let mut out: Vec<u8> = vec![];
+
+        board.write_to(&mut out, &RenderStyle::default()).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}", board));
+    }
+
+    #[test]
+    fn glider_ascii() {
+        let board = Board::new_from_file("./tests/test-boards/glider.txt");
+
+        assert_eq!(
+            board.to_ascii(),
+            ".....\n\
+             ..#..\n\
+             ...#.\n\
+             .###.\n\
+             .....\n"
+        );
+    }
+
+    #[test]
+    fn debug_grid_labels() {
+        let board = Board::new_from_file("./tests/test-boards/glider.txt");
+        let grid = board.debug_grid();
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines[0], "   0 1 2 3 4");
+        assert_eq!(lines[2], "1 ░░░░▓▓░░░░");
+        assert_eq!(lines.len(), 6);
+
+        let wide = Board::new(12, 11, BorderOpt::Empty).debug_grid();
+        let lines: Vec<&str> = wide.lines().collect();
+        assert!(lines[0].starts_with("     0  1  2"));
+        assert!(lines[0].ends_with("  9 10 11"));
+        assert!(lines[1].starts_with(" 0  ░░ ░░"));
+        assert!(lines[11].starts_with("10  ░░ ░░"));
+    }
+
+    #[test]
+    fn rgba_2x2() {
+        let board = get_2x2_board();
+        let (black, white) = ([0, 0, 0, 255], [255, 255, 255, 255]);
+
+        let (pixels, w, h) = board.to_rgba(1, black, white);
+        assert_eq!((w, h), (2, 2));
+        assert_eq!(pixels, [black, white, white, black].concat());
+
+        let (pixels, w, h) = board.to_rgba(2, black, white);
+        assert_eq!((w, h), (4, 4));
+        assert_eq!(
+            &pixels[..16],
+            [black, black, white, white].concat().as_slice()
+        );
+        assert_eq!(
+            &pixels[48..],
+            [white, white, black, black].concat().as_slice()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "more pixels than fit in memory")]
+    fn rgba_too_large() {
+        get_2x2_board().to_rgba(usize::MAX / 2, [0; 4], [255; 4]);
+    }
+
+    #[test]
+    fn status_footer() {
+        let mut board = Board::new_random(16, 16, BorderOpt::Empty, 0.4, 12);
+        board.advance_n_cycles(3);
+        let population = board.population();
+
+        let rendered = board.render_with_status(&RenderStyle::new("#", "."), None);
+        let footer = rendered.lines().last().unwrap();
+        assert!(rendered.starts_with(&board.to_ascii()));
+        assert_eq!(footer, format!("gen 3 | pop {} | period -", population));
+
+        let mut block = Board::new(4, 4, BorderOpt::Empty);
+        block.fill_rect(1, 1, 2, 2, Cell::Alive);
+        let period = block.detect_background_period(STATUS_MAX_PERIOD);
+        assert!(block
+            .render_with_status(&RenderStyle::default(), period)
+            .ends_with("gen 0 | pop 4 | period 1\n"));
+    }
+
+    #[test]
+    fn indexed_2x2() {
+        let board = get_2x2_board();
+        let frame = board.to_indexed_frame();
+
+        assert_eq!(frame, [1, 0, 0, 1]);
+        // Indices agree with the palette's colors for RGBA rendering
+        let [r, g, b] = INDEXED_PALETTE[frame[0] as usize];
+        let (pixels, _, _) = board.to_rgba(1, [0, 0, 0, 255], [255; 4]);
+        assert_eq!(pixels[..4], [r, g, b, 255]);
+    }
+
+    #[test]
+    fn braille_blocks() {
+        // ▓▓░░
+        // ░░▓▓
+        // ▓▓▓▓
+        // ░░░░
+        let mut board = Board::new(2, 4, BorderOpt::Empty);
+        board.set(0, 0, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+        board.fill_rect(0, 2, 2, 1, Cell::Alive);
+        // Dots 1, 5, 3 and 6
+        assert_eq!(board.to_braille(), "\u{2835}\n");
+
+        let mut full = Board::new(5, 5, BorderOpt::Empty);
+        full.fill_rect(0, 0, 5, 5, Cell::Alive);
+        assert_eq!(full.to_braille(), "⣿⣿⡇\n⠉⠉⠁\n");
+        assert_eq!(
+            Board::new(3, 1, BorderOpt::Empty).to_braille(),
+            "\u{2800}\u{2800}\n"
+        );
+    }
+
+    #[test]
+    fn emoji_2x2() {
+        let board = get_2x2_board();
+
+        assert_eq!(board.to_emoji(EMOJI_ALIVE, EMOJI_DEAD), "⬛⬜\n⬜⬛\n");
+        // Heart with a variation selector and a ZWJ family stay whole
+        assert_eq!(
+            board.to_emoji("❤\u{fe0f}", "👩\u{200d}👧"),
+            "❤\u{fe0f}👩\u{200d}👧\n👩\u{200d}👧❤\u{fe0f}\n"
+        );
+    }
+}
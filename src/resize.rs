@@ -0,0 +1,481 @@
+use std::mem;
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::DimensionsTooLarge,
+};
+
+/// How [`Board::thumbnail_with`] decides whether a block of cells
+/// becomes a live cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownscaleMode {
+    /// Alive if any cell in the block is, so sparse patterns don't vanish
+    Any,
+    /// Alive if more than half of the block's cells are
+    Majority,
+    /// Alive if the block is at least as dense as the board as a whole,
+    /// which keeps the thumbnail's density close to the board's
+    Average,
+}
+
+impl Board {
+    /// A scaled-down copy of the board that fits within `max_w`×`max_h`
+    ///
+    /// The board is split into square blocks, as small as possible while
+    /// still fitting, and each block becomes one cell of the thumbnail.
+    /// A block is alive if any of its cells are, so small patterns don't
+    /// vanish when shrunk. A board that already fits is copied at full
+    /// size. The border mode is kept.
+    ///
+    /// # Panics:
+    ///
+    /// If `max_w` or `max_h` is 0.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+    /// let thumbnail = board.thumbnail(12, 12);
+    /// assert!(thumbnail.width <= 12 && thumbnail.height <= 12);
+    /// ```
+    pub fn thumbnail(&self, max_w: usize, max_h: usize) -> Board {
+        self.thumbnail_with(max_w, max_h, DownscaleMode::Any)
+    }
+
+    /// Like [`Board::thumbnail`], but with `mode` deciding which blocks
+    /// become live cells
+    ///
+    /// Blocks cut short by the edge of the board are judged on the cells
+    /// they have.
+    ///
+    /// # Panics:
+    ///
+    /// If `max_w` or `max_h` is 0.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 2, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 3, 2, Cell::Alive);
+    ///
+    /// let thumbnail = board.thumbnail_with(2, 1, DownscaleMode::Majority);
+    /// assert_eq!(thumbnail.to_ascii(), "#.\n");
+    /// ```
+    pub fn thumbnail_with(&self, max_w: usize, max_h: usize, mode: DownscaleMode) -> Board {
+        if max_w == 0 || max_h == 0 {
+            panic!("Thumbnail bounds must be non-zero, got {}x{}", max_w, max_h);
+        }
+        let scale = self
+            .width
+            .div_ceil(max_w)
+            .max(self.height.div_ceil(max_h))
+            .max(1);
+        let (w, h) = (self.width.div_ceil(scale), self.height.div_ceil(scale));
+        let live = self.population();
+        let cells = (0..h)
+            .flat_map(|ty| (0..w).map(move |tx| (tx, ty)))
+            .map(|(tx, ty)| {
+                let xs = tx * scale..((tx + 1) * scale).min(self.width);
+                let ys = ty * scale..((ty + 1) * scale).min(self.height);
+                let block = xs.len() * ys.len();
+                let block_live = ys
+                    .flat_map(|y| xs.clone().map(move |x| (x, y)))
+                    .filter(|&(x, y)| self.get(x, y) == Cell::Alive)
+                    .count();
+                let alive = match mode {
+                    DownscaleMode::Any => block_live > 0,
+                    DownscaleMode::Majority => 2 * block_live > block,
+                    // block_live / block >= live / cells, without division
+                    DownscaleMode::Average => {
+                        block_live > 0 && block_live * self.cells.len() >= live * block
+                    }
+                };
+                if alive {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        Board::from_cells(w, h, self.border.clone(), cells)
+    }
+
+    /// A copy of the board resampled to `new_width`×`new_height` by
+    /// nearest neighbor
+    ///
+    /// Each cell takes the state of the source cell under its center, so
+    /// the board can be stretched or squashed to any size, not just by
+    /// whole factors. Shrinking drops cells rather than combining them;
+    /// use [`Board::thumbnail_with`] for that. An empty source gives a
+    /// dead board. The border mode is kept.
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::new`], if the board would have too many cells
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(2, 1, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    ///
+    /// assert_eq!(board.resample(5, 2).to_ascii(), "##...\n##...\n");
+    /// ```
+    pub fn resample(&self, new_width: usize, new_height: usize) -> Board {
+        let mut board = Board::new(new_width, new_height, self.border.clone());
+        if self.width == 0 || self.height == 0 {
+            return board;
+        }
+        // The source cell under the center of cell `t` of `target`
+        let source = |t: usize, target: usize, source: usize| (2 * t + 1) * source / (2 * target);
+
+        board.seed_with(|x, y| {
+            self.get(
+                source(x, new_width, self.width),
+                source(y, new_height, self.height),
+            )
+        });
+
+        board
+    }
+
+    /// Grow the board if any live cell is within `margin` cells of an
+    /// edge, recentering the live cells with room to spare
+    ///
+    /// The board grows so that at least `2 * margin` dead rows or columns
+    /// surround the live cells on every side, and never shrinks. Frozen
+    /// cells move along with the content and the neighbor radius, rule,
+    /// backend, generation count and history carry over, though
+    /// generations from before the board grew no longer fit, so
+    /// [`Board::step_back`] can't return to them. A board with no live
+    /// cells is left alone.
+    ///
+    /// # Panics:
+    ///
+    /// If the grown board would have more cells than a `usize` can count
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    ///
+    /// board.grow_to_fit(1);
+    /// assert_eq!((board.width, board.height), (5, 5));
+    /// assert_eq!(board.margins(), (2, 2, 2, 2));
+    /// ```
+    pub fn grow_to_fit(&mut self, margin: usize) {
+        let (top, right, bottom, left) = self.margins();
+        let (bx, by, bw, bh) = match self.bounding_box() {
+            Some(bounds) if top.min(right).min(bottom).min(left) < margin => bounds,
+            _ => return,
+        };
+        let grown = |len: usize, current: usize| {
+            let room = margin.checked_mul(2)?;
+            Some(current.max(len.checked_add(room.checked_mul(2)?)?))
+        };
+        let (width, height) = match (grown(bw, self.width), grown(bh, self.height)) {
+            (Some(width), Some(height)) => (width, height),
+            (width, height) => panic!(
+                "{}",
+                DimensionsTooLarge {
+                    width: width.unwrap_or(usize::MAX),
+                    height: height.unwrap_or(usize::MAX),
+                }
+            ),
+        };
+        let (dx, dy) = (
+            ((width - bw) / 2) as i64 - bx as i64,
+            ((height - bh) / 2) as i64 - by as i64,
+        );
+
+        let mut grown = self.pad(0, width - self.width, height - self.height, 0);
+        grown.shift(dx, dy);
+        grown.history = mem::take(&mut self.history);
+        *self = grown;
+    }
+
+    /// Shrink the board to the live cells' bounding box plus `margin`
+    /// dead rows or columns on every side, where the board has them
+    ///
+    /// The opposite of [`Board::grow_to_fit`], for keeping the working
+    /// set of a long run small once parts of the board have died down.
+    /// The board never grows. Frozen cells and rule map entries inside
+    /// the new bounds are kept, as are the neighbor radius, rule, border,
+    /// backend and generation count, but the history is lost. A board
+    /// with no live cells is left alone.
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::set_border`], if the board is `Loop` and the cropped
+    /// board is too small for its neighbor radius
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(20, 20, BorderOpt::Empty);
+    /// board.fill_rect(5, 5, 2, 2, Cell::Alive);
+    ///
+    /// board.crop_to_active(1);
+    /// assert_eq!((board.width, board.height), (4, 4));
+    /// assert_eq!(board.margins(), (1, 1, 1, 1));
+    /// ```
+    pub fn crop_to_active(&mut self, margin: usize) {
+        let (bx, by, bw, bh) = match self.bounding_box() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+        let (x0, y0) = (bx.saturating_sub(margin), by.saturating_sub(margin));
+        let x1 = (bx + bw).saturating_add(margin).min(self.width);
+        let y1 = (by + bh).saturating_add(margin).min(self.height);
+        let (width, height) = (x1 - x0, y1 - y0);
+        if (width, height) == (self.width, self.height) {
+            return;
+        }
+        let rect = (x0, y0, width, height);
+
+        let cells = crop(&self.cells, self.width, rect);
+        let mut cropped = Board::from_cells(width, height, BorderOpt::Empty, cells);
+        cropped.set_neighbor_radius(self.neighbor_radius());
+        cropped.set_border(self.border.clone());
+        cropped.set_rule(self.rule().clone());
+        cropped.set_backend(self.backend());
+        cropped.skip_generations(self.generation().into());
+        cropped.frozen = crop(&self.frozen, self.width, rect);
+        cropped.rule_map = self.rule_map.take().map(|mut map| {
+            map.ids = crop(&map.ids, self.width, rect);
+            map
+        });
+
+        *self = cropped;
+    }
+
+    /// Advance board state by `n` cycles, growing the board whenever live
+    /// cells come within `margin` of an edge
+    ///
+    /// Calls [`Board::grow_to_fit`] before every cycle, so patterns run as
+    /// if on an unbounded field instead of deforming at the edges. The
+    /// board can grow without limit, e.g. when running a glider gun.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// board.advance_n_cycles_autogrow(40, 2);
+    /// assert!(board.width > 5 && board.height > 5);
+    ///
+    /// let (_, _, w, h) = board.bounding_box().unwrap();
+    /// assert_eq!((w, h), (3, 3));
+    /// ```
+    pub fn advance_n_cycles_autogrow(&mut self, n: usize, margin: usize) {
+        (0..n).for_each(|_| {
+            self.grow_to_fit(margin);
+            self.advance_cycle();
+        });
+    }
+}
+
+// The `w`×`h` rectangle at `x`, `y` of the row-major `v`, whose rows
+// are `stride` long
+fn crop<T: Copy>(v: &[T], stride: usize, (x, y, w, h): (usize, usize, usize, usize)) -> Vec<T> {
+    (y..y + h)
+        .flat_map(|row| v[row * stride + x..row * stride + x + w].iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{Backend, BorderOpt, Generation};
+
+    #[test]
+    fn resample_blinker() {
+        let mut blinker = Board::new(3, 3, BorderOpt::Loop);
+        blinker.fill_rect(0, 1, 3, 1, Cell::Alive);
+
+        let doubled = blinker.resample(6, 6);
+        assert_eq!(
+            doubled.to_ascii(),
+            "......\n......\n######\n######\n......\n......\n"
+        );
+        assert_eq!(doubled.border, BorderOpt::Loop);
+
+        // Any size works, and back down is lossless here
+        assert_eq!(doubled.resample(3, 3), blinker);
+        assert_eq!(
+            blinker.resample(4, 5).to_ascii(),
+            "....\n....\n####\n....\n....\n"
+        );
+        assert_eq!(
+            Board::new(0, 0, BorderOpt::Empty).resample(2, 2).to_ascii(),
+            "..\n..\n"
+        );
+    }
+
+    #[test]
+    fn glider_never_touches_edge() {
+        let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+        let mut board = glider.clone();
+
+        (0..50).for_each(|_| {
+            board.advance_n_cycles_autogrow(4, 2);
+            let (top, right, bottom, left) = board.margins();
+            assert!(top.min(right).min(bottom).min(left) >= 1);
+        });
+
+        assert!(board.width > glider.width && board.height > glider.height);
+        assert_eq!(board.generation(), Generation(200));
+        assert_eq!(
+            board.extract_pattern(0, 0, board.width, board.height),
+            glider.extract_pattern(0, 0, glider.width, glider.height)
+        );
+    }
+
+    #[test]
+    fn grow_keeps_settings_and_frozen_cells() {
+        let mut board = Board::new(4, 4, BorderOpt::Loop);
+        board.set(3, 3, Cell::Alive);
+        board.freeze(2, 3);
+        board.set_backend(Backend::Bitboard);
+
+        board.grow_to_fit(1);
+
+        assert_eq!((board.width, board.height), (5, 5));
+        assert_eq!(board.border, BorderOpt::Loop);
+        assert_eq!(board.backend(), Backend::Bitboard);
+        assert_eq!(board.get(2, 2), Cell::Alive);
+        assert!(board.is_frozen(1, 2));
+        assert!(board.validate().is_ok());
+    }
+
+    #[test]
+    fn grow_carries_history_over() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.fill_rect(0, 1, 3, 1, Cell::Alive);
+        board.set_history_capacity(4);
+        board.advance_cycle();
+
+        board.grow_to_fit(1);
+        assert_eq!(board.history_capacity(), 4);
+        assert_eq!(board.history_len(), 1);
+        // The recorded generation is smaller than the grown board
+        assert!(!board.step_back());
+        board.advance_cycle();
+        assert!(board.step_back());
+    }
+
+    #[test]
+    #[should_panic(expected = "more cells than fit in memory")]
+    fn grow_past_usize() {
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.set(1, 1, Cell::Alive);
+        board.grow_to_fit(usize::MAX / 2);
+    }
+
+    #[test]
+    fn crop_blinker_out_of_large_board() {
+        let mut board = Board::new(50, 50, BorderOpt::Empty);
+        board.fill_rect(20, 30, 3, 1, Cell::Alive);
+        board.freeze(21, 30);
+        board.freeze(0, 0);
+        board.advance_n_cycles(2);
+
+        board.crop_to_active(2);
+
+        assert_eq!((board.width, board.height), (7, 5));
+        assert_eq!(
+            board.to_ascii(),
+            ".......\n.......\n..###..\n.......\n.......\n"
+        );
+        assert_eq!(board.generation(), Generation(2));
+        assert!(board.is_frozen(3, 2));
+        assert!(board.validate().is_ok());
+        // Still blinking around its frozen center
+        board.advance_cycle();
+        assert_eq!(
+            board.to_ascii(),
+            ".......\n...#...\n...#...\n...#...\n.......\n"
+        );
+
+        // Near an edge the margin is whatever is left
+        let mut corner = Board::new(10, 10, BorderOpt::Empty);
+        corner.set(0, 1, Cell::Alive);
+        corner.crop_to_active(3);
+        assert_eq!((corner.width, corner.height), (4, 5));
+
+        let mut empty = Board::new(10, 10, BorderOpt::Empty);
+        empty.crop_to_active(1);
+        assert_eq!((empty.width, empty.height), (10, 10));
+    }
+
+    #[test]
+    fn downscale_modes_on_half_filled_block() {
+        // The left 2×2 block is half filled, the right one empty
+        let mut board = Board::new(4, 2, BorderOpt::Empty);
+        board.set(0, 0, Cell::Alive);
+        board.set(1, 1, Cell::Alive);
+
+        let thumbnail = |board: &Board, mode| board.thumbnail_with(2, 1, mode).to_ascii();
+        assert_eq!(thumbnail(&board, DownscaleMode::Any), "#.\n");
+        assert_eq!(thumbnail(&board, DownscaleMode::Majority), "..\n");
+        // Denser than the board's overall quarter
+        assert_eq!(thumbnail(&board, DownscaleMode::Average), "#.\n");
+
+        // Both blocks half filled, exactly as dense as the board
+        board.set(2, 1, Cell::Alive);
+        board.set(3, 1, Cell::Alive);
+        assert_eq!(thumbnail(&board, DownscaleMode::Majority), "..\n");
+        assert_eq!(thumbnail(&board, DownscaleMode::Average), "##\n");
+
+        // A block denser than the rest stands out, a sparser one doesn't
+        board.set(1, 0, Cell::Alive);
+        assert_eq!(thumbnail(&board, DownscaleMode::Majority), "#.\n");
+        assert_eq!(thumbnail(&board, DownscaleMode::Average), "#.\n");
+        assert_eq!(thumbnail(&board, DownscaleMode::Any), "##\n");
+    }
+
+    #[test]
+    fn thumbnail_of_large_board() {
+        let mut board = Board::new(100, 100, BorderOpt::Empty);
+
+        (40..50).for_each(|y| (60..70).for_each(|x| board.set(x, y, Cell::Alive)));
+
+        let thumbnail = board.thumbnail(10, 10);
+
+        assert_eq!((thumbnail.width, thumbnail.height), (10, 10));
+        assert_eq!(thumbnail.bounding_box(), Some((6, 4, 1, 1)));
+    }
+
+    #[test]
+    fn thumbnail_keeps_small_boards() {
+        let board = Board::new_from_file("./tests/test-boards/glider.txt");
+
+        assert_eq!(board.thumbnail(10, 10), board);
+    }
+
+    #[test]
+    fn thumbnail_rounds_partial_blocks_up() {
+        let mut board = Board::new(7, 3, BorderOpt::Empty);
+        board.set(6, 2, Cell::Alive);
+
+        let thumbnail = board.thumbnail(3, 3);
+
+        assert_eq!((thumbnail.width, thumbnail.height), (3, 1));
+        assert_eq!(thumbnail.get(2, 0), Cell::Alive);
+    }
+}
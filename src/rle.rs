@@ -0,0 +1,528 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::Path,
+};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::ParseError,
+    rule::Rule,
+};
+
+// Most RLE readers expect lines no longer than this
+const RLE_LINE_LIMIT: usize = 70;
+/// Largest pattern, in cells, [`Board::from_rle`] and
+/// [`Board::from_row_rle`] will create
+///
+/// Sizes and run lengths come straight from the input, so without a
+/// limit a single line could ask for any amount of memory.
+pub const RLE_MAX_CELLS: usize = 1 << 26;
+
+impl Board {
+    /// Initialize new board from a pattern in run length encoded (RLE)
+    /// format
+    ///
+    /// # Format:
+    ///
+    /// Lines starting with `#` are comments. The first other line is the
+    /// header, `x = <width>, y = <height>`, optionally followed by
+    /// `rule = <rule>`, which the board then follows; without one it
+    /// follows Conway's rules. The pattern follows as runs of `b` (dead)
+    /// and `o` (alive) cells, with `$` ending a row and `!` ending the
+    /// pattern. Each of these may be preceded by a count; rows may stop
+    /// short, leaving the rest of the row dead.
+    ///
+    /// The rule may be in `B.../S...` or `S.../B...` notation (`23/3` is
+    /// Conway's), and a `:T...` topology suffix is skipped. Rules a
+    /// [`Rule`] can't represent, such as Hensel notation (`B2-a/S12`) or
+    /// names like `Life`, are ignored, leaving Conway's rules.
+    ///
+    /// # Errors:
+    ///
+    /// - If the header is missing or malformed
+    /// - If the header asks for more than [`RLE_MAX_CELLS`] cells
+    /// - If the pattern contains any other character or doesn't fit the
+    ///   size given in the header
+    ///
+    /// Reported rows are line numbers in `input`, starting at zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let rle = "#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n";
+    /// let board = Board::from_rle(rle, BorderOpt::Empty).unwrap();
+    /// assert_eq!(board.get(1, 0), Cell::Alive);
+    /// assert_eq!(board.get(0, 2), Cell::Alive);
+    /// ```
+    pub fn from_rle(input: &str, border: BorderOpt) -> Result<Self, ParseError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim_start().starts_with('#') && !l.trim().is_empty());
+        let (header_row, header) = lines.next().ok_or(ParseError {
+            row: 0,
+            message: "missing header".to_string(),
+        })?;
+        let (width, height, rule) = Self::parse_rle_header(header).ok_or_else(|| ParseError {
+            row: header_row,
+            message: format!("invalid header {:?}", header),
+        })?;
+        let rule = rule.and_then(Self::parse_rle_rule);
+        // An empty pattern is still a valid, if pointless, file
+        let mut board = if width == 0 || height == 0 {
+            Board::new(width, height, border)
+        } else {
+            Board::new_checked_with_limit(width, height, border, RLE_MAX_CELLS).map_err(|e| {
+                ParseError {
+                    row: header_row,
+                    message: e.to_string(),
+                }
+            })?
+        };
+        if let Some(rule) = rule {
+            board.set_rule(rule);
+        }
+        let (mut x, mut y): (usize, usize) = (0, 0);
+        let too_large = |row: usize| ParseError {
+            row,
+            message: format!("pattern is larger than {}x{}", width, height),
+        };
+
+        'lines: for (row, line) in lines {
+            let mut count = String::new();
+            for c in line.chars().filter(|c| !c.is_whitespace()) {
+                if c.is_ascii_digit() {
+                    count.push(c);
+                    continue;
+                }
+                let run: usize = if count.is_empty() {
+                    1
+                } else {
+                    count.parse().map_err(|_| ParseError {
+                        row,
+                        message: format!("run length {} is too large", count),
+                    })?
+                };
+                count.clear();
+
+                match c {
+                    'b' | 'o' => {
+                        let end = x
+                            .checked_add(run)
+                            .filter(|&end| end <= width && y < height)
+                            .ok_or_else(|| too_large(row))?;
+                        if c == 'o' {
+                            (x..end).for_each(|cx| board.set(cx, y, Cell::Alive));
+                        }
+                        x = end;
+                    }
+                    '$' => {
+                        x = 0;
+                        y = y.checked_add(run).ok_or_else(|| too_large(row))?;
+                    }
+                    '!' => break 'lines,
+                    _ => {
+                        return Err(ParseError {
+                            row,
+                            message: format!("unexpected {:?}", c),
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(board)
+    }
+
+    /// Initialize new board from rows of runs, one line per row, e.g.
+    /// `3_2#5_` for 3 dead, 2 alive and 5 dead cells
+    ///
+    /// A simpler alternative to [`Board::from_rle`] that's easier to
+    /// write by hand. `_` is dead and `#` alive, like in the plain board
+    /// files, and a run without a count is a single cell. Every row must
+    /// add up to the same width. Whitespace and blank lines are ignored.
+    ///
+    /// # Errors:
+    ///
+    /// - If a row has a different width than the first
+    /// - If a row contains any other character or ends in a count
+    /// - If the board would have more than [`RLE_MAX_CELLS`] cells
+    ///
+    /// Reported rows count only non-blank lines, starting at zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::from_row_rle("2_#\n3#\n", BorderOpt::Empty).unwrap();
+    /// assert_eq!(board.to_ascii(), "..#\n###\n");
+    /// ```
+    pub fn from_row_rle(input: &str, border: BorderOpt) -> Result<Self, ParseError> {
+        let mut cells: Vec<Cell> = vec![];
+        let (mut width, mut height) = (0, 0);
+
+        for (row, line) in input.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+            let start = cells.len();
+            let mut count = String::new();
+            for c in line.chars().filter(|c| !c.is_whitespace()) {
+                if c.is_ascii_digit() {
+                    count.push(c);
+                    continue;
+                }
+                let run: usize = if count.is_empty() {
+                    1
+                } else {
+                    count.parse().map_err(|_| ParseError {
+                        row,
+                        message: format!("run length {} is too large", count),
+                    })?
+                };
+                count.clear();
+
+                let cell = match c {
+                    '_' => Cell::Dead,
+                    '#' => Cell::Alive,
+                    _ => {
+                        return Err(ParseError {
+                            row,
+                            message: format!("unexpected {:?}", c),
+                        })
+                    }
+                };
+                // Past the first row the width is known; before it, the
+                // cell limit keeps a huge count from allocating
+                let max_len = if row == 0 { RLE_MAX_CELLS } else { width };
+                let len = (cells.len() - start)
+                    .checked_add(run)
+                    .filter(|&len| len <= max_len && start + len <= RLE_MAX_CELLS)
+                    .ok_or_else(|| ParseError {
+                        row,
+                        message: if row == 0 {
+                            format!("more than {} cells", RLE_MAX_CELLS)
+                        } else {
+                            format!("more than {} columns", width)
+                        },
+                    })?;
+                cells.resize(start + len, cell);
+            }
+            if !count.is_empty() {
+                return Err(ParseError {
+                    row,
+                    message: format!("run length {} has no cell", count),
+                });
+            }
+
+            let len = cells.len() - start;
+            width = if row == 0 { len } else { width };
+            if len != width {
+                return Err(ParseError {
+                    row,
+                    message: format!("{} columns, expected {}", len, width),
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Board::from_cells(width, height, border, cells))
+    }
+
+    /// Encode the board in run length encoded (RLE) format, with a
+    /// `#N <name>` comment line if `name` is given
+    ///
+    /// The header carries the board's rule, unless it has counts above 8
+    /// that don't fit `B.../S...` notation (see
+    /// [`Rule::has_digit_notation`]); such a rule is left out and reads
+    /// back as Conway's. The border mode, neighbor radius and any rule map
+    /// aren't part of the format and are lost.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(
+    ///     board.to_rle(Some("Glider")),
+    ///     "#N Glider\nx = 5, y = 5, rule = B3/S23\n$2bo$3bo$b3o!\n"
+    /// );
+    /// ```
+    pub fn to_rle(&self, name: Option<&str>) -> String {
+        let mut tokens: Vec<String> = vec![];
+        let mut pending_rows = 0;
+        let token = |run: usize, tag: char| match run {
+            1 => tag.to_string(),
+            _ => format!("{}{}", run, tag),
+        };
+
+        self.cells.chunks(self.width.max(1)).for_each(|row| {
+            let mut runs: Vec<(usize, Cell)> = vec![];
+            row.iter().for_each(|&c| match runs.last_mut() {
+                Some((run, cell)) if *cell == c => *run += 1,
+                _ => runs.push((1, c)),
+            });
+            if runs.last().map(|&(_, c)| c) == Some(Cell::Dead) {
+                runs.pop();
+            }
+
+            if !runs.is_empty() {
+                if pending_rows > 0 {
+                    tokens.push(token(pending_rows, '$'));
+                    pending_rows = 0;
+                }
+                runs.iter().for_each(|&(run, c)| {
+                    tokens.push(token(run, if c == Cell::Alive { 'o' } else { 'b' }))
+                });
+            }
+            pending_rows += 1;
+        });
+        tokens.push("!".to_string());
+
+        let mut rle = String::new();
+        if let Some(name) = name {
+            rle.push_str(&format!("#N {}\n", name));
+        }
+        rle.push_str(&format!("x = {}, y = {}", self.width, self.height));
+        if self.rule().has_digit_notation() {
+            rle.push_str(&format!(", rule = {}", self.rule()));
+        }
+        rle.push('\n');
+        let mut line_len = 0;
+        tokens.iter().for_each(|t| {
+            if line_len + t.len() > RLE_LINE_LIMIT {
+                rle.push('\n');
+                line_len = 0;
+            }
+            rle.push_str(t);
+            line_len += t.len();
+        });
+        rle.push('\n');
+
+        rle
+    }
+
+    /// Initialize new board from the RLE file at `path`, see
+    /// [`Board::from_rle`]
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be read or isn't valid RLE
+    pub fn from_rle_file<P: AsRef<Path>>(path: P, border: BorderOpt) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+
+        Self::from_rle(&contents, border).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Write the board to an RLE file at `path`, see [`Board::to_rle`]
+    ///
+    /// # Errors:
+    ///
+    /// If the file can't be written
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let path = std::env::temp_dir().join("yagoll-rle-doc.rle");
+    ///
+    /// board.to_rle_file(&path, Some("Glider")).unwrap();
+    /// assert_eq!(Board::from_rle_file(&path, BorderOpt::Empty).unwrap(), board);
+    /// ```
+    pub fn to_rle_file<P: AsRef<Path>>(&self, path: P, name: Option<&str>) -> io::Result<()> {
+        fs::write(path, self.to_rle(name))
+    }
+
+    // The width, height and rule, if any, from an RLE header
+    fn parse_rle_header(header: &str) -> Option<(usize, usize, Option<&str>)> {
+        let mut fields = header.split(',').map(|f| {
+            f.split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+        });
+        let (width, height) = match (fields.next()??, fields.next()??) {
+            (("x", w), ("y", h)) => (w.parse().ok()?, h.parse().ok()?),
+            _ => return None,
+        };
+        let rule = match fields.next() {
+            Some(Some(("rule", rule))) => Some(rule),
+            Some(_) => return None,
+            None => None,
+        };
+
+        Some((width, height, rule))
+    }
+
+    // A header rule in B/S or S/B notation, without its topology suffix
+    fn parse_rle_rule(rule: &str) -> Option<Rule> {
+        let rule = rule.split(':').next()?.trim();
+        if let Ok(rule) = Rule::parse(rule) {
+            return Some(rule);
+        }
+        let (survive, born) = rule.split_once('/')?;
+        let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if !digits(survive) || !digits(born) {
+            return None;
+        }
+
+        Rule::parse(&format!("B{}/S{}", born, survive)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn glider_gun_round_trip() {
+        let gun = Board::new_from_file("./tests/test-boards/glider-gun.txt");
+        let path = std::env::temp_dir().join("yagoll-rle-gun.rle");
+
+        gun.to_rle_file(&path, Some("Gosper glider gun")).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let read = Board::from_rle_file(&path, BorderOpt::Empty).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(contents.starts_with("#N Gosper glider gun\nx = "));
+        assert!(contents.lines().all(|l| l.len() <= RLE_LINE_LIMIT));
+        assert_eq!(read, gun);
+    }
+
+    #[test]
+    fn rule_round_trip() {
+        let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+        board.set_rule(Rule::parse("B36/S23").unwrap());
+
+        let rle = board.to_rle(None);
+        assert!(rle.starts_with("x = 5, y = 5, rule = B36/S23\n"));
+        let read = Board::from_rle(&rle, BorderOpt::Empty).unwrap();
+        assert_eq!(read.rule(), &Rule::parse("B36/S23").unwrap());
+        assert_eq!(read, board);
+
+        let seeds = Board::from_rle("x = 1, y = 1, rule = B2/S\no!", BorderOpt::Empty).unwrap();
+        assert_eq!(seeds.rule().to_string(), "B2/S");
+        let conway = Board::from_rle("x = 1, y = 1\no!", BorderOpt::Empty).unwrap();
+        assert!(conway.rule().is_conway());
+
+        let ignored = Board::from_rle("x = 1, y = 1, rule = B9/S\no!", BorderOpt::Empty).unwrap();
+        assert!(ignored.rule().is_conway());
+    }
+
+    #[test]
+    fn other_rule_notations() {
+        let rule_of = |rule: &str| {
+            let rle = format!("x = 1, y = 1, rule = {}\no!", rule);
+            Board::from_rle(&rle, BorderOpt::Empty)
+                .unwrap()
+                .rule()
+                .clone()
+        };
+
+        assert_eq!(rule_of("23/36"), Rule::parse("B36/S23").unwrap());
+        assert_eq!(rule_of("/2"), Rule::parse("B2/S").unwrap());
+        assert_eq!(rule_of("b36/s23:T10,10"), Rule::parse("B36/S23").unwrap());
+        assert_eq!(rule_of("23/36:P20,20"), Rule::parse("B36/S23").unwrap());
+        // Not representable, so left at Conway's
+        assert!(rule_of("B2-a/S12").is_conway());
+        assert!(rule_of("Life").is_conway());
+        assert!(rule_of("B3/S23/5").is_conway());
+    }
+
+    #[test]
+    fn multi_digit_rule_is_left_out() {
+        let mut board = Board::new_from_file("./tests/test-boards/glider.txt");
+        board.set_rule(Rule::new(&[10], &[]));
+
+        let rle = board.to_rle(None);
+        assert!(rle.starts_with("x = 5, y = 5\n"));
+        let read = Board::from_rle(&rle, BorderOpt::Empty).unwrap();
+        assert!(read.rule().is_conway());
+        assert_ne!(read.rule(), &Rule::new(&[0, 1], &[]));
+        assert_eq!(read.cells, board.cells);
+    }
+
+    #[test]
+    fn parse_runs_across_lines() {
+        let rle = "#C a blinker\nx=3,y=3\n$\n3o$!";
+        let board = Board::from_rle(rle, BorderOpt::Empty).unwrap();
+
+        assert_eq!(
+            format!("{}", board),
+            "░░░░░░\n\
+             ▓▓▓▓▓▓\n\
+             ░░░░░░\n"
+        );
+    }
+
+    #[test]
+    fn row_rle_blinker() {
+        let board = Board::from_row_rle("5_\n_3#_\n\n5_\n", BorderOpt::Empty).unwrap();
+
+        assert_eq!(
+            format!("{}", board),
+            "░░░░░░░░░░\n\
+             ░░▓▓▓▓▓▓░░\n\
+             ░░░░░░░░░░\n"
+        );
+        assert_eq!(board.advanced_n(2).to_ascii(), ".....\n.###.\n.....\n");
+    }
+
+    #[test]
+    fn invalid_row_rle() {
+        let err = |input| {
+            Board::from_row_rle(input, BorderOpt::Empty)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(err("3_\n2_\n"), "row 1: 2 columns, expected 3");
+        assert_eq!(err("2_o\n"), "row 0: unexpected 'o'");
+        assert_eq!(err("2_3\n"), "row 0: run length 3 has no cell");
+
+        // Runs are checked before any cells are added
+        assert_eq!(err("3_\n999999999999#\n"), "row 1: more than 3 columns");
+        assert_eq!(
+            err("999999999999#\n"),
+            format!("row 0: more than {} cells", RLE_MAX_CELLS)
+        );
+        assert_eq!(
+            err(&format!("#{}_\n", usize::MAX)),
+            format!("row 0: more than {} cells", RLE_MAX_CELLS)
+        );
+    }
+
+    #[test]
+    fn invalid_rle() {
+        let too_wide = Board::from_rle("x = 2, y = 1\n3o!", BorderOpt::Empty).unwrap_err();
+        assert_eq!(too_wide.row, 1);
+
+        assert!(Board::from_rle("3o!", BorderOpt::Empty).is_err());
+        assert!(Board::from_rle("x = 3, y = 1\n3z!", BorderOpt::Empty).is_err());
+        assert!(Board::from_rle("", BorderOpt::Empty).is_err());
+    }
+
+    #[test]
+    fn hostile_rle_headers_and_runs() {
+        let err = |input| Board::from_rle(input, BorderOpt::Empty).unwrap_err();
+
+        // Sizes are checked before anything is allocated
+        let huge = format!("x = {}, y = 2\n!", usize::MAX);
+        assert_eq!(err(&huge).row, 0);
+        let over = format!("x = {}, y = 1\n!", RLE_MAX_CELLS + 1);
+        assert!(err(&over).message.contains("more than"));
+
+        // Runs can't overflow or run off the row
+        let long_run = format!("x = 3, y = 1\n{}o!", usize::MAX);
+        assert_eq!(err(&long_run).message, "pattern is larger than 3x1");
+        let many_rows = format!("x = 3, y = 1\n2${}$o!", usize::MAX);
+        assert_eq!(err(&many_rows).message, "pattern is larger than 3x1");
+
+        let empty = Board::from_rle("x = 0, y = 0\n!", BorderOpt::Empty).unwrap();
+        assert_eq!((empty.width, empty.height), (0, 0));
+    }
+}
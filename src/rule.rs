@@ -0,0 +1,438 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+    error::InvalidRule,
+    fixed::Grid,
+};
+
+/// A Life-like rule: which live neighbor counts bring a dead cell to
+/// life, and which keep a live cell alive
+///
+/// The default is Conway's `B3/S23`.
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// let highlife = Rule::parse("B36/S23").unwrap();
+/// assert!(highlife.is_born(6));
+/// assert!(!highlife.survives(6));
+/// assert_eq!(highlife.to_string(), "B36/S23");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    born: Vec<usize>,
+    survive: Vec<usize>,
+}
+
+impl Rule {
+    /// A rule where a dead cell with a count in `born` comes to life and
+    /// a live cell with a count in `survive` stays alive
+    ///
+    /// Counts can go past 8 for boards with a larger neighbor radius.
+    pub fn new(born: &[usize], survive: &[usize]) -> Self {
+        let sorted = |counts: &[usize]| {
+            let mut counts = counts.to_vec();
+            counts.sort_unstable();
+            counts.dedup();
+            counts
+        };
+
+        Rule {
+            born: sorted(born),
+            survive: sorted(survive),
+        }
+    }
+
+    /// Conway's Game of Life, `B3/S23`
+    pub fn conway() -> Self {
+        Rule::new(&[3], &[2, 3])
+    }
+
+    /// Parse a rule in `B.../S...` notation, such as `B36/S23`
+    ///
+    /// Each digit is one neighbor count, so only counts up to 8 can be
+    /// given this way; use [`Rule::new`] for more.
+    ///
+    /// # Errors:
+    ///
+    /// If the rule isn't of the form `B<digits>/S<digits>`.
+    pub fn parse(rule: &str) -> Result<Self, InvalidRule> {
+        let invalid = || InvalidRule(rule.to_string());
+        let (born, survive) = rule.trim().split_once('/').ok_or_else(invalid)?;
+        let counts = |part: &str, prefix: char| -> Result<Vec<usize>, InvalidRule> {
+            let digits = part
+                .strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .ok_or_else(invalid)?;
+            digits
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(n) if n <= 8 => Ok(n as usize),
+                    _ => Err(invalid()),
+                })
+                .collect()
+        };
+
+        Ok(Rule::new(&counts(born, 'B')?, &counts(survive, 'S')?))
+    }
+
+    /// Whether a dead cell with `neighbors` live neighbors comes to life
+    pub fn is_born(&self, neighbors: usize) -> bool {
+        self.born.contains(&neighbors)
+    }
+
+    /// Whether a live cell with `neighbors` live neighbors stays alive
+    pub fn survives(&self, neighbors: usize) -> bool {
+        self.survive.contains(&neighbors)
+    }
+
+    /// Whether every count is a single digit, so the rule can be written
+    /// in `B.../S...` notation and read back by [`Rule::parse`]
+    ///
+    /// Counts above 8 run together when printed, e.g. `B10/S` reads back
+    /// as born on 0 or 1.
+    pub fn has_digit_notation(&self) -> bool {
+        self.born.iter().chain(&self.survive).all(|&n| n <= 8)
+    }
+
+    /// Whether this is Conway's rule
+    pub fn is_conway(&self) -> bool {
+        *self == Rule::conway()
+    }
+}
+
+/// Which rule of the palette given to [`Board::set_rule_map`] a cell
+/// advances under
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuleId(pub usize);
+
+// A rule for every cell, as an index into a palette of rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RuleMap {
+    pub(crate) palette: Vec<Rule>,
+    pub(crate) ids: Vec<RuleId>,
+}
+
+impl RuleMap {
+    pub(crate) fn rule(&self, i: usize) -> &Rule {
+        &self.palette[self.ids[i].0]
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::conway()
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let digits =
+            |counts: &[usize]| -> String { counts.iter().map(|n| n.to_string()).collect() };
+        write!(f, "B{}/S{}", digits(&self.born), digits(&self.survive))
+    }
+}
+
+impl Board {
+    /// Initialize new board with every cell dead, advancing under `rule`
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::new`], if the board would have too many cells
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::with_rule(5, 5, BorderOpt::Empty, Rule::parse("B36/S23").unwrap());
+    /// assert_eq!(board.rule().to_string(), "B36/S23");
+    /// ```
+    pub fn with_rule(width: usize, height: usize, border: BorderOpt, rule: Rule) -> Self {
+        let mut board = Board::new(width, height, border);
+        board.set_rule(rule);
+
+        board
+    }
+
+    /// Advance the board through each `(rule, cycles)` stage in turn,
+    /// running `cycles` cycles under `rule`
+    ///
+    /// Models conditions that change during an experiment. The board is
+    /// left with the last stage's rule; cells covered by a rule map keep
+    /// following it throughout.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    ///
+    /// // A block is stable under Conway, then grows under B2/S
+    /// board.advance_staged(&[(Rule::conway(), 3), (Rule::parse("B2/S").unwrap(), 1)]);
+    /// assert_eq!(board.rule().to_string(), "B2/S");
+    /// assert_eq!(board.live_in_rect(1, 1, 2, 2), 0);
+    /// ```
+    pub fn advance_staged(&mut self, stages: &[(Rule, usize)]) {
+        stages.iter().for_each(|(rule, cycles)| {
+            self.set_rule(rule.clone());
+            self.advance_n_cycles(*cycles);
+        });
+    }
+
+    /// Advance board state by one cycle under `rule`, counting as
+    /// neighbors the cells at `offsets` from each cell instead of the
+    /// square around it
+    ///
+    /// Any neighborhood can be described this way, like a knight's moves,
+    /// or a hexagonal grid emulated by leaving out two opposite corners.
+    /// `rule`'s counts are out of `offsets.len()`. The border mode
+    /// applies to offsets that land off the board, and frozen cells stay
+    /// as they are; the board's own rule, rule map and radius are
+    /// ignored.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // The six neighbors of a hexagonal grid stored in offset rows
+    /// let hex = [(-1, -1), (0, -1), (-1, 0), (1, 0), (0, 1), (1, 1)];
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 1, Cell::Alive);
+    ///
+    /// board.advance_cycle_with_offsets(&hex, &Rule::new(&[2], &[]));
+    /// assert_eq!(board.to_ascii(), ".#..\n....\n..#.\n....\n");
+    /// ```
+    pub fn advance_cycle_with_offsets(&mut self, offsets: &[(i32, i32)], rule: &Rule) {
+        self.begin_generation();
+        let updates: Vec<(usize, usize, Cell)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter_map(|(x, y)| {
+                let neighbors = self.live_neighbors_at_offsets(x, y, offsets);
+                let cell = self.get(x, y);
+                let next = match cell {
+                    Cell::Alive if rule.survives(neighbors) => Cell::Alive,
+                    Cell::Dead if rule.is_born(neighbors) => Cell::Alive,
+                    _ => Cell::Dead,
+                };
+                if next != cell {
+                    Some((x, y, next))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        self.apply_updates(&updates);
+        self.invalidate_frontier();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{Backend, Generation};
+
+    const KNIGHT: [(i32, i32); 8] = [
+        (1, 2),
+        (2, 1),
+        (2, -1),
+        (1, -2),
+        (-1, -2),
+        (-2, -1),
+        (-2, 1),
+        (-1, 2),
+    ];
+
+    #[test]
+    fn knight_move_neighborhood() {
+        // Every square a knight can reach from the center of a 5×5 board
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        KNIGHT
+            .iter()
+            .for_each(|&(dx, dy)| board.set((2 + dx) as usize, (2 + dy) as usize, Cell::Alive));
+
+        assert_eq!(board.live_neighbors_at_offsets(2, 2, &KNIGHT), 8);
+        assert_eq!(board.live_neighbors(2, 2, 1), 0);
+        assert_eq!(board.live_neighbors_at_offsets(0, 0, &KNIGHT), 0);
+        assert_eq!(board.live_neighbors_at_offsets(2, 0, &KNIGHT), 2);
+        assert_eq!(board.live_neighbors_at_offsets(4, 4, &KNIGHT), 0);
+
+        // Only the center has all eight, and nothing survives
+        board.advance_cycle_with_offsets(&KNIGHT, &Rule::new(&[8], &[]));
+        assert_eq!(board.to_ascii(), ".....\n.....\n..#..\n.....\n.....\n");
+        assert_eq!(board.generation(), Generation(1));
+        assert_eq!(board.last_diff().len(), 9);
+    }
+
+    #[test]
+    fn offsets_follow_border_and_frozen_cells() {
+        let mut board = Board::new(5, 5, BorderOpt::Loop);
+        board.set(0, 0, Cell::Alive);
+
+        // On a torus the knight wraps around to reach the corner
+        assert_eq!(board.live_neighbors_at_offsets(4, 3, &KNIGHT), 1);
+        assert_eq!(board.live_neighbors_at_offsets(3, 4, &KNIGHT), 1);
+
+        board.freeze(0, 0);
+        board.advance_cycle_with_offsets(&KNIGHT, &Rule::new(&[1], &[]));
+        assert_eq!(board.get(0, 0), Cell::Alive);
+        assert_eq!(board.get(4, 3), Cell::Alive);
+        assert_eq!(board.get(2, 1), Cell::Alive);
+        assert_eq!(board.live_in_rect(0, 0, 5, 5), 9);
+
+        board.set_border(BorderOpt::Solid);
+        assert_eq!(board.live_neighbors_at_offsets(0, 0, &KNIGHT), 8);
+    }
+
+    #[test]
+    fn highlife_replicator_birth() {
+        // Six live neighbors around the center: born under HighLife only
+        let mut conway = Board::new(3, 3, BorderOpt::Empty);
+        conway.fill_rect(0, 0, 3, 1, Cell::Alive);
+        conway.fill_rect(0, 2, 3, 1, Cell::Alive);
+        let mut highlife = conway.clone();
+        highlife.set_rule(Rule::parse("B36/S23").unwrap());
+
+        conway.advance_cycle();
+        highlife.advance_cycle();
+
+        assert_eq!(conway.get(1, 1), Cell::Dead);
+        assert_eq!(highlife.get(1, 1), Cell::Alive);
+    }
+
+    #[test]
+    fn staged_kill_all() {
+        let mut board = Board::new_random(12, 12, BorderOpt::Loop, 0.4, 3);
+        let mut expected = board.clone();
+        let kill_all = Rule::parse("B/S").unwrap();
+
+        board.advance_staged(&[(Rule::conway(), 2)]);
+        expected.advance_n_cycles(2);
+        assert_eq!(board, expected);
+        assert_ne!(board.live_in_rect(0, 0, 12, 12), 0);
+
+        board.advance_staged(&[(Rule::conway(), 2), (kill_all.clone(), 1)]);
+        assert_eq!(board.live_in_rect(0, 0, 12, 12), 0);
+        assert_eq!(board.generation(), Generation(5));
+        assert_eq!(*board.rule(), kill_all);
+    }
+
+    #[test]
+    fn stored_rule_matches_explicit_rule() {
+        let rule = Rule::parse("B36/S23").unwrap();
+        let soup = Board::new_random(24, 24, BorderOpt::Loop, 0.4, 8);
+        let mut board = Board::with_rule(24, 24, BorderOpt::Loop, rule.clone());
+        board.cells = soup.cells.clone();
+        let mut expected = soup;
+
+        (0..20).for_each(|_| {
+            board.advance_cycle();
+            expected.advance_cycle_with(|cell, n| {
+                let alive = match cell {
+                    Cell::Alive => rule.survives(n),
+                    Cell::Dead => rule.is_born(n),
+                };
+                if alive {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            });
+            assert!(board.cells_eq(&expected).unwrap());
+        });
+
+        // The fast paths only handle Conway's rule and must step aside
+        expected.set_rule(rule.clone());
+        let mut bitboard = Board::with_rule(24, 24, BorderOpt::Loop, rule.clone());
+        bitboard.cells = expected.cells.clone();
+        bitboard.set_backend(Backend::Bitboard);
+        let mut hashlife = bitboard.clone();
+        bitboard.advance_n_cycles(9);
+        hashlife.advance_hashlife(9);
+        expected.advance_n_cycles(9);
+        assert!(bitboard.cells_eq(&expected).unwrap());
+        assert!(hashlife.cells_eq(&expected).unwrap());
+    }
+
+    // Two copies of a pattern whose center is born under HighLife only,
+    // one in each half of a board that's Conway on the left and HighLife
+    // on the right
+    fn split_board() -> Board {
+        let mut board = Board::new(20, 7, BorderOpt::Empty);
+        [3, 13].iter().for_each(|&x| {
+            board.fill_rect(x, 2, 3, 1, Cell::Alive);
+            board.fill_rect(x, 4, 3, 1, Cell::Alive);
+        });
+        let ids = (0..20 * 7).map(|i| RuleId(i % 20 / 10)).collect();
+        board.set_rule_map(vec![Rule::conway(), Rule::parse("B36/S23").unwrap()], ids);
+
+        board
+    }
+
+    #[test]
+    fn rule_map_splits_board() {
+        let mut board = split_board();
+        let mut conway = board.clone();
+        conway.clear_rule_map();
+        let mut highlife = conway.clone();
+        highlife.set_rule(Rule::parse("B36/S23").unwrap());
+
+        board.advance_cycle();
+        conway.advance_cycle();
+        highlife.advance_cycle();
+
+        assert_eq!(board.get(4, 3), Cell::Dead);
+        assert_eq!(board.get(14, 3), Cell::Alive);
+        (0..7).for_each(|y| {
+            (0..10).for_each(|x| assert_eq!(board.get(x, y), conway.get(x, y)));
+            (10..20).for_each(|x| assert_eq!(board.get(x, y), highlife.get(x, y)));
+        });
+
+        // The Conway only fast paths step aside
+        let mut bitboard = split_board();
+        bitboard.set_backend(Backend::Bitboard);
+        bitboard.advance_cycle();
+        let mut hashlife = split_board();
+        hashlife.advance_hashlife(1);
+        assert!(bitboard.cells_eq(&board).unwrap());
+        assert!(hashlife.cells_eq(&board).unwrap());
+    }
+
+    #[test]
+    fn rule_map_grows_with_board() {
+        let mut board = split_board();
+        board.grow_to_fit(4);
+
+        assert!(board.width > 20);
+        assert_eq!(board.validate(), Ok(()));
+        assert!(board.rule_at(0, 0).is_conway());
+        assert!(!board.rule_at(board.width - 1, board.height - 1).is_conway());
+    }
+
+    #[test]
+    #[should_panic(expected = "rule id 2 is outside the palette of 2 rules")]
+    fn rule_map_id_out_of_palette() {
+        let mut board = Board::new(2, 1, BorderOpt::Empty);
+        board.set_rule_map(vec![Rule::conway(); 2], vec![RuleId(0), RuleId(2)]);
+    }
+
+    #[test]
+    fn parse_rules() {
+        assert_eq!(Rule::parse("B3/S23"), Ok(Rule::conway()));
+        assert_eq!(Rule::parse("b3/s32"), Ok(Rule::conway()));
+        assert_eq!(Rule::parse("B/S").unwrap().to_string(), "B/S");
+        assert!(Rule::parse("B9/S23").is_err());
+        assert!(Rule::parse("S23/B3").is_err());
+        assert!(Rule::parse("B3S23").is_err());
+        assert_eq!(Rule::default(), Rule::conway());
+    }
+}
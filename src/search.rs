@@ -0,0 +1,338 @@
+use crate::{
+    board::{Board, BorderOpt, Generation},
+    cell::Cell,
+    rng::SplitMix64,
+};
+
+const SOUP_DENSITY: f64 = 0.5;
+/// Longest oscillator period `soup_search` waits for when deciding
+/// whether a soup has settled
+const SOUP_MAX_PERIOD: usize = 64;
+/// Largest board, in cells, `Board::has_predecessor` will search
+pub const PREDECESSOR_MAX_CELLS: usize = 64;
+/// Largest board, in cells, `Board::predecessor_count` will search
+///
+/// Lower than [`PREDECESSOR_MAX_CELLS`] since counting visits every
+/// predecessor instead of stopping at the first.
+pub const PREDECESSOR_COUNT_MAX_CELLS: usize = 25;
+
+/// Search random soups for long-lived patterns
+///
+/// Generates `trials` random `size`×`size` soups (Empty border, 50%
+/// density) and returns the seed and initial board of every soup whose
+/// lifespan, the generation at which it settles into a still life or
+/// oscillator, exceeds `min_lifespan`. Soups are simulated for at most
+/// `min_lifespan` plus 64 generations; any soup that hasn't visibly
+/// settled by then is treated as long-lived.
+///
+/// Every returned board can be recreated with
+/// `Board::new_random(size, size, BorderOpt::Empty, 0.5, seed)`.
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// for (seed, soup) in soup_search(8, 20, 10, 1) {
+///     assert_eq!(soup, Board::new_random(8, 8, BorderOpt::Empty, 0.5, seed));
+/// }
+/// ```
+pub fn soup_search(
+    size: usize,
+    trials: usize,
+    min_lifespan: usize,
+    seed: u64,
+) -> Vec<(u64, Board)> {
+    let mut rng = SplitMix64::new(seed);
+
+    (0..trials)
+        .map(|_| rng.next_u64())
+        .filter_map(|soup_seed| {
+            let soup = Board::new_random(size, size, BorderOpt::Empty, SOUP_DENSITY, soup_seed);
+
+            match soup.detect_stability(min_lifespan + SOUP_MAX_PERIOD) {
+                Some((start, _)) if start <= Generation(min_lifespan) => None,
+                _ => Some((soup_seed, soup)),
+            }
+        })
+        .collect()
+}
+
+impl Board {
+    /// Whether some board of the same size and settings advances into
+    /// this one, i.e. whether this isn't a "Garden of Eden"
+    ///
+    /// Predecessors are searched for cell by cell with backtracking: as
+    /// soon as every cell around a cell is decided, the cell's next state
+    /// is checked against this board, pruning any candidate that can't
+    /// work. The search is still exponential in the worst case, so it's
+    /// only meant for small boards. Frozen cells are treated like any
+    /// other.
+    ///
+    /// # Panics:
+    ///
+    /// If the board has more than [`PREDECESSOR_MAX_CELLS`] cells
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let soup = Board::new_random(5, 5, BorderOpt::Empty, 0.5, 1);
+    /// assert!(soup.advanced().has_predecessor());
+    /// ```
+    pub fn has_predecessor(&self) -> bool {
+        self.assert_searchable(PREDECESSOR_MAX_CELLS);
+        let (mut candidate, checks) = self.predecessor_search();
+
+        self.cells.is_empty() || self.extend_predecessor(&mut candidate, &checks, 0)
+    }
+
+    /// The number of boards of the same size and settings that advance
+    /// into this one, 0 for a "Garden of Eden"
+    ///
+    /// Uses the same backtracking search as [`Board::has_predecessor`],
+    /// but visits every predecessor, of which there can be exponentially
+    /// many, so the limit is lower. Frozen cells are treated like any
+    /// other.
+    ///
+    /// # Panics:
+    ///
+    /// If the board has more than [`PREDECESSOR_COUNT_MAX_CELLS`] cells
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// // A 2×2 block comes from itself or any of its four 3-cell corners
+    /// let mut block = Board::new(2, 2, BorderOpt::Empty);
+    /// block.fill_rect(0, 0, 2, 2, Cell::Alive);
+    /// assert_eq!(block.predecessor_count(), 5);
+    /// ```
+    pub fn predecessor_count(&self) -> u64 {
+        self.assert_searchable(PREDECESSOR_COUNT_MAX_CELLS);
+        let (mut candidate, checks) = self.predecessor_search();
+
+        self.count_predecessors(&mut candidate, &checks, 0)
+    }
+
+    fn assert_searchable(&self, max_cells: usize) {
+        if self.cells.len() > max_cells {
+            panic!(
+                "{}x{} board is too large to search, the limit is {} cells",
+                self.width, self.height, max_cells
+            );
+        }
+    }
+
+    // An all-dead candidate to fill in, and for each cell the cells
+    // whose whole neighborhood is decided once it is
+    fn predecessor_search(&self) -> (Board, Vec<Vec<(usize, usize)>>) {
+        let mut checks: Vec<Vec<(usize, usize)>> = vec![vec![]; self.cells.len()];
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .for_each(|(x, y)| checks[self.last_neighborhood_idx(x, y)].push((x, y)));
+
+        let mut candidate = self.clone();
+        candidate.cells.iter_mut().for_each(|c| *c = Cell::Dead);
+
+        (candidate, checks)
+    }
+
+    // Whether the cells decided by setting cell `idx` of `candidate`
+    // advance into this board's
+    fn predecessor_consistent(
+        &self,
+        candidate: &Board,
+        checks: &[Vec<(usize, usize)>],
+        idx: usize,
+    ) -> bool {
+        checks[idx]
+            .iter()
+            .all(|&(x, y)| candidate.cell_should_live(x, y) == (self.get(x, y) == Cell::Alive))
+    }
+
+    fn extend_predecessor(
+        &self,
+        candidate: &mut Board,
+        checks: &[Vec<(usize, usize)>],
+        idx: usize,
+    ) -> bool {
+        if idx == self.cells.len() {
+            return true;
+        }
+
+        [Cell::Dead, Cell::Alive].iter().any(|&c| {
+            candidate.cells[idx] = c;
+            let found = self.predecessor_consistent(candidate, checks, idx)
+                && self.extend_predecessor(candidate, checks, idx + 1);
+            candidate.cells[idx] = Cell::Dead;
+            found
+        })
+    }
+
+    fn count_predecessors(
+        &self,
+        candidate: &mut Board,
+        checks: &[Vec<(usize, usize)>],
+        idx: usize,
+    ) -> u64 {
+        if idx == self.cells.len() {
+            return 1;
+        }
+
+        [Cell::Dead, Cell::Alive]
+            .iter()
+            .map(|&c| {
+                candidate.cells[idx] = c;
+                let count = if self.predecessor_consistent(candidate, checks, idx) {
+                    self.count_predecessors(candidate, checks, idx + 1)
+                } else {
+                    0
+                };
+                candidate.cells[idx] = Cell::Dead;
+                count
+            })
+            .sum()
+    }
+
+    // The largest row-major index among the cell at `x`, `y` and its
+    // neighbors on the board
+    fn last_neighborhood_idx(&self, x: usize, y: usize) -> usize {
+        let r = self.neighbor_radius() as i64;
+        let (w, h) = (self.width as i64, self.height as i64);
+
+        (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (x as i64 + dx, y as i64 + dy)))
+            .filter_map(|(nx, ny)| match self.border {
+                BorderOpt::Loop => Some((nx.rem_euclid(w), ny.rem_euclid(h))),
+                _ if (0..w).contains(&nx) && (0..h).contains(&ny) => Some((nx, ny)),
+                _ => None,
+            })
+            .map(|(nx, ny)| (ny * w + nx) as usize)
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn soup_search_meets_threshold() {
+        let min_lifespan = 15;
+        let results = soup_search(8, 50, min_lifespan, 7);
+
+        assert!(!results.is_empty());
+        results.iter().for_each(|(seed, soup)| {
+            assert_eq!(
+                *soup,
+                Board::new_random(8, 8, BorderOpt::Empty, SOUP_DENSITY, *seed)
+            );
+            if let Some((start, _)) = soup.detect_stability(1000) {
+                assert!(start > Generation(min_lifespan));
+            }
+        });
+    }
+
+    // Every 3×3 board reachable in one step, found by advancing all 512
+    // possible boards
+    fn reachable_3x3(border: BorderOpt) -> Vec<Board> {
+        (0..512u32)
+            .map(|bits| {
+                let mut board = Board::new(3, 3, border.clone());
+                (0..9)
+                    .filter(|i| bits & 1 << i != 0)
+                    .for_each(|i| board.set(i % 3, i / 3, Cell::Alive));
+                board.advanced()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn predecessors_match_exhaustive_search() {
+        [BorderOpt::Empty, BorderOpt::Loop]
+            .iter()
+            .for_each(|border| {
+                let reachable = reachable_3x3(border.clone());
+
+                (0..512u32).for_each(|bits| {
+                    let mut board = Board::new(3, 3, border.clone());
+                    (0..9)
+                        .filter(|i| bits & 1 << i != 0)
+                        .for_each(|i| board.set(i % 3, i / 3, Cell::Alive));
+
+                    assert_eq!(
+                        board.has_predecessor(),
+                        reachable.contains(&board),
+                        "{:?}\n{}",
+                        border,
+                        board
+                    );
+                });
+            });
+    }
+
+    #[test]
+    fn predecessor_counts_match_exhaustive_search() {
+        [BorderOpt::Empty, BorderOpt::Loop]
+            .iter()
+            .for_each(|border| {
+                let reachable = reachable_3x3(border.clone());
+
+                reachable.iter().for_each(|board| {
+                    let expected = reachable.iter().filter(|&b| b == board).count() as u64;
+                    assert_eq!(
+                        board.predecessor_count(),
+                        expected,
+                        "{:?}\n{}",
+                        border,
+                        board
+                    );
+                });
+            });
+    }
+
+    #[test]
+    fn tiny_predecessor_counts() {
+        // On an empty 2×2 board, zero, one or two live cells all die out
+        assert_eq!(Board::new(2, 2, BorderOpt::Empty).predecessor_count(), 11);
+
+        let mut lone = Board::new(1, 1, BorderOpt::Empty);
+        lone.set(0, 0, Cell::Alive);
+        assert_eq!(lone.predecessor_count(), 0);
+        assert_eq!(Board::new(0, 0, BorderOpt::Empty).predecessor_count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn predecessor_count_is_bounded() {
+        Board::new(6, 5, BorderOpt::Empty).predecessor_count();
+    }
+
+    #[test]
+    fn full_board_is_garden_of_eden() {
+        // No 3×3 board advances into a full one, as the exhaustive
+        // search above confirms
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        (0..9).for_each(|i| board.set(i % 3, i / 3, Cell::Alive));
+
+        assert!(!board.has_predecessor());
+    }
+
+    #[test]
+    fn blinker_has_predecessor() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        (1..4).for_each(|x| board.set(x, 2, Cell::Alive));
+
+        assert!(board.has_predecessor());
+    }
+
+    #[test]
+    fn soup_search_is_reproducible() {
+        assert_eq!(soup_search(6, 20, 5, 3), soup_search(6, 20, 5, 3));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+};
+
+/// An unbounded board that only stores its live cells
+///
+/// Cheaper than a [`Board`] when few cells are alive, e.g. a handful of
+/// gliders spread out over a large area, and never runs into an edge.
+/// Always uses Conway's rules with a neighbor radius of 1. Convert back
+/// and forth with [`Board::to_sparse`] and [`SparseBoard::to_dense`] as
+/// the population changes.
+///
+/// # Example:
+///
+/// ```
+/// use yagoll::*;
+///
+/// let mut board = SparseBoard::new();
+/// board.set(-1, 0, Cell::Alive);
+/// board.set(0, 0, Cell::Alive);
+/// board.set(1, 0, Cell::Alive);
+///
+/// board.advance_cycle();
+/// assert_eq!(board.live_cells(), vec![(0, -1), (0, 0), (0, 1)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseBoard {
+    live: HashSet<(i64, i64)>,
+}
+
+impl SparseBoard {
+    /// Initialize a new board with no live cells
+    pub fn new() -> Self {
+        SparseBoard::default()
+    }
+
+    /// Set cell at `x` and `y` to state `c`
+    pub fn set(&mut self, x: i64, y: i64, c: Cell) {
+        match c {
+            Cell::Alive => self.live.insert((x, y)),
+            Cell::Dead => self.live.remove(&(x, y)),
+        };
+    }
+
+    /// Get cell at `x` and `y`
+    pub fn get(&self, x: i64, y: i64) -> Cell {
+        if self.live.contains(&(x, y)) {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+
+    /// The number of live cells
+    pub fn population(&self) -> usize {
+        self.live.len()
+    }
+
+    /// The coordinates of every live cell, in row-major order
+    pub fn live_cells(&self) -> Vec<(i64, i64)> {
+        let mut cells: Vec<(i64, i64)> = self.live.iter().copied().collect();
+        cells.sort_by_key(|&(x, y)| (y, x));
+
+        cells
+    }
+
+    /// Advance board state by one cycle
+    pub fn advance_cycle(&mut self) {
+        let mut neighbors: HashMap<(i64, i64), u8> = HashMap::new();
+
+        self.live.iter().for_each(|&(x, y)| {
+            (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                .filter(|&d| d != (0, 0))
+                .for_each(|(dx, dy)| *neighbors.entry((x + dx, y + dy)).or_insert(0) += 1);
+        });
+
+        self.live = neighbors
+            .into_iter()
+            .filter(|&(pos, n)| n == 3 || (n == 2 && self.live.contains(&pos)))
+            .map(|(pos, _)| pos)
+            .collect();
+    }
+
+    /// Advance board state by n cycles
+    pub fn advance_n_cycles(&mut self, n: usize) {
+        (0..n).for_each(|_| self.advance_cycle())
+    }
+
+    /// A `width`×`height` board with the live cells that fall inside it,
+    /// with this board's origin at its top left corner
+    ///
+    /// Live cells at negative coordinates or beyond the size are dropped.
+    ///
+    /// # Panics:
+    ///
+    /// Like [`Board::new`], if the board would have too many cells
+    pub fn to_dense(&self, width: usize, height: usize, border: BorderOpt) -> Board {
+        let mut board = Board::new(width, height, border);
+
+        self.live
+            .iter()
+            .filter(|&&(x, y)| x >= 0 && y >= 0)
+            .map(|&(x, y)| (x as usize, y as usize))
+            .filter(|&(x, y)| x < width && y < height)
+            .for_each(|(x, y)| board.set(x, y, Cell::Alive));
+
+        board
+    }
+}
+
+impl Board {
+    /// The board's live cells as a [`SparseBoard`], with the same
+    /// coordinates
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let sparse = board.to_sparse();
+    /// assert_eq!(sparse.population(), 5);
+    /// assert_eq!(sparse.to_dense(board.width, board.height, BorderOpt::Empty), board);
+    /// ```
+    pub fn to_sparse(&self) -> SparseBoard {
+        SparseBoard {
+            live: self
+                .cells
+                .iter()
+                .enumerate()
+                .filter(|(_, &c)| c == Cell::Alive)
+                .map(|(i, _)| ((i % self.width) as i64, (i / self.width) as i64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn glider_round_trip() {
+        let mut board = Board::new(8, 8, BorderOpt::Empty);
+        board
+            .place_coords(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)], 0, 0, true)
+            .unwrap();
+
+        let mut sparse = board.to_sparse();
+        assert_eq!(
+            sparse.live_cells(),
+            vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+        );
+        assert_eq!(sparse.to_dense(8, 8, BorderOpt::Empty), board);
+
+        // Both representations agree while the glider is away from the edge
+        sparse.advance_n_cycles(8);
+        board.advance_n_cycles(8);
+        assert_eq!(sparse, board.to_sparse());
+        assert_eq!(sparse.to_dense(8, 8, BorderOpt::Empty), board);
+    }
+
+    #[test]
+    fn to_dense_drops_cells_outside() {
+        let mut sparse = SparseBoard::new();
+        sparse.set(-1, 0, Cell::Alive);
+        sparse.set(1, 1, Cell::Alive);
+        sparse.set(3, 0, Cell::Alive);
+
+        let board = sparse.to_dense(3, 2, BorderOpt::Loop);
+        assert_eq!(board.to_ascii(), "...\n.#.\n");
+        assert_eq!(board.border, BorderOpt::Loop);
+    }
+}
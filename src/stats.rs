@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+
+use crate::{board::Board, cell::Cell};
+
+impl Board {
+    /// The number of live cells
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.population(), 5);
+    /// ```
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|&&c| c == Cell::Alive).count()
+    }
+
+    /// The number of live cells in each row, top to bottom
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.row_populations(), vec![0, 1, 1, 3, 0]);
+    /// ```
+    pub fn row_populations(&self) -> Vec<usize> {
+        (0..self.height)
+            .map(|y| self.live_in_rect(0, y, self.width, 1))
+            .collect()
+    }
+
+    /// The number of live cells in each column, left to right
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// assert_eq!(board.column_populations(), vec![0, 1, 2, 2, 0]);
+    /// ```
+    pub fn column_populations(&self) -> Vec<usize> {
+        let mut columns = vec![0; self.width];
+
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .for_each(|(i, _)| columns[i % self.width] += 1);
+
+        columns
+    }
+
+    /// Advance the board by `cycles` cycles, counting how many times each
+    /// cell changed state along the way
+    ///
+    /// The counts are returned in row-major order, like the board's
+    /// cells, so the count for `x`, `y` is at `y * width + x`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    ///
+    /// assert_eq!(board.activity_heatmap(3), vec![0, 0, 0, 0, 1, 0, 0, 0, 0]);
+    /// ```
+    pub fn activity_heatmap(&mut self, cycles: usize) -> Vec<u32> {
+        let mut heat = vec![0; self.cells.len()];
+
+        (0..cycles).for_each(|_| {
+            let previous = self.cells.clone();
+            self.advance_cycle();
+            heat.iter_mut()
+                .zip(previous.iter().zip(self.cells.iter()))
+                .filter(|(_, (before, after))| before != after)
+                .for_each(|(h, _)| *h += 1);
+        });
+
+        heat
+    }
+
+    /// The number of live cells in this generation and each of the next
+    /// `cycles`, for plotting
+    ///
+    /// A copy of the board is advanced, leaving `self` untouched, so
+    /// `cycles + 1` populations are returned, starting with the current
+    /// one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 2, 1, Cell::Alive);
+    /// assert_eq!(board.population_series(2), vec![2, 0, 0]);
+    /// ```
+    pub fn population_series(&self, cycles: usize) -> Vec<usize> {
+        let mut board = self.clone();
+        board.set_history_capacity(0);
+        let mut series = Vec::with_capacity(cycles + 1);
+
+        series.push(board.population());
+        (0..cycles).for_each(|_| {
+            board.advance_cycle();
+            series.push(board.population());
+        });
+
+        series
+    }
+
+    /// Advance the board by `cycles` cycles, recording the state of the
+    /// cell at `x` and `y` in every generation
+    ///
+    /// The starting state is included, so `cycles + 1` states are
+    /// returned.
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    /// assert_eq!(board.track_cell(1, 1, 2), vec![Cell::Alive, Cell::Dead, Cell::Dead]);
+    /// ```
+    pub fn track_cell(&mut self, x: usize, y: usize, cycles: usize) -> Vec<Cell> {
+        let mut states = Vec::with_capacity(cycles + 1);
+
+        states.push(self.get(x, y));
+        (0..cycles).for_each(|_| {
+            self.advance_cycle();
+            states.push(self.get(x, y));
+        });
+
+        states
+    }
+
+    /// Advance the board by `cycles` cycles, capturing the cells of every
+    /// generation
+    ///
+    /// Frame 0 is the starting state, so `cycles + 1` frames are
+    /// returned. Each frame is a row-major copy of the cells, without the
+    /// rest of the board, ready to render or encode.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    ///
+    /// let frames = board.record(1);
+    /// assert_eq!(frames[0][4], Cell::Alive);
+    /// assert_eq!(frames[1], vec![Cell::Dead; 9]);
+    /// ```
+    pub fn record(&mut self, cycles: usize) -> Vec<Vec<Cell>> {
+        let mut frames = Vec::with_capacity(cycles + 1);
+
+        frames.push(self.cells.clone());
+        (0..cycles).for_each(|_| {
+            self.advance_cycle();
+            frames.push(self.cells.clone());
+        });
+
+        frames
+    }
+
+    /// Like [`Board::record`], but with consecutive identical frames
+    /// stored once, as `(repeat_count, frame)` pairs
+    ///
+    /// The counts add up to `cycles + 1`. A board that settles into a
+    /// still life costs one frame from then on, however long the run.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(3, 3, BorderOpt::Empty);
+    /// board.set(1, 1, Cell::Alive);
+    ///
+    /// let frames = board.record_compressed(5);
+    /// assert_eq!(frames.len(), 2);
+    /// assert_eq!(frames[1], (5, vec![Cell::Dead; 9]));
+    /// ```
+    pub fn record_compressed(&mut self, cycles: usize) -> Vec<(usize, Vec<Cell>)> {
+        let mut runs: Vec<(usize, Vec<Cell>)> = vec![(1, self.cells.clone())];
+
+        (0..cycles).for_each(|_| {
+            self.advance_cycle();
+            match runs.last_mut() {
+                Some((count, frame)) if *frame == self.cells => *count += 1,
+                _ => runs.push((1, self.cells.clone())),
+            }
+        });
+
+        runs
+    }
+
+    /// Advance the board by `cycles` cycles and return the total number
+    /// of times any cell changed state, a rough measure of how busy the
+    /// pattern is
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    ///
+    /// // A block never changes
+    /// assert_eq!(board.total_transitions(10), 0);
+    /// ```
+    pub fn total_transitions(&mut self, cycles: usize) -> u64 {
+        self.activity_heatmap(cycles)
+            .iter()
+            .map(|&h| u64::from(h))
+            .sum()
+    }
+
+    /// The Shannon entropy, in bits, of the distribution of `tile`×`tile`
+    /// configurations across the board
+    ///
+    /// Every `tile`×`tile` window that fits on the board is counted, so
+    /// windows overlap. Structured boards reuse few configurations and
+    /// score low; noisy ones score high, up to `tile * tile` bits. A board
+    /// smaller than one tile, or made of one repeated configuration, has
+    /// an entropy of 0.
+    ///
+    /// # Panics:
+    ///
+    /// If `tile` is 0.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 1, BorderOpt::Empty);
+    /// board.fill_rect(0, 0, 2, 1, Cell::Alive);
+    ///
+    /// // Half of the cells are alive, half dead
+    /// assert_eq!(board.tile_entropy(1), 1.0);
+    /// ```
+    pub fn tile_entropy(&self, tile: usize) -> f64 {
+        if tile == 0 {
+            panic!("Tile size must be non-zero");
+        }
+        if tile > self.width || tile > self.height {
+            return 0.0;
+        }
+        let mut counts: HashMap<Vec<Cell>, usize> = HashMap::new();
+
+        (0..=self.height - tile)
+            .flat_map(|y| (0..=self.width - tile).map(move |x| (x, y)))
+            .for_each(|(x, y)| {
+                let window = (y..y + tile)
+                    .flat_map(|wy| (x..x + tile).map(move |wx| (wx, wy)))
+                    .map(|(wx, wy)| self.get(wx, wy))
+                    .collect();
+                *counts.entry(window).or_insert(0) += 1;
+            });
+        let total = counts.values().sum::<usize>() as f64;
+
+        counts
+            .values()
+            .map(|&n| n as f64 / total)
+            .map(|p| -p * p.log2())
+            .sum::<f64>()
+            .max(0.0)
+    }
+
+    /// The number of orthogonally adjacent pairs of live cells per live
+    /// cell, a quick measure of how clustered the live cells are
+    ///
+    /// Isolated cells score 0 and a large solid block approaches 2. Only
+    /// pairs within the board count, even on `Loop` boards. A board with
+    /// no live cells scores 0.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.fill_rect(1, 1, 2, 2, Cell::Alive);
+    ///
+    /// // Four pairs among four cells
+    /// assert_eq!(board.live_adjacency_ratio(), 1.0);
+    /// ```
+    pub fn live_adjacency_ratio(&self) -> f64 {
+        let live = self.population();
+        if live == 0 {
+            return 0.0;
+        }
+        let alive = |x: usize, y: usize| self.get(x, y) == Cell::Alive;
+        let pairs = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| alive(x, y))
+            .map(|(x, y)| {
+                let right = x + 1 < self.width && alive(x + 1, y);
+                let below = y + 1 < self.height && alive(x, y + 1);
+                right as usize + below as usize
+            })
+            .sum::<usize>();
+
+        pairs as f64 / live as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        board::{Board, BorderOpt, Generation},
+        cell::Cell,
+    };
+
+    #[test]
+    fn entropy_of_uniform_and_random_boards() {
+        let dead = Board::new(16, 16, BorderOpt::Empty);
+        let random = Board::new_random(16, 16, BorderOpt::Empty, 0.5, 21);
+
+        assert_eq!(dead.tile_entropy(2), 0.0);
+        assert_eq!(dead.tile_entropy(3), 0.0);
+        assert!(random.tile_entropy(2) > 3.0);
+        assert!(random.tile_entropy(3) > random.tile_entropy(2));
+        assert!(random.tile_entropy(2) <= 4.0);
+        assert_eq!(random.tile_entropy(17), 0.0);
+    }
+
+    #[test]
+    fn adjacency_of_block_and_scatter() {
+        let mut block = Board::new(10, 10, BorderOpt::Empty);
+        block.fill_rect(0, 0, 10, 10, Cell::Alive);
+        // 2 * 10 * 9 pairs among 100 cells
+        assert_eq!(block.live_adjacency_ratio(), 1.8);
+
+        let mut scattered = Board::new(10, 10, BorderOpt::Loop);
+        (0..10).step_by(2).for_each(|y| {
+            (0..10)
+                .step_by(2)
+                .for_each(|x| scattered.set(x, y, Cell::Alive))
+        });
+        assert_eq!(scattered.live_adjacency_ratio(), 0.0);
+
+        assert!(Board::new_random(10, 10, BorderOpt::Empty, 0.5, 2).live_adjacency_ratio() < 1.8);
+        assert_eq!(
+            Board::new(3, 3, BorderOpt::Empty).live_adjacency_ratio(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn blinker_heatmap() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        (1..4).for_each(|x| board.set(x, 2, Cell::Alive));
+
+        let heat = board.activity_heatmap(6);
+        let at = |x: usize, y: usize| heat[y * 5 + x];
+
+        // The center never changes, both pairs of ends flip every cycle
+        assert_eq!(at(2, 2), 0);
+        [(1, 2), (3, 2), (2, 1), (2, 3)]
+            .iter()
+            .for_each(|&(x, y)| assert_eq!(at(x, y), 6));
+        assert_eq!(heat.iter().sum::<u32>(), 24);
+    }
+
+    #[test]
+    fn track_blinker_endpoint() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+
+        let states = board.track_cell(1, 2, 5);
+
+        assert_eq!(states.len(), 6);
+        states.iter().enumerate().for_each(|(generation, &state)| {
+            let expected = if generation % 2 == 0 {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            };
+            assert_eq!(state, expected);
+        });
+    }
+
+    #[test]
+    fn record_blinker() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(1, 2, 3, 1, Cell::Alive);
+        let initial = board.clone();
+
+        let frames = board.record(2);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], initial.cells);
+        assert_eq!(frames[1], initial.clone().advanced().cells);
+        assert_eq!(frames[2], board.cells);
+        assert_ne!(frames[0], frames[1]);
+        assert_ne!(frames[1], frames[2]);
+    }
+
+    #[test]
+    fn record_compressed_block_and_blinker() {
+        let mut block = Board::new(4, 4, BorderOpt::Empty);
+        block.fill_rect(1, 1, 2, 2, Cell::Alive);
+        let initial = block.cells.clone();
+
+        assert_eq!(block.record_compressed(10), vec![(11, initial)]);
+
+        let mut blinker = Board::new(5, 5, BorderOpt::Empty);
+        blinker.fill_rect(1, 2, 3, 1, Cell::Alive);
+        let frames = blinker.clone().record(4);
+        let runs = blinker.record_compressed(4);
+        assert_eq!(runs.len(), 5);
+        assert!(runs
+            .iter()
+            .zip(frames)
+            .all(|(run, frame)| *run == (1, frame)));
+    }
+
+    #[test]
+    fn blinker_populations() {
+        // ░░▓▓░░
+        // ░░▓▓░░
+        // ░░▓▓░░
+        let mut board = Board::new(3, 3, BorderOpt::Empty);
+        board.fill_rect(1, 0, 1, 3, Cell::Alive);
+        assert_eq!(board.row_populations(), vec![1, 1, 1]);
+        assert_eq!(board.column_populations(), vec![0, 3, 0]);
+
+        board.advance_cycle();
+        assert_eq!(board.row_populations(), vec![0, 3, 0]);
+        assert_eq!(board.column_populations(), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn population_series_trends() {
+        let mut blinker = Board::new(5, 5, BorderOpt::Empty);
+        blinker.fill_rect(1, 2, 3, 1, Cell::Alive);
+        let before = blinker.clone();
+        assert_eq!(blinker.population_series(6), vec![3; 7]);
+        assert_eq!(blinker, before);
+        assert_eq!(blinker.generation(), Generation(0));
+
+        // A diagonal line shrinks from both ends until it's gone
+        let mut diagonal = Board::new(8, 8, BorderOpt::Empty);
+        (0..6).for_each(|i| diagonal.set(i + 1, i + 1, Cell::Alive));
+        let series = diagonal.population_series(5);
+        assert_eq!(series, vec![6, 4, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn transition_totals() {
+        let mut block = Board::new(6, 6, BorderOpt::Empty);
+        block.fill_rect(2, 2, 2, 2, Cell::Alive);
+        assert_eq!(block.total_transitions(7), 0);
+
+        let mut blinker = Board::new(5, 5, BorderOpt::Empty);
+        blinker.fill_rect(1, 2, 3, 1, Cell::Alive);
+        assert_eq!(blinker.total_transitions(7), 4 * 7);
+    }
+}
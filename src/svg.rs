@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use crate::{board::Board, cell::Cell};
+
+impl Board {
+    /// Render the board as an SVG image, with each cell a `cell_px`
+    /// square
+    ///
+    /// Every live cell is a `<rect>` filled with `alive`, while dead cells
+    /// are left to the canvas background, `dead`, which keeps the file
+    /// small. Colors are anything CSS accepts, like `"black"` or
+    /// `"#ff8800"`. Unlike the PNG export this needs no extra features,
+    /// and the result stays sharp at any zoom, for docs and web pages.
+    ///
+    /// # Panics:
+    ///
+    /// - If `cell_px` is 0
+    /// - If the image would be wider or taller, in pixels, than a `usize`
+    ///   can count
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let board = Board::new_from_file("./tests/test-boards/glider.txt");
+    /// let svg = board.to_svg(10, "black", "white");
+    ///
+    /// assert!(svg.starts_with("<svg "));
+    /// assert_eq!(svg.matches("<rect ").count(), 5);
+    /// ```
+    pub fn to_svg(&self, cell_px: usize, alive: &str, dead: &str) -> String {
+        if cell_px == 0 {
+            panic!("cell_px must be at least 1");
+        }
+        let mut svg = self.svg_open(cell_px, dead);
+        svg.push_str(&format!("<g fill=\"{}\">\n", escape_attr(alive)));
+        self.write_svg_rects(&mut svg, cell_px);
+        svg.push_str("</g>\n</svg>\n");
+
+        svg
+    }
+
+    /// Advance the board `cycles` times and render the starting board and
+    /// every generation after it as one animated SVG, black on white
+    ///
+    /// Each generation is a `<g>` group of live cells like in
+    /// [`Board::to_svg`], shown for `ms_per_frame` milliseconds in turn by
+    /// a CSS animation, and the whole run loops forever. The file plays in
+    /// any browser without scripts or other files.
+    ///
+    /// # Panics:
+    ///
+    /// - If `cell_px` or `ms_per_frame` is 0
+    /// - Like [`Board::to_svg`], if the image would be too large
+    /// - If the loop, `cycles + 1` frames of `ms_per_frame` each, would
+    ///   last more milliseconds than a `usize` can count
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(1, 2, 3, 1, Cell::Alive);
+    ///
+    /// let svg = board.to_svg_animation(1, 10, 500);
+    /// assert!(svg.contains("animation: frame 1000ms step-end 500ms infinite"));
+    /// ```
+    pub fn to_svg_animation(
+        &mut self,
+        cycles: usize,
+        cell_px: usize,
+        ms_per_frame: usize,
+    ) -> String {
+        if cell_px == 0 || ms_per_frame == 0 {
+            panic!("cell_px and ms_per_frame must be at least 1");
+        }
+        // Every frame's delay is below the total, so it can't overflow either
+        let (frames, total) = cycles
+            .checked_add(1)
+            .and_then(|frames| Some((frames, frames.checked_mul(ms_per_frame)?)))
+            .unwrap_or_else(|| {
+                panic!(
+                    "{} cycles at {}ms per frame last longer than a usize can count",
+                    cycles, ms_per_frame
+                )
+            });
+        let mut svg = self.svg_open(cell_px, "white");
+        // Each frame is visible for its share of the loop, then hidden
+        // until its turn comes around again
+        writeln!(
+            svg,
+            "<style>\ng {{ visibility: hidden; }}\n\
+             @keyframes frame {{ 0% {{ visibility: visible; }} {}% {{ visibility: hidden; }} }}\n\
+             </style>",
+            100.0 / frames as f64
+        )
+        .unwrap();
+
+        (0..frames).for_each(|frame| {
+            if frame > 0 {
+                self.advance_cycle();
+            }
+            writeln!(
+                svg,
+                "<g fill=\"black\" style=\"animation: frame {}ms step-end {}ms infinite\">",
+                total,
+                frame * ms_per_frame
+            )
+            .unwrap();
+            self.write_svg_rects(&mut svg, cell_px);
+            svg.push_str("</g>\n");
+        });
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    // The opening `<svg>` tag sized for the board, with `background` as
+    // its background color. Checking the canvas size here bounds every
+    // position `write_svg_rects` works out.
+    fn svg_open(&self, cell_px: usize, background: &str) -> String {
+        let (w, h) = self
+            .width
+            .checked_mul(cell_px)
+            .zip(self.height.checked_mul(cell_px))
+            .unwrap_or_else(|| {
+                panic!(
+                    "a {}x{} board at {}px per cell is larger than a usize can count",
+                    self.width, self.height, cell_px
+                )
+            });
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\" style=\"background-color: {}\">\n",
+            escape_attr(background),
+            w = w,
+            h = h
+        )
+    }
+
+    // One `<rect>` line per live cell, inside the canvas `svg_open`
+    // checked
+    fn write_svg_rects(&self, svg: &mut String, cell_px: usize) {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c == Cell::Alive)
+            .for_each(|(i, _)| {
+                let (x, y) = (i % self.width * cell_px, i / self.width * cell_px);
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{p}\" height=\"{p}\"/>",
+                    x,
+                    y,
+                    p = cell_px
+                )
+                .unwrap();
+            });
+    }
+}
+
+// `s` with the characters that can't appear in a quoted XML attribute
+// escaped
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::BorderOpt;
+
+    #[test]
+    fn single_cell_svg() {
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+        board.set(2, 1, Cell::Alive);
+
+        let svg = board.to_svg(8, "#000", "#fff");
+        assert!(svg.contains("width=\"32\" height=\"24\" viewBox=\"0 0 32 24\""));
+        assert!(svg.contains("background-color: #fff"));
+        assert_eq!(svg.matches("<rect ").count(), 1);
+        assert!(svg.contains("<rect x=\"16\" y=\"8\" width=\"8\" height=\"8\"/>"));
+        assert!(svg.contains("<g fill=\"#000\">"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+
+        // Colors can't break out of their attribute
+        assert!(!board.to_svg(1, "\"><script>", "white").contains("<script>"));
+    }
+
+    #[test]
+    #[should_panic(expected = "px per cell is larger than a usize can count")]
+    fn svg_past_usize() {
+        Board::new(4, 3, BorderOpt::Empty).to_svg(usize::MAX / 2, "black", "white");
+    }
+
+    #[test]
+    #[should_panic(expected = "ms per frame last longer than a usize can count")]
+    fn animation_cycles_past_usize() {
+        Board::new(4, 3, BorderOpt::Empty).to_svg_animation(usize::MAX, 1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "ms per frame last longer than a usize can count")]
+    fn animation_frames_past_usize() {
+        Board::new(4, 3, BorderOpt::Empty).to_svg_animation(1, 1, usize::MAX);
+    }
+
+    #[test]
+    fn animation_has_a_group_per_generation() {
+        let mut board = Board::new_from_file("./tests/test-boards/glider.txt").pad(2, 2, 2, 2);
+        let frames: Vec<String> = (0..=4)
+            .map(|n| board.clone().advanced_n(n).to_svg(6, "black", "white"))
+            .collect();
+
+        let svg = board.to_svg_animation(4, 6, 100);
+        assert_eq!(svg.matches("<g ").count(), 5);
+        assert_eq!(svg.matches("<rect ").count(), 25);
+        assert!(svg.contains("@keyframes frame { 0% { visibility: visible; } 20% {"));
+        (0..5).for_each(|i| {
+            let style = format!("frame 500ms step-end {}ms infinite", i * 100);
+            assert_eq!(svg.matches(&style).count(), 1);
+        });
+        // Each group holds the same cells as that generation's still SVG
+        let groups: Vec<&str> = svg.split("<g ").skip(1).collect();
+        groups.iter().zip(&frames).for_each(|(group, still)| {
+            let rects = |s: &str| {
+                s.lines()
+                    .filter(|l| l.starts_with("<rect "))
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(rects(group), rects(still.split("<g ").nth(1).unwrap()));
+        });
+        assert_eq!(board.generation().0, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn svg_needs_cell_size() {
+        Board::default().to_svg(0, "black", "white");
+    }
+}
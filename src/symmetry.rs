@@ -0,0 +1,330 @@
+use crate::{board::Board, cell::Cell};
+
+// Maps a cell of a transformed `w`×`h` pattern back to the cell of the
+// original it comes from
+pub(crate) type SourceFn = fn(usize, usize, usize, usize) -> (usize, usize);
+
+// The 8 rotations and reflections of a pattern: where each takes its cell
+// at `x`, `y` from, and whether it swaps width and height. The first four
+// are the pattern as is, mirrored left to right, mirrored top to bottom
+// and turned half way around.
+pub(crate) const TRANSFORMS: [(bool, SourceFn); 8] = [
+    (false, |x, y, _, _| (x, y)),
+    (false, |x, y, w, _| (w - 1 - x, y)),
+    (false, |x, y, _, h| (x, h - 1 - y)),
+    (false, |x, y, w, h| (w - 1 - x, h - 1 - y)),
+    (true, |x, y, _, _| (y, x)),
+    (true, |x, y, w, _| (w - 1 - y, x)),
+    (true, |x, y, _, h| (y, h - 1 - x)),
+    (true, |x, y, w, h| (w - 1 - y, h - 1 - x)),
+];
+
+/// Which way [`Board::mirror_to_symmetric`] and [`Board::symmetrize`]
+/// reflect a board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymmetryKind {
+    /// Reflect the top half onto the bottom half
+    Horizontal,
+    /// Reflect the left half onto the right half
+    Vertical,
+    /// Reflect the top-left quadrant onto the other three
+    Both,
+    /// Reflect the part on and above the main diagonal (top-left to
+    /// bottom-right) onto the part below it
+    Diagonal,
+}
+
+/// How [`Board::symmetrize`] combines a cell with its reflections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoolOp {
+    /// Alive if the cell or any of its reflections is
+    Or,
+    /// Alive only if the cell and all of its reflections are
+    And,
+}
+
+impl Board {
+    /// A copy of the board made symmetric by reflecting part of it onto
+    /// the rest, as chosen by `kind`
+    ///
+    /// Only the source part (e.g. the top-left quadrant for
+    /// [`SymmetryKind::Both`]) matters; whatever else is on the board is
+    /// overwritten. With an odd width or height the middle column or row
+    /// is its own reflection, so it belongs to the source and is kept as
+    /// is.
+    ///
+    /// # Panics:
+    ///
+    /// If `kind` is [`SymmetryKind::Diagonal`] and the board isn't square
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 4, BorderOpt::Empty);
+    /// board.set(0, 1, Cell::Alive);
+    ///
+    /// let mirrored = board.mirror_to_symmetric(SymmetryKind::Vertical);
+    /// assert_eq!(mirrored.get(3, 1), Cell::Alive);
+    /// ```
+    pub fn mirror_to_symmetric(&self, kind: SymmetryKind) -> Board {
+        if kind == SymmetryKind::Diagonal && self.width != self.height {
+            panic!(
+                "diagonal symmetry needs a square board, this one is {}x{}",
+                self.width, self.height
+            );
+        }
+        let (w, h) = (self.width, self.height);
+        let fold_x = |x: usize| x.min(w - 1 - x);
+        let fold_y = |y: usize| y.min(h - 1 - y);
+        let mut board = self.clone();
+
+        (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .for_each(|(x, y)| {
+                let (sx, sy) = match kind {
+                    SymmetryKind::Horizontal => (x, fold_y(y)),
+                    SymmetryKind::Vertical => (fold_x(x), y),
+                    SymmetryKind::Both => (fold_x(x), fold_y(y)),
+                    SymmetryKind::Diagonal => (x.max(y), x.min(y)),
+                };
+                board.set(x, y, self.get(sx, sy));
+            });
+
+        board
+    }
+
+    /// A copy of the board made symmetric by combining every cell with
+    /// its reflections under `kind`
+    ///
+    /// Unlike [`Board::mirror_to_symmetric`], every part of the board
+    /// contributes: [`BoolOp::Or`] keeps a cell alive if it's alive in
+    /// any reflection, [`BoolOp::And`] only if it's alive in all of them.
+    /// Handy for turning an asymmetric seed into a symmetric soup.
+    ///
+    /// # Panics:
+    ///
+    /// If `kind` is [`SymmetryKind::Diagonal`] and the board isn't square
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(4, 1, BorderOpt::Empty);
+    /// board.set(0, 0, Cell::Alive);
+    ///
+    /// let or = board.symmetrize(SymmetryKind::Vertical, BoolOp::Or);
+    /// assert_eq!(or.to_ascii(), "#..#\n");
+    /// let and = board.symmetrize(SymmetryKind::Vertical, BoolOp::And);
+    /// assert_eq!(and.to_ascii(), "....\n");
+    /// ```
+    pub fn symmetrize(&self, kind: SymmetryKind, combine: BoolOp) -> Board {
+        if kind == SymmetryKind::Diagonal && self.width != self.height {
+            panic!(
+                "diagonal symmetry needs a square board, this one is {}x{}",
+                self.width, self.height
+            );
+        }
+        let (w, h) = (self.width, self.height);
+        let mut board = self.clone();
+
+        (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .for_each(|(x, y)| {
+                let (fx, fy) = (w - 1 - x, h - 1 - y);
+                let images: &[(usize, usize)] = match kind {
+                    SymmetryKind::Horizontal => &[(x, y), (x, fy)],
+                    SymmetryKind::Vertical => &[(x, y), (fx, y)],
+                    SymmetryKind::Both => &[(x, y), (fx, y), (x, fy), (fx, fy)],
+                    SymmetryKind::Diagonal => &[(x, y), (y, x)],
+                };
+                let mut alive = images
+                    .iter()
+                    .map(|&(ix, iy)| self.get(ix, iy) == Cell::Alive);
+                let cell = match combine {
+                    BoolOp::Or => alive.any(|a| a),
+                    BoolOp::And => alive.all(|a| a),
+                };
+                board.set(x, y, if cell { Cell::Alive } else { Cell::Dead });
+            });
+
+        board
+    }
+
+    /// The pattern on the board in a standard orientation, so that
+    /// patterns that are rotations or reflections of each other give the
+    /// same result
+    ///
+    /// The live cells are trimmed to their bounding box (see
+    /// [`Board::extract_pattern`]) and all 8 rotations and reflections
+    /// are compared by width, then height, then cells in row-major order
+    /// with dead before alive. The smallest one is returned.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut horizontal = Board::new(5, 5, BorderOpt::Empty);
+    /// horizontal.fill_rect(1, 2, 3, 1, Cell::Alive);
+    /// let vertical = horizontal.clone().advanced();
+    ///
+    /// assert_eq!(horizontal.canonical_form(), vertical.canonical_form());
+    /// assert_eq!(horizontal.canonical_form().width, 1);
+    /// ```
+    pub fn canonical_form(&self) -> Board {
+        let pattern = self.extract_pattern(0, 0, self.width, self.height);
+
+        TRANSFORMS
+            .iter()
+            .map(|&transform| pattern.transformed(transform))
+            .min_by_key(|b| {
+                let alive: Vec<bool> = b.cells.iter().map(|&c| c == Cell::Alive).collect();
+                (b.width, b.height, alive)
+            })
+            .unwrap()
+    }
+
+    /// Whether both boards hold the same pattern, up to position,
+    /// rotation and reflection, see [`Board::canonical_form`]
+    pub fn is_equivalent(&self, other: &Board) -> bool {
+        self.canonical_form().cells_eq(&other.canonical_form()) == Ok(true)
+    }
+
+    // The board's cells rotated or reflected by one of `TRANSFORMS`, on a
+    // board of their own with the same border
+    pub(crate) fn transformed(&self, (swap, source): (bool, SourceFn)) -> Board {
+        let (w, h) = (self.width, self.height);
+        let (tw, th) = if swap { (h, w) } else { (w, h) };
+        let cells = (0..th)
+            .flat_map(|y| (0..tw).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (sx, sy) = source(x, y, w, h);
+                self.get(sx, sy)
+            })
+            .collect();
+
+        Board::from_cells(tw, th, self.border.clone(), cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{board::BorderOpt, cell::Cell};
+
+    fn flipped(board: &Board, flip_x: bool, flip_y: bool) -> Board {
+        let mut flipped = board.clone();
+        (0..board.height)
+            .flat_map(|y| (0..board.width).map(move |x| (x, y)))
+            .for_each(|(x, y)| {
+                let sx = if flip_x { board.width - 1 - x } else { x };
+                let sy = if flip_y { board.height - 1 - y } else { y };
+                flipped.set(x, y, board.get(sx, sy));
+            });
+        flipped
+    }
+
+    #[test]
+    fn four_fold_from_quadrant() {
+        [(8, 6), (7, 5)].iter().for_each(|&(w, h)| {
+            let mut board = Board::new_random(w, h, BorderOpt::Empty, 0.5, 5);
+            let quadrant = board.extract_pattern(0, 0, w.div_ceil(2), h.div_ceil(2));
+
+            board = board.mirror_to_symmetric(SymmetryKind::Both);
+
+            assert_eq!(flipped(&board, true, false), board);
+            assert_eq!(flipped(&board, false, true), board);
+            assert_eq!(
+                board.extract_pattern(0, 0, w.div_ceil(2), h.div_ceil(2)),
+                quadrant
+            );
+        });
+    }
+
+    #[test]
+    fn single_axis_and_diagonal() {
+        let board = Board::new_random(6, 6, BorderOpt::Empty, 0.5, 8);
+
+        let horizontal = board.mirror_to_symmetric(SymmetryKind::Horizontal);
+        assert_eq!(flipped(&horizontal, false, true), horizontal);
+
+        let vertical = board.mirror_to_symmetric(SymmetryKind::Vertical);
+        assert_eq!(flipped(&vertical, true, false), vertical);
+
+        let diagonal = board.mirror_to_symmetric(SymmetryKind::Diagonal);
+        (0..6).for_each(|y| {
+            (0..6).for_each(|x| {
+                assert_eq!(diagonal.get(x, y), diagonal.get(y, x));
+                if x >= y {
+                    assert_eq!(diagonal.get(x, y), board.get(x, y));
+                }
+            })
+        });
+    }
+
+    #[test]
+    fn symmetrize_asymmetric_glider() {
+        let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+        assert_ne!(flipped(&glider, false, true), glider);
+
+        let or = glider.symmetrize(SymmetryKind::Horizontal, BoolOp::Or);
+        let and = glider.symmetrize(SymmetryKind::Horizontal, BoolOp::And);
+        assert_eq!(flipped(&or, false, true), or);
+        assert_eq!(flipped(&and, false, true), and);
+        assert_eq!(glider.overlay(&or).unwrap(), or);
+        assert_eq!(and.overlay(&glider).unwrap(), glider);
+
+        let both = Board::new_random(7, 6, BorderOpt::Empty, 0.3, 2)
+            .symmetrize(SymmetryKind::Both, BoolOp::Or);
+        assert_eq!(flipped(&both, true, false), both);
+        assert_eq!(flipped(&both, false, true), both);
+
+        let diagonal = glider.symmetrize(SymmetryKind::Diagonal, BoolOp::Or);
+        (0..5)
+            .for_each(|y| (0..5).for_each(|x| assert_eq!(diagonal.get(x, y), diagonal.get(y, x))));
+    }
+
+    #[test]
+    fn glider_and_mirror_are_equivalent() {
+        let glider = Board::new_from_file("./tests/test-boards/glider.txt");
+        let mirror = flipped(&glider, true, false);
+
+        assert_ne!(glider, mirror);
+        assert_eq!(glider.canonical_form(), mirror.canonical_form());
+        assert!(glider.is_equivalent(&mirror));
+
+        // A glider alternates between two shapes, each generation the
+        // mirror image of the one two generations before
+        let roomy = glider.pad(4, 4, 4, 4);
+        let next = roomy.clone().advanced();
+        assert!(!roomy.is_equivalent(&next));
+        assert!(roomy.is_equivalent(&roomy.clone().advanced_n(2)));
+        assert!(next.is_equivalent(&next.clone().advanced_n(2)));
+    }
+
+    #[test]
+    fn canonical_form_is_trimmed() {
+        let mut board = Board::new(9, 9, BorderOpt::Loop);
+        board.fill_rect(5, 1, 2, 3, Cell::Alive);
+        board.set(6, 1, Cell::Dead);
+
+        let canonical = board.canonical_form();
+
+        assert_eq!((canonical.width, canonical.height), (2, 3));
+        assert_eq!(canonical.border, BorderOpt::Empty);
+        assert_eq!(canonical, canonical.canonical_form());
+        assert!(Board::new(3, 3, BorderOpt::Empty).is_equivalent(&Board::default()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn diagonal_needs_square() {
+        let mut board = Board::new(4, 3, BorderOpt::Empty);
+        board.set(0, 0, Cell::Alive);
+        board.mirror_to_symmetric(SymmetryKind::Diagonal);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{board::Board, cell::Cell, rng::SplitMix64};
+
+/// The order cells are updated in by [`Board::advance_async`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Every cell at once from the previous generation, like
+    /// [`Board::advance_cycle`]
+    Synchronous,
+    /// One cell at a time in the given order
+    Sequential(ScanOrder),
+    /// One cell at a time in an order shuffled from the seed, the same
+    /// order every cycle
+    RandomOrder(u64),
+}
+
+/// The order cells are visited in, where that makes a difference
+///
+/// [`Board::advance_cycle`] visits cells in [`ScanOrder::ColumnMajor`]
+/// order, but only decides every cell's next state before changing any,
+/// so the order never shows in the result, see
+/// [`Board::advance_cycle_ordered`]. It does for
+/// [`UpdateMode::Sequential`], where each cell sees the ones visited
+/// before it in their new state, and for
+/// [`Board::advance_capped_ordered`], where the cells visited first get
+/// the births the cap allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOrder {
+    /// Row by row from the top, left to right along each row
+    RowMajor,
+    /// Column by column from the left, top to bottom down each column
+    ColumnMajor,
+}
+
+impl ScanOrder {
+    // Every `(x, y)` on a `width`×`height` board, in this order
+    pub(crate) fn positions(
+        self,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        (0..width * height).map(move |i| match self {
+            ScanOrder::RowMajor => (i % width, i / width),
+            ScanOrder::ColumnMajor => (i / height, i % height),
+        })
+    }
+}
+
+impl Board {
+    /// Advance board state by one cycle, updating cells in the order
+    /// given by `mode`
+    ///
+    /// In the one-at-a-time modes, each cell sees the cells updated
+    /// before it in their new state, so patterns behave very differently
+    /// than under standard, synchronous Life: a blinker doesn't blink,
+    /// for one. Frozen cells, the rule and the border all apply as usual,
+    /// and the cycle counts as a generation.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use yagoll::*;
+    ///
+    /// let mut board = Board::new(5, 5, BorderOpt::Empty);
+    /// board.fill_rect(2, 1, 1, 3, Cell::Alive);
+    /// let synchronous = board.clone().advanced();
+    ///
+    /// board.advance_async(UpdateMode::Sequential(ScanOrder::RowMajor));
+    /// assert_ne!(board, synchronous);
+    /// ```
+    pub fn advance_async(&mut self, mode: UpdateMode) {
+        let order: Vec<(usize, usize)> = match mode {
+            UpdateMode::Synchronous => return self.advance_cycle(),
+            UpdateMode::Sequential(order) => order.positions(self.width, self.height).collect(),
+            UpdateMode::RandomOrder(seed) => {
+                let mut order: Vec<_> = ScanOrder::RowMajor
+                    .positions(self.width, self.height)
+                    .collect();
+                // Fisher-Yates
+                let mut rng = SplitMix64::new(seed);
+                (1..order.len()).rev().for_each(|i| {
+                    let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+                    order.swap(i, j);
+                });
+                order
+            }
+        };
+
+        self.begin_generation();
+        order.iter().for_each(|&(x, y)| {
+            let cell = if self.cell_should_live(x, y) {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            };
+            self.apply_updates(&[(x, y, cell)]);
+        });
+        self.invalidate_frontier();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::board::{BorderOpt, Generation};
+
+    #[test]
+    fn synchronous_matches_advance_cycle() {
+        let mut board = Board::new_random(16, 12, BorderOpt::Loop, 0.4, 11);
+        let mut expected = board.clone();
+
+        (0..10).for_each(|_| {
+            board.advance_async(UpdateMode::Synchronous);
+            expected.advance_cycle();
+            assert_eq!(board, expected);
+        });
+        assert_eq!(board.generation(), Generation(10));
+    }
+
+    #[test]
+    fn sequential_sees_updated_neighbors() {
+        let mut board = Board::new(5, 5, BorderOpt::Empty);
+        board.fill_rect(2, 1, 1, 3, Cell::Alive);
+        let mut sequential = board.clone();
+        sequential.advance_async(UpdateMode::Sequential(ScanOrder::RowMajor));
+
+        // Each cell dies before the one below it is looked at, leaving
+        // that one with a single neighbor, so the whole blinker dies
+        // instead of turning
+        assert_ne!(sequential, board.clone().advanced());
+        assert!(sequential.to_sparse().live_cells().is_empty());
+        assert_eq!(sequential.generation(), Generation(1));
+        assert_eq!(sequential.last_diff().len(), 3);
+
+        // Column by column, the cells left of the blinker are looked at
+        // first and come to life, so something survives
+        let mut columns = board.clone();
+        columns.advance_async(UpdateMode::Sequential(ScanOrder::ColumnMajor));
+        assert_ne!(columns, sequential);
+        assert!(columns.population() > 0);
+    }
+
+    #[test]
+    fn synchronous_result_is_independent_of_scan_order() {
+        [BorderOpt::Empty, BorderOpt::Solid, BorderOpt::Loop]
+            .iter()
+            .for_each(|border| {
+                let board = Board::new_random(13, 9, border.clone(), 0.4, 5);
+                let advance = |order: ScanOrder| {
+                    let mut next = board.clone();
+                    next.advance_cycle_ordered(order);
+                    next
+                };
+
+                let rows = advance(ScanOrder::RowMajor);
+                let columns = advance(ScanOrder::ColumnMajor);
+                assert_eq!(rows, columns, "{:?}", border);
+                assert_eq!(rows, board.clone().advanced(), "{:?}", border);
+
+                // Only the order the changes are listed in differs
+                let mut diff = columns.last_diff().to_vec();
+                assert_ne!(rows.last_diff(), diff.as_slice());
+                diff.sort_by_key(|&(x, y, _)| (y, x));
+                assert_eq!(rows.last_diff(), diff.as_slice());
+            });
+    }
+
+    #[test]
+    fn scan_orders_visit_every_cell_once() {
+        let rows: Vec<_> = ScanOrder::RowMajor.positions(3, 2).collect();
+        let columns: Vec<_> = ScanOrder::ColumnMajor.positions(3, 2).collect();
+
+        assert_eq!(rows, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(
+            columns,
+            vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]
+        );
+        assert_eq!(ScanOrder::ColumnMajor.positions(0, 4).count(), 0);
+        assert_eq!(ScanOrder::RowMajor.positions(4, 0).count(), 0);
+    }
+
+    #[test]
+    fn capped_births_follow_scan_order() {
+        // Two L-trominoes, each about to fill in to a block, with room
+        // for only one more cell: (5, 2) comes first row by row, (2, 5)
+        // column by column
+        let mut board = Board::new(8, 8, BorderOpt::Empty);
+        [(5, 1), (6, 1), (6, 2), (1, 5), (1, 6), (2, 6)]
+            .iter()
+            .for_each(|&(x, y)| board.set(x, y, Cell::Alive));
+        let capped = |order: ScanOrder| {
+            let mut board = board.clone();
+            board.advance_capped_ordered(7, order);
+            (board.get(5, 2), board.get(2, 5))
+        };
+
+        assert_eq!(capped(ScanOrder::RowMajor), (Cell::Alive, Cell::Dead));
+        assert_eq!(capped(ScanOrder::ColumnMajor), (Cell::Dead, Cell::Alive));
+    }
+
+    #[test]
+    fn random_order_is_reproducible() {
+        let board = Board::new_random(16, 12, BorderOpt::Loop, 0.4, 11);
+        let run = |mode: UpdateMode| {
+            let mut board = board.clone();
+            (0..5).for_each(|_| board.advance_async(mode));
+            board
+        };
+
+        assert_eq!(
+            run(UpdateMode::RandomOrder(3)),
+            run(UpdateMode::RandomOrder(3))
+        );
+        assert_ne!(
+            run(UpdateMode::RandomOrder(3)),
+            run(UpdateMode::RandomOrder(4))
+        );
+        assert_ne!(
+            run(UpdateMode::RandomOrder(3)),
+            run(UpdateMode::Sequential(ScanOrder::RowMajor))
+        );
+    }
+}
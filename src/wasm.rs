@@ -0,0 +1,145 @@
+//! A flat wrapper around [`Board`] for driving the crate from JavaScript
+//!
+//! Only plain numbers, booleans and byte arrays cross the boundary. With
+//! the `wasm` feature the wrapper is exported through `wasm-bindgen`,
+//! where `to_bytes` arrives as a `Uint8Array`.
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    board::{Board, BorderOpt},
+    cell::Cell,
+};
+
+/// A board with a JavaScript friendly API
+///
+/// Borders are given as a byte, the same one the encoded formats use:
+/// 0 for `Empty`, 1 for `Solid` and 2 for `Loop`.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone)]
+pub struct WasmBoard {
+    board: Board,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl WasmBoard {
+    /// Initialize new board with every cell dead
+    ///
+    /// # Panics:
+    ///
+    /// If `border` isn't 0, 1 or 2, or the board would have too many
+    /// cells
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(width: u32, height: u32, border: u8) -> WasmBoard {
+        let border = match border {
+            0 => BorderOpt::Empty,
+            1 => BorderOpt::Solid,
+            2 => BorderOpt::Loop,
+            b => panic!("Invalid border byte {}", b),
+        };
+
+        WasmBoard {
+            board: Board::new(width as usize, height as usize, border),
+        }
+    }
+
+    /// The width of the board
+    pub fn width(&self) -> u32 {
+        self.board.width as u32
+    }
+
+    /// The height of the board
+    pub fn height(&self) -> u32 {
+        self.board.height as u32
+    }
+
+    /// Set the cell at `x` and `y` alive or dead
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn set(&mut self, x: u32, y: u32, alive: bool) {
+        let cell = if alive { Cell::Alive } else { Cell::Dead };
+        self.board.set(x as usize, y as usize, cell);
+    }
+
+    /// Whether the cell at `x` and `y` is alive
+    ///
+    /// # Panics:
+    ///
+    /// If `x` or `y` are out of range
+    pub fn get(&self, x: u32, y: u32) -> bool {
+        self.board.get(x as usize, y as usize) == Cell::Alive
+    }
+
+    /// Advance board state by `cycles` cycles
+    pub fn advance(&mut self, cycles: u32) {
+        self.board.advance_n_cycles(cycles as usize);
+    }
+
+    /// One byte per cell in row-major order, 1 for alive and 0 for dead
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.board
+            .cells
+            .iter()
+            .map(|&c| if c == Cell::Alive { 1 } else { 0 })
+            .collect()
+    }
+}
+
+impl From<Board> for WasmBoard {
+    fn from(board: Board) -> Self {
+        WasmBoard { board }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn wrapper_matches_board() {
+        let soup = Board::new_random(12, 9, BorderOpt::Loop, 0.4, 5);
+        let mut wrapped = WasmBoard::new(12, 9, 2);
+        (0..9).for_each(|y| {
+            (0..12).for_each(|x| {
+                let alive = soup.get(x as usize, y as usize) == Cell::Alive;
+                wrapped.set(x, y, alive);
+            })
+        });
+
+        let expected = soup.advanced_n(7);
+        wrapped.advance(7);
+
+        assert_eq!((wrapped.width(), wrapped.height()), (12, 9));
+        assert_eq!(
+            wrapped.to_bytes(),
+            WasmBoard::from(expected.clone()).to_bytes()
+        );
+        (0..9).for_each(|y| {
+            (0..12).for_each(|x| {
+                assert_eq!(
+                    wrapped.get(x, y),
+                    expected.get(x as usize, y as usize) == Cell::Alive
+                )
+            })
+        });
+    }
+
+    #[test]
+    fn to_bytes_is_row_major() {
+        let mut board = WasmBoard::new(3, 2, 0);
+        board.set(2, 0, true);
+        board.set(0, 1, true);
+
+        assert_eq!(board.to_bytes(), vec![0, 0, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid border byte 3")]
+    fn invalid_border_byte() {
+        WasmBoard::new(3, 3, 3);
+    }
+}